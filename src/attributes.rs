@@ -0,0 +1,306 @@
+//! # Attribute Templates
+//!
+//! Templates label the output of a `generate` statement. A statement may set
+//! the type of every generated node and edge and assign attributes, either as
+//! fixed values or sampled independently for each element from a
+//! distribution:
+//!
+//! ```ggl
+//! generate barabasi_albert {
+//!     nodes: 100;
+//!     edges_per_node: 2;
+//!     node_type: "server";
+//!     node_attrs: [cpu = uniform_int(2, 64), zone = choice("a", "b", "c")];
+//!     edge_type: "link";
+//!     edge_attrs: [latency = normal(5.0, 1.0), bandwidth = 10];
+//! }
+//! ```
+//!
+//! ## Distributions
+//!
+//! * `uniform_int(min, max)` - Integers from `min` to `max`, both included
+//! * `uniform(min, max)` - Floats between `min` and `max`
+//! * `normal(mean, std_dev)` - Normally distributed floats
+//! * `choice(a, b, ...)` - One of the given values, each equally likely
+//!
+//! Values are drawn from the engine's random number generator, visiting
+//! elements in sorted id order, so a seeded program always assigns the same
+//! values.
+
+use crate::generators::suggest;
+use crate::types::{Graph, MetadataValue};
+use rand::Rng;
+use std::f64::consts::PI;
+
+/// A distribution to sample attribute values from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Distribution {
+    /// Integers from `min` to `max`, both included
+    UniformInt { min: i64, max: i64 },
+    /// Floats from `min` up to `max`
+    Uniform { min: f64, max: f64 },
+    /// Floats from a normal distribution
+    Normal { mean: f64, std_dev: f64 },
+    /// One of the values, each equally likely
+    Choice(Vec<MetadataValue>),
+}
+
+const DISTRIBUTIONS: [&str; 4] = ["uniform_int", "uniform", "normal", "choice"];
+
+impl Distribution {
+    /// Builds a distribution from a call such as `uniform_int(2, 64)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::attributes::Distribution;
+    /// use graph_generation_language::types::MetadataValue;
+    ///
+    /// let cpu = Distribution::from_call(
+    ///     "uniform_int",
+    ///     &[MetadataValue::Integer(2), MetadataValue::Integer(64)],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(cpu, Distribution::UniformInt { min: 2, max: 64 });
+    ///
+    /// let error = Distribution::from_call("gaussian", &[]).unwrap_err();
+    /// assert!(error.contains("Unknown distribution gaussian"));
+    /// ```
+    pub fn from_call(name: &str, args: &[MetadataValue]) -> Result<Self, String> {
+        let number = |value: &MetadataValue| match value {
+            MetadataValue::Integer(n) => Ok(*n as f64),
+            MetadataValue::Float(x) => Ok(*x),
+            other => Err(format!("{} expects numbers, got {:?}", name, other)),
+        };
+        let arity = |count: usize| {
+            if args.len() == count {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} expects {} arguments, got {}",
+                    name,
+                    count,
+                    args.len()
+                ))
+            }
+        };
+
+        match name {
+            "uniform_int" => {
+                arity(2)?;
+                match (&args[0], &args[1]) {
+                    (MetadataValue::Integer(min), MetadataValue::Integer(max)) if min <= max => {
+                        Ok(Distribution::UniformInt {
+                            min: *min,
+                            max: *max,
+                        })
+                    }
+                    (MetadataValue::Integer(min), MetadataValue::Integer(max)) => Err(format!(
+                        "uniform_int needs min <= max, got {} and {}",
+                        min, max
+                    )),
+                    _ => Err("uniform_int expects two integers".to_string()),
+                }
+            }
+            "uniform" => {
+                arity(2)?;
+                let (min, max) = (number(&args[0])?, number(&args[1])?);
+                if min > max {
+                    return Err(format!("uniform needs min <= max, got {} and {}", min, max));
+                }
+                Ok(Distribution::Uniform { min, max })
+            }
+            "normal" => {
+                arity(2)?;
+                let (mean, std_dev) = (number(&args[0])?, number(&args[1])?);
+                if std_dev < 0.0 {
+                    return Err(format!(
+                        "normal needs a non-negative std_dev, got {}",
+                        std_dev
+                    ));
+                }
+                Ok(Distribution::Normal { mean, std_dev })
+            }
+            "choice" => {
+                if args.is_empty() {
+                    return Err("choice needs at least one value".to_string());
+                }
+                Ok(Distribution::Choice(args.to_vec()))
+            }
+            other => Err(match suggest(other, DISTRIBUTIONS) {
+                Some(name) => format!("Unknown distribution {}, did you mean {}?", other, name),
+                None => format!(
+                    "Unknown distribution {}, expected one of {}",
+                    other,
+                    DISTRIBUTIONS.join(", ")
+                ),
+            }),
+        }
+    }
+
+    /// Draws one value.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> MetadataValue {
+        match self {
+            Distribution::UniformInt { min, max } => {
+                MetadataValue::Integer(rng.gen_range(*min..=*max))
+            }
+            Distribution::Uniform { min, max } => {
+                MetadataValue::Float(*min + (*max - *min) * rng.gen::<f64>())
+            }
+            Distribution::Normal { mean, std_dev } => {
+                // Box-Muller transform; 1 - u keeps the logarithm finite
+                let u1 = 1.0 - rng.gen::<f64>();
+                let u2 = rng.gen::<f64>();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+                MetadataValue::Float(mean + std_dev * z)
+            }
+            Distribution::Choice(values) => values[rng.gen_range(0..values.len())].clone(),
+        }
+    }
+}
+
+/// The value assigned by an attribute template.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    /// The same value for every element, e.g. `rack = 1`
+    Fixed(MetadataValue),
+    /// A fresh sample for every element, e.g. `cpu = uniform_int(2, 64)`
+    Sampled(Distribution),
+}
+
+/// One `name = value` entry of `node_attrs` or `edge_attrs`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeTemplate {
+    pub name: String,
+    pub value: AttributeValue,
+}
+
+impl AttributeTemplate {
+    /// Returns the value for the next element.
+    pub fn value<R: Rng>(&self, rng: &mut R) -> MetadataValue {
+        match &self.value {
+            AttributeValue::Fixed(value) => value.clone(),
+            AttributeValue::Sampled(distribution) => distribution.sample(rng),
+        }
+    }
+}
+
+/// Types and attribute templates applied to every generated node and edge.
+///
+/// Types replace those set by the generator, and attributes are added to or
+/// overwrite the generator's own.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Templates {
+    pub node_type: Option<String>,
+    pub node_attrs: Vec<AttributeTemplate>,
+    pub edge_type: Option<String>,
+    pub edge_attrs: Vec<AttributeTemplate>,
+}
+
+impl Templates {
+    /// Returns whether the templates leave a graph unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.node_type.is_none()
+            && self.node_attrs.is_empty()
+            && self.edge_type.is_none()
+            && self.edge_attrs.is_empty()
+    }
+
+    /// Labels every node and edge of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::attributes::{
+    ///     AttributeTemplate, AttributeValue, Distribution, Templates,
+    /// };
+    /// use graph_generation_language::generators::generate_path;
+    /// use graph_generation_language::types::MetadataValue;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+    /// let mut graph = generate_path(&params).unwrap();
+    ///
+    /// let templates = Templates {
+    ///     node_type: Some("server".to_string()),
+    ///     node_attrs: vec![AttributeTemplate {
+    ///         name: "cpu".to_string(),
+    ///         value: AttributeValue::Sampled(Distribution::UniformInt { min: 2, max: 64 }),
+    ///     }],
+    ///     ..Templates::default()
+    /// };
+    /// templates.apply(&mut graph, &mut SmallRng::seed_from_u64(1));
+    ///
+    /// assert_eq!(graph.get_node("n0").unwrap().r#type, "server");
+    /// assert!(graph.get_node("n2").unwrap().metadata.contains_key("cpu"));
+    /// ```
+    pub fn apply<R: Rng>(&self, graph: &mut Graph, rng: &mut R) {
+        let mut node_ids: Vec<String> = graph.nodes.keys().cloned().collect();
+        node_ids.sort();
+        for id in node_ids {
+            let node = graph.nodes.get_mut(&id).unwrap();
+            if let Some(node_type) = &self.node_type {
+                node.r#type = node_type.clone();
+            }
+            for template in &self.node_attrs {
+                node.metadata
+                    .insert(template.name.clone(), template.value(rng));
+            }
+        }
+
+        let mut edge_ids: Vec<String> = graph.edges.keys().cloned().collect();
+        edge_ids.sort();
+        for id in edge_ids {
+            let edge = graph.edges.get_mut(&id).unwrap();
+            if let Some(edge_type) = &self.edge_type {
+                edge.r#type = edge_type.clone();
+            }
+            for template in &self.edge_attrs {
+                edge.metadata
+                    .insert(template.name.clone(), template.value(rng));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_normal_moments() {
+        let normal = Distribution::Normal {
+            mean: 5.0,
+            std_dev: 2.0,
+        };
+        let mut rng = SmallRng::seed_from_u64(3);
+        let samples: Vec<f64> = (0..20000)
+            .map(|_| match normal.sample(&mut rng) {
+                MetadataValue::Float(x) => x,
+                other => panic!("expected a float, got {:?}", other),
+            })
+            .collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+        assert!((mean - 5.0).abs() < 0.05, "mean {}", mean);
+        assert!((variance - 4.0).abs() < 0.15, "variance {}", variance);
+    }
+
+    #[test]
+    fn test_invalid_calls() {
+        let int = MetadataValue::Integer;
+        assert!(Distribution::from_call("uniform_int", &[int(5), int(1)]).is_err());
+        assert!(Distribution::from_call("uniform_int", &[int(1)]).is_err());
+        assert!(Distribution::from_call("normal", &[int(0), int(-1)]).is_err());
+        assert!(Distribution::from_call("choice", &[]).is_err());
+        assert!(Distribution::from_call("normall", &[int(0), int(1)])
+            .unwrap_err()
+            .contains("did you mean normal?"));
+    }
+}
@@ -0,0 +1,486 @@
+//! # Graph Export
+//!
+//! This module writes graphs in formats read by other graph tools, next to the
+//! JSON produced by [`GGLEngine`](crate::GGLEngine):
+//!
+//! * [`to_dot`] - Graphviz DOT
+//! * [`to_graphml`] - GraphML
+//!
+//! Subgraphs declared with `subgraph` blocks are kept: DOT output places
+//! their nodes in `subgraph cluster_*` blocks, which Graphviz draws as boxes,
+//! and GraphML output in nested graphs. Both exporters list nodes, edges and
+//! attributes in sorted order, so the same graph always gives the same text.
+//!
+//! Edges have no direction flag of their own, so the caller chooses whether
+//! to write them as arcs from source to target or as undirected edges.
+
+use crate::types::{Graph, MetadataValue, Node};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Nodes grouped by subgraph, with the subgraphs nested under their parents.
+struct Hierarchy<'a> {
+    /// Sorted members of each subgraph, `None` being the top level
+    members: BTreeMap<Option<&'a str>, Vec<&'a Node>>,
+    /// Sorted child subgraphs of each subgraph
+    children: BTreeMap<Option<&'a str>, BTreeSet<&'a str>>,
+}
+
+impl<'a> Hierarchy<'a> {
+    fn new(graph: &'a Graph) -> Self {
+        let mut nodes: Vec<&Node> = graph.nodes.values().collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut members: BTreeMap<Option<&str>, Vec<&Node>> = BTreeMap::new();
+        let mut children: BTreeMap<Option<&str>, BTreeSet<&str>> = BTreeMap::new();
+        for node in nodes {
+            let subgraph = node.subgraph.as_deref();
+            members.entry(subgraph).or_default().push(node);
+
+            // Register every enclosing subgraph, even those without nodes of their own
+            let mut path = subgraph;
+            while let Some(current) = path {
+                let parent = current.rsplit_once('.').map(|(outer, _)| outer);
+                children.entry(parent).or_default().insert(current);
+                path = parent;
+            }
+        }
+        Hierarchy { members, children }
+    }
+
+    fn members(&self, subgraph: Option<&'a str>) -> &[&'a Node] {
+        self.members.get(&subgraph).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every subgraph, nested ones included.
+    fn subgraphs(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.children.values().flatten().copied()
+    }
+
+    fn children(&self, subgraph: Option<&'a str>) -> Vec<&'a str> {
+        self.children
+            .get(&subgraph)
+            .map_or_else(Vec::new, |children| children.iter().copied().collect())
+    }
+}
+
+/// The id of a subgraph in the output, prefixed with `cluster_` unless it already is.
+fn cluster_id(path: &str) -> String {
+    if path.starts_with("cluster") {
+        path.to_string()
+    } else {
+        format!("cluster_{}", path)
+    }
+}
+
+/// Formats a value as text, writing lists as JSON.
+pub(crate) fn value_text(value: &MetadataValue) -> String {
+    match value {
+        MetadataValue::String(s) => s.clone(),
+        MetadataValue::Integer(n) => n.to_string(),
+        MetadataValue::Float(x) => x.to_string(),
+        MetadataValue::Boolean(b) => b.to_string(),
+        MetadataValue::List(_) => serde_json::to_string(value).unwrap(),
+    }
+}
+
+fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Formats `[key="value", ...]` for the given attributes, or nothing when there are none.
+fn dot_attributes(attributes: Vec<(String, String)>) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let attributes: Vec<String> = attributes
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", dot_quote(&key), dot_quote(&value)))
+        .collect();
+    format!(" [{}]", attributes.join(", "))
+}
+
+fn sorted_metadata(metadata: &HashMap<String, MetadataValue>) -> Vec<(&String, &MetadataValue)> {
+    let mut entries: Vec<(&String, &MetadataValue)> = metadata.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+fn write_dot_subgraph(
+    out: &mut String,
+    hierarchy: &Hierarchy,
+    subgraph: Option<&str>,
+    depth: usize,
+) {
+    let indent = "    ".repeat(depth);
+    for node in hierarchy.members(subgraph) {
+        let mut attributes = Vec::new();
+        if !node.r#type.is_empty() {
+            attributes.push(("type".to_string(), node.r#type.clone()));
+        }
+        if node.x != 0.0 || node.y != 0.0 {
+            attributes.push(("pos".to_string(), format!("{},{}", node.x, node.y)));
+        }
+        for (key, value) in sorted_metadata(&node.metadata) {
+            attributes.push((key.clone(), value_text(value)));
+        }
+        out.push_str(&format!(
+            "{}{}{};\n",
+            indent,
+            dot_quote(&node.id),
+            dot_attributes(attributes)
+        ));
+    }
+    for child in hierarchy.children(subgraph) {
+        let label = child.rsplit('.').next().unwrap();
+        out.push_str(&format!(
+            "{}subgraph {} {{\n{}    label={};\n",
+            indent,
+            dot_quote(&cluster_id(child)),
+            indent,
+            dot_quote(label)
+        ));
+        write_dot_subgraph(out, hierarchy, Some(child), depth + 1);
+        out.push_str(&format!("{}}}\n", indent));
+    }
+}
+
+/// Writes the graph in the Graphviz DOT language.
+///
+/// Node types, positions and attributes become DOT attributes, with positions
+/// written as `pos`. Each subgraph becomes a `subgraph cluster_*` block
+/// labelled with its name, nested like the GGL blocks. Edges are written after
+/// all nodes, with their id as an `id` attribute.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write
+/// * `directed` - Write a `digraph` with `->` edges instead of a `graph` with `--`
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::export::to_dot;
+/// use graph_generation_language::types::{Edge, Graph, Node};
+///
+/// let mut graph = Graph::new();
+/// graph.add_node(Node::new("a.gw".to_string()).with_subgraph("a".to_string()));
+/// graph.add_node(Node::new("core".to_string()));
+/// graph.add_edge(Edge::new("up".to_string(), "a.gw".to_string(), "core".to_string()));
+///
+/// let dot = to_dot(&graph, true);
+/// assert!(dot.starts_with("digraph {"));
+/// assert!(dot.contains("subgraph \"cluster_a\" {"));
+/// assert!(dot.contains("\"a.gw\" -> \"core\" [\"id\"=\"up\"];"));
+/// ```
+pub fn to_dot(graph: &Graph, directed: bool) -> String {
+    let (keyword, op) = if directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    let mut out = format!("{} {{\n", keyword);
+    write_dot_subgraph(&mut out, &Hierarchy::new(graph), None, 1);
+
+    let mut edges: Vec<_> = graph.edges.values().collect();
+    edges.sort_by(|a, b| a.id.cmp(&b.id));
+    for edge in edges {
+        let mut attributes = vec![("id".to_string(), edge.id.clone())];
+        if !edge.r#type.is_empty() {
+            attributes.push(("type".to_string(), edge.r#type.clone()));
+        }
+        for (key, value) in sorted_metadata(&edge.metadata) {
+            attributes.push((key.clone(), value_text(value)));
+        }
+        out.push_str(&format!(
+            "    {} {} {}{};\n",
+            dot_quote(&edge.source),
+            op,
+            dot_quote(&edge.target),
+            dot_attributes(attributes)
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// The GraphML type able to hold every value of an attribute.
+fn graphml_type<'a>(values: impl Iterator<Item = &'a MetadataValue>) -> &'static str {
+    let mut kind = None;
+    for value in values {
+        let next = match value {
+            MetadataValue::Integer(_) => "long",
+            MetadataValue::Float(_) => "double",
+            MetadataValue::Boolean(_) => "boolean",
+            MetadataValue::String(_) | MetadataValue::List(_) => "string",
+        };
+        kind = Some(match (kind, next) {
+            (None, next) => next,
+            (Some(previous), next) if previous == next => next,
+            (Some("long"), "double") | (Some("double"), "long") => "double",
+            _ => "string",
+        });
+    }
+    kind.unwrap_or("string")
+}
+
+fn write_graphml_data(out: &mut String, indent: &str, key: &str, value: &str) {
+    out.push_str(&format!(
+        "{}<data key=\"{}\">{}</data>\n",
+        indent,
+        xml_escape(key),
+        xml_escape(value)
+    ));
+}
+
+fn write_graphml_graph(
+    out: &mut String,
+    hierarchy: &Hierarchy,
+    subgraph: Option<&str>,
+    depth: usize,
+    edgedefault: &str,
+) {
+    let indent = "  ".repeat(depth);
+    let id = subgraph.map_or_else(|| "G".to_string(), |path| format!("{}:", cluster_id(path)));
+    out.push_str(&format!(
+        "{}<graph id=\"{}\" edgedefault=\"{}\">\n",
+        indent,
+        xml_escape(&id),
+        edgedefault
+    ));
+    let inner = format!("{}  ", indent);
+    let data = format!("{}    ", indent);
+    for node in hierarchy.members(subgraph) {
+        out.push_str(&format!(
+            "{}<node id=\"{}\">\n",
+            inner,
+            xml_escape(&node.id)
+        ));
+        if !node.r#type.is_empty() {
+            write_graphml_data(out, &data, "node_type", &node.r#type);
+        }
+        write_graphml_data(out, &data, "node_x", &node.x.to_string());
+        write_graphml_data(out, &data, "node_y", &node.y.to_string());
+        for (key, value) in sorted_metadata(&node.metadata) {
+            write_graphml_data(
+                out,
+                &data,
+                &format!("node_attr_{}", key),
+                &value_text(value),
+            );
+        }
+        out.push_str(&format!("{}</node>\n", inner));
+    }
+    for child in hierarchy.children(subgraph) {
+        out.push_str(&format!(
+            "{}<node id=\"{}\">\n",
+            inner,
+            xml_escape(&cluster_id(child))
+        ));
+        write_graphml_graph(out, hierarchy, Some(child), depth + 2, edgedefault);
+        out.push_str(&format!("{}</node>\n", inner));
+    }
+    if subgraph.is_some() {
+        out.push_str(&format!("{}</graph>\n", indent));
+    }
+}
+
+/// Writes the graph as a GraphML document.
+///
+/// Node types and positions and edge types are declared as GraphML keys
+/// named `node_type`, `node_x`, `node_y` and `edge_type`, and attributes as
+/// keys named `node_attr_*` and `edge_attr_*`, typed from the values they
+/// hold. Each subgraph becomes a node holding a nested graph, with ids
+/// prefixed `cluster_` as in DOT output. Edges are listed in the top-level
+/// graph, which contains both endpoints of every edge.
+///
+/// Fails if the id given to a subgraph is already the id of a node or of
+/// another subgraph, e.g. for a node `cluster_dc` next to a subgraph `dc`.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write
+/// * `directed` - Whether edges default to directed
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::export::to_graphml;
+/// use graph_generation_language::types::{Graph, Node};
+///
+/// let mut graph = Graph::new();
+/// graph.add_node(Node::new("a.gw".to_string()).with_subgraph("a".to_string()));
+///
+/// let graphml = to_graphml(&graph, false).unwrap();
+/// assert!(graphml.contains("<node id=\"cluster_a\">"));
+/// assert!(graphml.contains("<graph id=\"cluster_a:\" edgedefault=\"undirected\">"));
+///
+/// graph.add_node(Node::new("cluster_a".to_string()));
+/// assert!(to_graphml(&graph, false).is_err());
+/// ```
+pub fn to_graphml(graph: &Graph, directed: bool) -> Result<String, String> {
+    let hierarchy = Hierarchy::new(graph);
+    let mut clusters: HashMap<String, &str> = HashMap::new();
+    for subgraph in hierarchy.subgraphs() {
+        let id = cluster_id(subgraph);
+        if graph.nodes.contains_key(&id) {
+            return Err(format!(
+                "subgraph {} would be written as {}, which is also a node id",
+                subgraph, id
+            ));
+        }
+        if let Some(other) = clusters.insert(id.clone(), subgraph) {
+            return Err(format!(
+                "subgraphs {} and {} would both be written as {}",
+                other, subgraph, id
+            ));
+        }
+    }
+
+    let edgedefault = if directed { "directed" } else { "undirected" };
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+
+    let mut node_values: BTreeMap<&str, Vec<&MetadataValue>> = BTreeMap::new();
+    for node in graph.nodes.values() {
+        for (key, value) in &node.metadata {
+            node_values.entry(key).or_default().push(value);
+        }
+    }
+    let mut edge_values: BTreeMap<&str, Vec<&MetadataValue>> = BTreeMap::new();
+    for edge in graph.edges.values() {
+        for (key, value) in &edge.metadata {
+            edge_values.entry(key).or_default().push(value);
+        }
+    }
+
+    // Attributes get their own key namespace, so one named `type` or `x`
+    // cannot clash with the built-in fields
+    let mut keys = vec![
+        ("node", "node_type".to_string(), "type", "string"),
+        ("node", "node_x".to_string(), "x", "double"),
+        ("node", "node_y".to_string(), "y", "double"),
+    ];
+    for (key, values) in &node_values {
+        keys.push((
+            "node",
+            format!("node_attr_{}", key),
+            key,
+            graphml_type(values.iter().copied()),
+        ));
+    }
+    keys.push(("edge", "edge_type".to_string(), "type", "string"));
+    for (key, values) in &edge_values {
+        keys.push((
+            "edge",
+            format!("edge_attr_{}", key),
+            key,
+            graphml_type(values.iter().copied()),
+        ));
+    }
+    for (domain, id, name, kind) in keys {
+        out.push_str(&format!(
+            "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>\n",
+            xml_escape(&id),
+            domain,
+            xml_escape(name),
+            kind
+        ));
+    }
+
+    write_graphml_graph(&mut out, &hierarchy, None, 1, edgedefault);
+
+    let mut edges: Vec<_> = graph.edges.values().collect();
+    edges.sort_by(|a, b| a.id.cmp(&b.id));
+    for edge in edges {
+        out.push_str(&format!(
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\">\n",
+            xml_escape(&edge.id),
+            xml_escape(&edge.source),
+            xml_escape(&edge.target)
+        ));
+        if !edge.r#type.is_empty() {
+            write_graphml_data(&mut out, "      ", "edge_type", &edge.r#type);
+        }
+        for (key, value) in sorted_metadata(&edge.metadata) {
+            write_graphml_data(
+                &mut out,
+                "      ",
+                &format!("edge_attr_{}", key),
+                &value_text(value),
+            );
+        }
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hierarchy_registers_empty_parents() {
+        let mut graph = Graph::new();
+        graph.add_node(Node::new("dc.rack1.gw".to_string()).with_subgraph("dc.rack1".to_string()));
+        graph.add_node(Node::new("core".to_string()));
+
+        let hierarchy = Hierarchy::new(&graph);
+        assert_eq!(hierarchy.children(None), vec!["dc"]);
+        assert_eq!(hierarchy.children(Some("dc")), vec!["dc.rack1"]);
+        assert!(hierarchy.members(Some("dc")).is_empty());
+        assert_eq!(hierarchy.members(None)[0].id, "core");
+    }
+
+    #[test]
+    fn test_graphml_types_and_escaping() {
+        let int = MetadataValue::Integer(1);
+        let float = MetadataValue::Float(0.5);
+        let text = MetadataValue::String("a".to_string());
+        assert_eq!(graphml_type([&int, &int].into_iter()), "long");
+        assert_eq!(graphml_type([&int, &float].into_iter()), "double");
+        assert_eq!(graphml_type([&int, &text].into_iter()), "string");
+        assert_eq!(xml_escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+        assert_eq!(dot_quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+    }
+
+    #[test]
+    fn test_graphml_attributes_do_not_clash_with_fields() {
+        let mut graph = Graph::new();
+        graph.add_node(
+            Node::new("a".to_string())
+                .with_type("server".to_string())
+                .with_metadata("type".to_string(), MetadataValue::Integer(2))
+                .with_metadata("x".to_string(), MetadataValue::Float(0.5)),
+        );
+        graph.add_node(Node::new("b.c".to_string()).with_subgraph("b".to_string()));
+        graph.add_node(Node::new("cluster_b.d".to_string()).with_subgraph("cluster_b".to_string()));
+
+        let error = to_graphml(&graph, false).unwrap_err();
+        assert!(error.contains("subgraphs b and cluster_b"), "{}", error);
+        graph.remove_node("cluster_b.d");
+
+        let graphml = to_graphml(&graph, false).unwrap();
+        assert_eq!(graphml.matches("<key id=\"node_type\"").count(), 1);
+        assert!(graphml.contains(
+            "<key id=\"node_attr_type\" for=\"node\" attr.name=\"type\" attr.type=\"long\"/>"
+        ));
+        assert!(graphml.contains("<data key=\"node_type\">server</data>"));
+        assert!(graphml.contains("<data key=\"node_attr_type\">2</data>"));
+        assert!(graphml.contains("<data key=\"node_attr_x\">0.5</data>"));
+
+        graph.add_node(Node::new("cluster_b".to_string()));
+        let error = to_graphml(&graph, false).unwrap_err();
+        assert!(error.contains("also a node id"), "{}", error);
+    }
+}
@@ -0,0 +1,669 @@
+//! # Built-in Generators
+//!
+//! Parameter schemas of the generators that ship with GGL. This table is the
+//! single list of built-in generator names: it populates every engine's
+//! [`GeneratorRegistry`](super::GeneratorRegistry) and backs
+//! [`get_generator`](super::get_generator).
+
+use super::registry::{FnGenerator, ParamSpec, ParamType};
+use super::*;
+
+/// A non-negative integer, which covers every count and size.
+fn integer(name: &str, description: &str) -> ParamSpec {
+    ParamSpec::new(name, ParamType::Integer, description).with_min(0.0)
+}
+
+fn integer_list(name: &str, description: &str) -> ParamSpec {
+    ParamSpec::new(name, ParamType::IntegerList, description).with_min(0.0)
+}
+
+/// A non-negative number.
+fn float(name: &str, description: &str) -> ParamSpec {
+    ParamSpec::new(name, ParamType::Float, description).with_min(0.0)
+}
+
+fn probability(name: &str, description: &str) -> ParamSpec {
+    float(name, description).with_max(1.0)
+}
+
+fn probability_matrix(name: &str, description: &str) -> ParamSpec {
+    ParamSpec::new(name, ParamType::Matrix, description)
+        .with_min(0.0)
+        .with_max(1.0)
+}
+
+fn string(name: &str, description: &str) -> ParamSpec {
+    ParamSpec::new(name, ParamType::String, description)
+}
+
+fn boolean(name: &str, description: &str, default: bool) -> ParamSpec {
+    ParamSpec::new(name, ParamType::Boolean, description)
+        .with_default(MetadataValue::Boolean(default))
+}
+
+fn prefix() -> ParamSpec {
+    string("prefix", "Node name prefix").with_default(MetadataValue::String("n".to_string()))
+}
+
+fn seed() -> ParamSpec {
+    ParamSpec::new("seed", ParamType::Integer, "Seed for reproducible output")
+}
+
+fn directed() -> ParamSpec {
+    boolean("directed", "Whether edges should be directed", false)
+}
+
+fn nodes() -> ParamSpec {
+    integer("nodes", "Number of nodes to generate").required()
+}
+
+fn rows_and_cols(generator: FnGenerator) -> FnGenerator {
+    generator
+        .with_param(integer("rows", "Number of rows").required().with_min(1.0))
+        .with_param(
+            integer("cols", "Number of columns")
+                .required()
+                .with_min(1.0),
+        )
+}
+
+/// Generators whose only parameters are `nodes`, `seed` and `prefix`.
+fn random_nodes(name: &str, description: &str, function: GeneratorFn) -> FnGenerator {
+    FnGenerator::new(name, description, function)
+        .with_param(nodes())
+        .with_param(seed())
+        .with_param(prefix())
+}
+
+/// Returns every built-in generator, in the order of the module documentation.
+pub(crate) fn builtin_generators() -> Vec<FnGenerator> {
+    let float_default =
+        |spec: ParamSpec, default: f64| spec.with_default(MetadataValue::Float(default));
+    let integer_default =
+        |spec: ParamSpec, default: i64| spec.with_default(MetadataValue::Integer(default));
+    let string_default = |spec: ParamSpec, default: &str| {
+        spec.with_default(MetadataValue::String(default.to_string()))
+    };
+    let lattice_periodic = || boolean("periodic", "Wrap both directions into a torus", false);
+
+    vec![
+        FnGenerator::new(
+            "complete",
+            "Complete graphs where every node connects to every other node",
+            generate_complete,
+        )
+        .with_param(nodes())
+        .with_param(prefix())
+        .with_param(directed()),
+        FnGenerator::new("path", "Linear chains of connected nodes", generate_path)
+            .with_param(nodes().with_min(1.0))
+            .with_param(prefix())
+            .with_param(directed()),
+        FnGenerator::new("cycle", "Circular chains of nodes", generate_cycle)
+            .with_param(nodes())
+            .with_param(prefix())
+            .with_param(directed()),
+        rows_and_cols(FnGenerator::new(
+            "grid",
+            "2D grid structures with optional periodic boundaries",
+            generate_grid,
+        ))
+        .with_param(prefix())
+        .with_param(boolean("periodic", "Connect opposite borders", false)),
+        FnGenerator::new(
+            "star",
+            "Star topologies with one central hub",
+            generate_star,
+        )
+        .with_param(nodes())
+        .with_param(prefix())
+        .with_param(directed()),
+        FnGenerator::new(
+            "tree",
+            "Balanced trees with specified branching and depth",
+            generate_tree,
+        )
+        .with_param(integer("branching", "Number of children per node").required())
+        .with_param(integer("depth", "Number of levels below the root").required())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "barabasi_albert",
+            "Scale-free networks using linear-time preferential attachment",
+            generate_barabasi_albert,
+        )
+        .with_param(nodes())
+        .with_param(
+            integer("edges_per_node", "Number of edges added with each new node").required(),
+        )
+        .with_param(string_default(
+            string("initial_graph", "Seed graph: complete, star, path or cycle"),
+            "complete",
+        ))
+        .with_param(integer(
+            "initial_nodes",
+            "Number of nodes in the seed graph (default: edges_per_node + 1)",
+        ))
+        .with_param(boolean(
+            "directed",
+            "Attach proportionally to in-degree + 1 instead of degree",
+            false,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "powerlaw_cluster",
+            "Scale-free networks with tunable clustering (Holme-Kim)",
+            generate_powerlaw_cluster,
+        )
+        .with_param(nodes())
+        .with_param(
+            integer("edges_per_node", "Number of edges added with each new node").required(),
+        )
+        .with_param(float_default(
+            probability("triad_probability", "Probability of a triad-formation step"),
+            0.5,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "forest_fire",
+            "Directed, densifying networks grown by forest-fire burning",
+            generate_forest_fire,
+        )
+        .with_param(nodes())
+        .with_param(float_default(
+            probability(
+                "forward_probability",
+                "Forward burning probability, below 1",
+            ),
+            0.37,
+        ))
+        .with_param(float_default(
+            probability(
+                "backward_probability",
+                "Backward burning probability, below 1",
+            ),
+            0.32,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "watts_strogatz",
+            "Small-world networks built by rewiring a ring lattice",
+            generate_watts_strogatz,
+        )
+        .with_param(nodes())
+        .with_param(
+            integer(
+                "k",
+                "Number of nearest neighbours in the ring lattice (even)",
+            )
+            .required(),
+        )
+        .with_param(float_default(probability("p", "Rewiring probability"), 0.1))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "newman_watts",
+            "Small-world networks built by adding shortcuts to a ring lattice",
+            generate_newman_watts,
+        )
+        .with_param(nodes())
+        .with_param(
+            integer(
+                "k",
+                "Number of nearest neighbours in the ring lattice (even)",
+            )
+            .required(),
+        )
+        .with_param(float_default(
+            probability("p", "Shortcut probability per lattice edge"),
+            0.1,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        rows_and_cols(FnGenerator::new(
+            "kleinberg",
+            "Navigable small-world grids with distance-dependent long-range links",
+            generate_kleinberg,
+        ))
+        .with_param(integer_default(
+            integer("p", "Lattice distance of local links"),
+            1,
+        ))
+        .with_param(integer_default(
+            integer("q", "Long-range links per node"),
+            1,
+        ))
+        .with_param(float_default(
+            float("r", "Distance exponent of long-range links"),
+            2.0,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "stochastic_block_model",
+            "Random graphs with planted communities",
+            generate_stochastic_block_model,
+        )
+        .with_param(integer_list("sizes", "Number of nodes in each block").required())
+        .with_param(
+            probability_matrix("probabilities", "Block-to-block edge probabilities").required(),
+        )
+        .with_param(ParamSpec::new(
+            "block_types",
+            ParamType::StringList,
+            "Node type for each block",
+        ))
+        .with_param(boolean(
+            "directed",
+            "Sample ordered pairs, allowing an asymmetric matrix",
+            false,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "random_geometric",
+            "Random points connected within a radius",
+            generate_random_geometric,
+        )
+        .with_param(nodes())
+        .with_param(float("radius", "Maximum distance between connected nodes").required())
+        .with_param(integer_default(
+            integer("dim", "Dimension of the space, 2 or 3")
+                .with_min(2.0)
+                .with_max(3.0),
+            2,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "waxman",
+            "Random points connected with distance-decaying probability",
+            generate_waxman,
+        )
+        .with_param(nodes())
+        .with_param(float_default(
+            probability("beta", "Overall link probability scale"),
+            0.4,
+        ))
+        .with_param(float_default(
+            float(
+                "alpha",
+                "Distance sensitivity, larger values favour long links",
+            ),
+            0.1,
+        ))
+        .with_param(float(
+            "max_distance",
+            "Distance scale L (default: maximum pairwise distance)",
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "hyperbolic_random_graph",
+            "Scale-free, clustered graphs from random points in the hyperbolic disk",
+            generate_hyperbolic_random_graph,
+        )
+        .with_param(nodes())
+        .with_param(float_default(
+            float("average_degree", "Target average degree"),
+            6.0,
+        ))
+        .with_param(float_default(
+            float(
+                "exponent",
+                "Power-law exponent of the degree distribution, above 2",
+            )
+            .with_min(2.0),
+            3.0,
+        ))
+        .with_param(float_default(
+            probability(
+                "temperature",
+                "Between 0 and 1; higher values weaken clustering",
+            ),
+            0.0,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "delaunay",
+            "Delaunay triangulations with optional Gabriel or relative-neighbourhood pruning",
+            generate_delaunay,
+        )
+        .with_param(integer(
+            "nodes",
+            "Number of points to sample, unless points is given",
+        ))
+        .with_param(ParamSpec::new(
+            "points",
+            ParamType::Matrix,
+            "List of [x, y] positions to triangulate",
+        ))
+        .with_param(string_default(
+            string("prune", "none, gabriel or relative_neighborhood"),
+            "none",
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "hypercube",
+            "Hypercubes connecting bit strings that differ in one bit",
+            generate_hypercube,
+        )
+        .with_param(
+            integer("dimension", "Number of dimensions")
+                .required()
+                .with_max(24.0),
+        )
+        .with_param(prefix()),
+        FnGenerator::new("torus", "n-dimensional tori and meshes", generate_torus)
+            .with_param(
+                integer_list("dims", "Side length of each dimension")
+                    .required()
+                    .with_min(1.0),
+            )
+            .with_param(boolean("periodic", "Whether axes wrap around", true))
+            .with_param(prefix()),
+        FnGenerator::new(
+            "butterfly",
+            "Butterfly and wrapped butterfly networks",
+            generate_butterfly,
+        )
+        .with_param(
+            integer("dimension", "Number of dimensions")
+                .required()
+                .with_min(1.0)
+                .with_max(20.0),
+        )
+        .with_param(boolean(
+            "wrapped",
+            "Merge the last level into the first",
+            false,
+        ))
+        .with_param(prefix()),
+        FnGenerator::new(
+            "de_bruijn",
+            "Directed de Bruijn shift-register graphs",
+            generate_de_bruijn,
+        )
+        .with_param(integer("symbols", "Alphabet size").required().with_min(2.0))
+        .with_param(integer("length", "Word length").required().with_min(1.0))
+        .with_param(prefix()),
+        FnGenerator::new(
+            "kautz",
+            "Directed Kautz shift-register graphs",
+            generate_kautz,
+        )
+        .with_param(integer("symbols", "Alphabet size").required().with_min(2.0))
+        .with_param(integer("length", "Word length").required().with_min(1.0))
+        .with_param(prefix()),
+        FnGenerator::new(
+            "fat_tree",
+            "k-ary fat-tree data-centre topologies",
+            generate_fat_tree,
+        )
+        .with_param(
+            integer("k", "Switch port count (even)")
+                .required()
+                .with_min(2.0)
+                .with_max(128.0),
+        )
+        .with_param(boolean("hosts", "Whether to generate the host layer", true))
+        .with_param(string_default(string("prefix", "Node name prefix"), "")),
+        rows_and_cols(FnGenerator::new(
+            "triangular_lattice",
+            "Triangular lattices with node positions",
+            generate_triangular_lattice,
+        ))
+        .with_param(lattice_periodic())
+        .with_param(prefix()),
+        rows_and_cols(FnGenerator::new(
+            "hexagonal_lattice",
+            "Hexagonal (honeycomb) lattices with node positions",
+            generate_hexagonal_lattice,
+        ))
+        .with_param(lattice_periodic())
+        .with_param(prefix()),
+        rows_and_cols(FnGenerator::new(
+            "kagome",
+            "Kagome lattices with node positions",
+            generate_kagome,
+        ))
+        .with_param(lattice_periodic())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "wheel",
+            "A hub joined to every node of a cycle",
+            generate_wheel,
+        )
+        .with_param(
+            integer("nodes", "Total number of nodes including the hub")
+                .required()
+                .with_min(4.0),
+        )
+        .with_param(prefix()),
+        FnGenerator::new("ladder", "Two paths joined by rungs", generate_ladder)
+            .with_param(integer("length", "Number of rungs").required())
+            .with_param(prefix()),
+        FnGenerator::new(
+            "circular_ladder",
+            "Two cycles joined by rungs (prism graphs)",
+            generate_circular_ladder,
+        )
+        .with_param(
+            integer("length", "Number of rungs")
+                .required()
+                .with_min(3.0),
+        )
+        .with_param(prefix()),
+        FnGenerator::new(
+            "lollipop",
+            "A clique with a path attached",
+            generate_lollipop,
+        )
+        .with_param(
+            integer("clique", "Size of the complete graph")
+                .required()
+                .with_min(2.0),
+        )
+        .with_param(integer("path", "Number of nodes in the tail").required())
+        .with_param(prefix()),
+        FnGenerator::new("barbell", "Two cliques joined by a path", generate_barbell)
+            .with_param(
+                integer("clique", "Size of each complete graph")
+                    .required()
+                    .with_min(2.0),
+            )
+            .with_param(integer("path", "Number of nodes on the connecting path").required())
+            .with_param(prefix()),
+        FnGenerator::new(
+            "complete_bipartite",
+            "Complete bipartite graphs",
+            generate_complete_bipartite,
+        )
+        .with_param(integer("left", "Number of nodes in the first part").required())
+        .with_param(integer("right", "Number of nodes in the second part").required())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "complete_multipartite",
+            "Complete multipartite graphs",
+            generate_complete_multipartite,
+        )
+        .with_param(integer_list("sizes", "Number of nodes in each part").required())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "turan",
+            "Turán graphs (balanced complete multipartite)",
+            generate_turan,
+        )
+        .with_param(integer("nodes", "Number of nodes").required())
+        .with_param(integer("parts", "Number of parts, between 1 and nodes").required())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "friendship",
+            "Triangles sharing a common hub",
+            generate_friendship,
+        )
+        .with_param(
+            integer("triangles", "Number of triangles")
+                .required()
+                .with_min(1.0),
+        )
+        .with_param(prefix()),
+        FnGenerator::new(
+            "named",
+            "Well-known fixed graphs such as Petersen or Zachary's karate club",
+            generate_named,
+        )
+        .with_param(string("name", "Name of the graph, e.g. petersen or karate_club").required())
+        .with_param(prefix()),
+        random_nodes(
+            "random_tree",
+            "Uniformly random labelled trees",
+            generate_random_tree,
+        ),
+        random_nodes(
+            "random_recursive_tree",
+            "Trees grown by attaching to uniformly chosen nodes",
+            generate_random_recursive_tree,
+        ),
+        random_nodes(
+            "preferential_attachment_tree",
+            "Trees grown by degree-proportional attachment",
+            generate_preferential_attachment_tree,
+        ),
+        FnGenerator::new(
+            "caterpillar",
+            "Random caterpillars (a path with attached leaves)",
+            generate_caterpillar,
+        )
+        .with_param(
+            integer("spine", "Number of nodes on the central path")
+                .required()
+                .with_min(1.0),
+        )
+        .with_param(integer("leaves", "Number of leaves to attach").required())
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "random_forest",
+            "Random forests with a given number of trees",
+            generate_random_forest,
+        )
+        .with_param(integer("nodes", "Number of nodes").required())
+        .with_param(
+            integer(
+                "trees",
+                "Number of connected components, between 1 and nodes",
+            )
+            .required(),
+        )
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "configuration_model",
+            "Random graphs with a given or sampled degree sequence",
+            generate_configuration_model,
+        )
+        .with_param(integer_list("degrees", "Degree of each node"))
+        .with_param(string(
+            "distribution",
+            "Distribution to sample degrees from: regular, uniform, poisson or powerlaw",
+        ))
+        .with_param(integer("nodes", "Number of nodes, with distribution"))
+        .with_param(integer(
+            "degree",
+            "Degree of every node, with the regular distribution",
+        ))
+        .with_param(integer(
+            "min_degree",
+            "Smallest sampled degree (default: 0 for uniform, 1 for powerlaw)",
+        ))
+        .with_param(integer(
+            "max_degree",
+            "Largest sampled degree (default: nodes - 1)",
+        ))
+        .with_param(float_default(
+            float("mean", "Mean of the poisson distribution"),
+            4.0,
+        ))
+        .with_param(float_default(
+            float("exponent", "Exponent of the powerlaw distribution").with_min(1.0),
+            2.5,
+        ))
+        .with_param(boolean(
+            "remove_self_loops",
+            "Drop edges from a node to itself",
+            false,
+        ))
+        .with_param(boolean(
+            "remove_multi_edges",
+            "Drop repeated edges between the same pair",
+            false,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "havel_hakimi",
+            "Deterministic realisations of a graphical degree sequence",
+            generate_havel_hakimi,
+        )
+        .with_param(integer_list("degrees", "Degree of each node").required())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "rmat",
+            "Graph500-style R-MAT benchmark graphs",
+            generate_rmat,
+        )
+        .with_param(
+            integer("scale", "Base-2 logarithm of the number of nodes")
+                .required()
+                .with_max(32.0),
+        )
+        .with_param(integer_default(
+            integer("edge_factor", "Edges per node"),
+            16,
+        ))
+        .with_param(float_default(
+            probability("a", "Probability of the top-left quadrant"),
+            0.57,
+        ))
+        .with_param(float_default(
+            probability("b", "Probability of the top-right quadrant"),
+            0.19,
+        ))
+        .with_param(float_default(
+            probability("c", "Probability of the bottom-left quadrant"),
+            0.19,
+        ))
+        .with_param(float_default(
+            probability("d", "Probability of the bottom-right quadrant"),
+            0.05,
+        ))
+        .with_param(boolean("permute", "Shuffle node labels", true))
+        .with_param(boolean(
+            "remove_self_loops",
+            "Drop edges from a node to itself",
+            false,
+        ))
+        .with_param(boolean("remove_multi_edges", "Drop repeated edges", false))
+        .with_param(seed())
+        .with_param(prefix()),
+        FnGenerator::new(
+            "stochastic_kronecker",
+            "Stochastic Kronecker graphs from an initiator matrix",
+            generate_stochastic_kronecker,
+        )
+        .with_param(probability_matrix("initiator", "Square matrix of probabilities").required())
+        .with_param(integer("power", "Number of Kronecker multiplications").required())
+        .with_param(boolean(
+            "remove_self_loops",
+            "Drop edges from a node to itself",
+            false,
+        ))
+        .with_param(seed())
+        .with_param(prefix()),
+    ]
+}
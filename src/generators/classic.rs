@@ -0,0 +1,441 @@
+//! # Classic Graph Families
+//!
+//! Small deterministic families that appear as standard fixtures in graph
+//! algorithm test suites. Node IDs are numbered `{prefix}0`, `{prefix}1`, … in
+//! the same order NetworkX uses for the equivalent constructors, so results
+//! can be compared node for node.
+
+use super::{get_param_int, get_param_int_list, get_param_string};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::HashMap;
+
+/// Adds an edge between nodes `u` and `v` with the usual `e{u}_{v}` ID.
+fn link(graph: &mut Graph, prefix: &str, u: usize, v: usize) {
+    graph.add_edge(Edge::new(
+        format!("e{}_{}", u, v),
+        format!("{}{}", prefix, u),
+        format!("{}{}", prefix, v),
+    ));
+}
+
+/// Adds nodes `0..n` without attributes.
+fn add_nodes(graph: &mut Graph, prefix: &str, n: usize) {
+    for i in 0..n {
+        graph.add_node(Node::new(format!("{}{}", prefix, i)));
+    }
+}
+
+/// Connects every pair of nodes in `first..first + size`.
+fn add_clique(graph: &mut Graph, prefix: &str, first: usize, size: usize) {
+    for u in first..first + size {
+        for v in u + 1..first + size {
+            link(graph, prefix, u, v);
+        }
+    }
+}
+
+/// Builds a complete multipartite graph with a `part` attribute on every node.
+fn multipartite(sizes: &[usize], prefix: &str) -> Graph {
+    let mut graph = Graph::new();
+    let mut part_of = Vec::new();
+    for (part, &size) in sizes.iter().enumerate() {
+        for _ in 0..size {
+            graph.add_node(
+                Node::new(format!("{}{}", prefix, part_of.len()))
+                    .with_metadata("part".to_string(), MetadataValue::Integer(part as i64)),
+            );
+            part_of.push(part);
+        }
+    }
+
+    for u in 0..part_of.len() {
+        for v in u + 1..part_of.len() {
+            if part_of[u] != part_of[v] {
+                link(&mut graph, prefix, u, v);
+            }
+        }
+    }
+    graph
+}
+
+/// Builds two rails of `length` nodes joined by rungs, optionally closed into rings.
+fn ladder(length: usize, prefix: &str, circular: bool) -> Graph {
+    let mut graph = Graph::new();
+    add_nodes(&mut graph, prefix, 2 * length);
+    for i in 0..length {
+        link(&mut graph, prefix, i, length + i);
+        if i + 1 < length {
+            link(&mut graph, prefix, i, i + 1);
+            link(&mut graph, prefix, length + i, length + i + 1);
+        }
+    }
+    if circular {
+        link(&mut graph, prefix, length - 1, 0);
+        link(&mut graph, prefix, 2 * length - 1, length);
+    }
+    graph
+}
+
+/// Generates a wheel graph.
+///
+/// A hub node `{prefix}0` connected to every node of a cycle formed by the
+/// remaining nodes.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Total number of nodes including the hub (at least 4)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: 2(n-1)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_wheel;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(6));
+///
+/// let graph = generate_wheel(&params).unwrap();
+/// assert_eq!(graph.node_count(), 6);
+/// assert_eq!(graph.edge_count(), 10);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate wheel {
+///     nodes: 8;
+/// }
+/// ```
+pub fn generate_wheel(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if n < 4 {
+        return Err(format!("wheel needs at least 4 nodes, got {}", n));
+    }
+
+    let mut graph = Graph::new();
+    add_nodes(&mut graph, &prefix, n);
+    for i in 1..n {
+        link(&mut graph, &prefix, 0, i);
+        link(&mut graph, &prefix, i, if i + 1 < n { i + 1 } else { 1 });
+    }
+    Ok(graph)
+}
+
+/// Generates a ladder graph.
+///
+/// Two paths of `length` nodes, `{prefix}0…` and `{prefix}{length}…`, with a
+/// rung between corresponding nodes.
+///
+/// # Parameters
+///
+/// * `length` (required) - Number of rungs
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2·length
+/// * **Edges**: 3·length - 2
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate ladder {
+///     length: 5;
+/// }
+/// ```
+pub fn generate_ladder(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let length = get_param_int(params, "length")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    Ok(ladder(length, &prefix, false))
+}
+
+/// Generates a circular ladder (prism) graph.
+///
+/// A [`generate_ladder`] whose two rails are closed into cycles.
+///
+/// # Parameters
+///
+/// * `length` (required) - Number of rungs (at least 3)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2·length
+/// * **Edges**: 3·length
+/// * **Regularity**: Every node has degree 3
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate circular_ladder {
+///     length: 6;
+/// }
+/// ```
+pub fn generate_circular_ladder(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let length = get_param_int(params, "length")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if length < 3 {
+        return Err(format!(
+            "circular ladder needs at least 3 rungs, got {}",
+            length
+        ));
+    }
+    Ok(ladder(length, &prefix, true))
+}
+
+/// Generates a lollipop graph.
+///
+/// A complete graph on `clique` nodes with a path of `path` further nodes
+/// attached to its last node.
+///
+/// # Parameters
+///
+/// * `clique` (required) - Size of the complete graph (at least 2)
+/// * `path` (required) - Number of nodes in the tail
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: clique + path
+/// * **Edges**: clique(clique-1)/2 + path
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate lollipop {
+///     clique: 5;
+///     path: 3;
+/// }
+/// ```
+pub fn generate_lollipop(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let m = get_param_int(params, "clique")?;
+    let n = get_param_int(params, "path")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if m < 2 {
+        return Err(format!("clique must have at least 2 nodes, got {}", m));
+    }
+
+    let mut graph = Graph::new();
+    add_nodes(&mut graph, &prefix, m + n);
+    add_clique(&mut graph, &prefix, 0, m);
+    for i in m..m + n {
+        link(&mut graph, &prefix, i - 1, i);
+    }
+    Ok(graph)
+}
+
+/// Generates a barbell graph.
+///
+/// Two complete graphs on `clique` nodes joined by a path of `path` nodes
+/// (a single bridge edge when `path` is 0).
+///
+/// # Parameters
+///
+/// * `clique` (required) - Size of each complete graph (at least 2)
+/// * `path` (required) - Number of nodes on the connecting path
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2·clique + path
+/// * **Edges**: clique(clique-1) + path + 1
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate barbell {
+///     clique: 4;
+///     path: 2;
+/// }
+/// ```
+pub fn generate_barbell(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let m = get_param_int(params, "clique")?;
+    let n = get_param_int(params, "path")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if m < 2 {
+        return Err(format!("clique must have at least 2 nodes, got {}", m));
+    }
+
+    let mut graph = Graph::new();
+    add_nodes(&mut graph, &prefix, 2 * m + n);
+    add_clique(&mut graph, &prefix, 0, m);
+    add_clique(&mut graph, &prefix, m + n, m);
+    for i in m..=m + n {
+        link(&mut graph, &prefix, i - 1, i);
+    }
+    Ok(graph)
+}
+
+/// Generates a complete bipartite graph.
+///
+/// Nodes `{prefix}0…` form the left part and the following nodes the right
+/// part; every left node connects to every right node. Each node stores its
+/// side (0 or 1) in a `part` attribute.
+///
+/// # Parameters
+///
+/// * `left` (required) - Number of nodes in the first part
+/// * `right` (required) - Number of nodes in the second part
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: left + right
+/// * **Edges**: left · right
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate complete_bipartite {
+///     left: 3;
+///     right: 4;
+/// }
+/// ```
+pub fn generate_complete_bipartite(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let left = get_param_int(params, "left")?;
+    let right = get_param_int(params, "right")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    Ok(multipartite(&[left, right], &prefix))
+}
+
+/// Generates a complete multipartite graph.
+///
+/// Nodes are split into consecutive parts of the given sizes and every pair of
+/// nodes in different parts is connected. Each node stores the index of its
+/// part in a `part` attribute.
+///
+/// # Parameters
+///
+/// * `sizes` (required) - List with the number of nodes in each part
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: sum of `sizes`
+/// * **Edges**: Σ over pairs of parts of size_a · size_b
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate complete_multipartite {
+///     sizes: [2, 3, 4];
+/// }
+/// ```
+pub fn generate_complete_multipartite(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let sizes = get_param_int_list(params, "sizes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    Ok(multipartite(&sizes, &prefix))
+}
+
+/// Generates a Turán graph.
+///
+/// The complete multipartite graph on `nodes` nodes with `parts` parts whose
+/// sizes differ by at most one. By Turán's theorem it is the densest graph on
+/// `nodes` nodes without a clique of size `parts + 1`.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes
+/// * `parts` (required) - Number of parts, between 1 and `nodes`
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate turan {
+///     nodes: 10;
+///     parts: 3;
+/// }
+/// ```
+pub fn generate_turan(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let r = get_param_int(params, "parts")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if r == 0 || r > n {
+        return Err(format!(
+            "parts must be between 1 and nodes ({}), got {}",
+            n, r
+        ));
+    }
+
+    let sizes: Vec<usize> = (0..r).map(|i| n / r + usize::from(i < n % r)).collect();
+    Ok(multipartite(&sizes, &prefix))
+}
+
+/// Generates a friendship (windmill) graph.
+///
+/// `triangles` triangles sharing the hub node `{prefix}0`; triangle `i` uses
+/// nodes `2i + 1` and `2i + 2`.
+///
+/// # Parameters
+///
+/// * `triangles` (required) - Number of triangles (at least 1)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2·triangles + 1
+/// * **Edges**: 3·triangles
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate friendship {
+///     triangles: 4;
+/// }
+/// ```
+pub fn generate_friendship(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let triangles = get_param_int(params, "triangles")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if triangles == 0 {
+        return Err("friendship graph needs at least 1 triangle".to_string());
+    }
+
+    let mut graph = Graph::new();
+    add_nodes(&mut graph, &prefix, 2 * triangles + 1);
+    for i in 0..triangles {
+        let (a, b) = (2 * i + 1, 2 * i + 2);
+        link(&mut graph, &prefix, 0, a);
+        link(&mut graph, &prefix, 0, b);
+        link(&mut graph, &prefix, a, b);
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipartite_skips_same_part() {
+        let graph = multipartite(&[2, 1], "n");
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.get_edge("e0_1").is_none());
+    }
+
+    #[test]
+    fn test_ladder_rails_and_rungs() {
+        let graph = ladder(3, "n", false);
+        assert!(graph.get_edge("e0_3").is_some());
+        assert!(graph.get_edge("e4_5").is_some());
+        assert!(graph.get_edge("e2_0").is_none());
+    }
+}
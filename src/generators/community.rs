@@ -0,0 +1,236 @@
+//! # Community Generators
+//!
+//! Generators for graphs with a planted community structure, used to
+//! benchmark community-detection algorithms against a known partition.
+
+use super::{
+    get_param_bool, get_param_float_matrix, get_param_int_list, get_param_string,
+    get_param_string_list, get_rng,
+};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::rngs::SmallRng;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Number of candidate pairs skipped before the next success of a Bernoulli(p) process.
+///
+/// Drawing geometric gaps instead of testing every pair makes sparse blocks cost
+/// time proportional to the number of edges rather than the number of pairs.
+fn geometric_skip(rng: &mut SmallRng, log_q: f64) -> usize {
+    let r: f64 = rng.gen();
+    ((1.0 - r).ln() / log_q).floor() as usize
+}
+
+/// Calls `emit` for every index in `0..total` independently with probability `p`.
+fn sample_indices(rng: &mut SmallRng, total: usize, p: f64, mut emit: impl FnMut(usize)) {
+    if p <= 0.0 || total == 0 {
+        return;
+    }
+    if p >= 1.0 {
+        (0..total).for_each(emit);
+        return;
+    }
+
+    let log_q = (1.0 - p).ln();
+    let mut index = geometric_skip(rng, log_q);
+    while index < total {
+        emit(index);
+        index = index.saturating_add(1 + geometric_skip(rng, log_q));
+    }
+}
+
+/// Generates a stochastic block model graph.
+///
+/// Nodes are partitioned into blocks of the given sizes, and each pair of nodes
+/// in blocks `a` and `b` is connected independently with probability
+/// `probabilities[a][b]`. Every node records the index of its block in a
+/// `block` attribute, and optionally a per-block node type.
+///
+/// # Parameters
+///
+/// * `sizes` (required) - List with the number of nodes in each block
+/// * `probabilities` (required) - Square matrix of block-to-block edge probabilities
+/// * `block_types` (optional) - List with a node type for each block
+/// * `directed` (optional) - Sample ordered pairs, allowing an asymmetric matrix (default: false)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: sum of `sizes`
+/// * **Edges**: expected Σ p_ab · (pairs between blocks a and b)
+/// * **Attributes**: `block` on every node
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_stochastic_block_model;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let row = |cells: &[f64]| {
+///     MetadataValue::List(cells.iter().map(|p| MetadataValue::Float(*p)).collect())
+/// };
+///
+/// let mut params = HashMap::new();
+/// params.insert(
+///     "sizes".to_string(),
+///     MetadataValue::List(vec![MetadataValue::Integer(5), MetadataValue::Integer(5)]),
+/// );
+/// params.insert(
+///     "probabilities".to_string(),
+///     MetadataValue::List(vec![row(&[1.0, 0.0]), row(&[0.0, 1.0])]),
+/// );
+///
+/// let graph = generate_stochastic_block_model(&params).unwrap();
+/// assert_eq!(graph.node_count(), 10);
+/// assert_eq!(graph.edge_count(), 20); // two disjoint K5 blocks
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate stochastic_block_model {
+///     sizes: [20, 30];
+///     probabilities: [[0.5, 0.02], [0.02, 0.4]];
+///     block_types: ["red", "blue"];
+///     seed: 1;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Benchmarks for community detection with a planted partition
+/// * Models of segregated social or biological networks
+pub fn generate_stochastic_block_model(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let sizes = get_param_int_list(params, "sizes")?;
+    let probabilities = get_param_float_matrix(params, "probabilities")?;
+    let block_types = get_param_string_list(params, "block_types")?;
+    let directed = get_param_bool(params, "directed", false);
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    let blocks = sizes.len();
+    if probabilities.len() != blocks || probabilities.iter().any(|row| row.len() != blocks) {
+        return Err(format!(
+            "probabilities must be a {}x{} matrix to match sizes",
+            blocks, blocks
+        ));
+    }
+    for (a, row) in probabilities.iter().enumerate() {
+        for (b, &p) in row.iter().enumerate() {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(format!(
+                    "probabilities[{}][{}] must be between 0 and 1, got {}",
+                    a, b, p
+                ));
+            }
+            if !directed && p != probabilities[b][a] {
+                return Err(format!(
+                    "probabilities must be symmetric for undirected graphs: [{}][{}]={} but [{}][{}]={}",
+                    a, b, p, b, a, probabilities[b][a]
+                ));
+            }
+        }
+    }
+    if let Some(ref types) = block_types {
+        if types.len() != blocks {
+            return Err(format!(
+                "block_types must have one entry per block, got {} for {} blocks",
+                types.len(),
+                blocks
+            ));
+        }
+    }
+
+    // First global node index of each block
+    let mut offsets = Vec::with_capacity(blocks);
+    let mut total = 0;
+    for &size in &sizes {
+        offsets.push(total);
+        total += size;
+    }
+
+    let mut graph = Graph::new();
+    let node_ids: Vec<String> = (0..total).map(|i| format!("{}{}", prefix, i)).collect();
+
+    for (block, (&offset, &size)) in offsets.iter().zip(&sizes).enumerate() {
+        for node_id in &node_ids[offset..offset + size] {
+            let mut node = Node::new(node_id.clone())
+                .with_metadata("block".to_string(), MetadataValue::Integer(block as i64));
+            if let Some(ref types) = block_types {
+                node = node.with_type(types[block].clone());
+            }
+            graph.add_node(node);
+        }
+    }
+
+    let mut add_edge = |u: usize, v: usize| {
+        graph.add_edge(Edge::new(
+            format!("e{}_{}", u, v),
+            node_ids[u].clone(),
+            node_ids[v].clone(),
+        ));
+    };
+
+    for a in 0..blocks {
+        let first_b = if directed { 0 } else { a };
+        for b in first_b..blocks {
+            let p = probabilities[a][b];
+            let (size_a, size_b) = (sizes[a], sizes[b]);
+            let (offset_a, offset_b) = (offsets[a], offsets[b]);
+
+            if a == b && !directed {
+                // Enumerate the pairs u < v of the block as a flattened triangle, one row per v
+                let pairs = size_a * size_a.saturating_sub(1) / 2;
+                let mut row = 1;
+                let mut row_start = 0;
+                sample_indices(&mut rng, pairs, p, |index| {
+                    while index >= row_start + row {
+                        row_start += row;
+                        row += 1;
+                    }
+                    add_edge(offset_a + index - row_start, offset_a + row);
+                });
+            } else {
+                sample_indices(&mut rng, size_a * size_b, p, |index| {
+                    let (u, v) = (offset_a + index / size_b, offset_b + index % size_b);
+                    if u != v {
+                        add_edge(u, v);
+                    }
+                });
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_sample_indices_extremes() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut all = Vec::new();
+        sample_indices(&mut rng, 5, 1.0, |i| all.push(i));
+        assert_eq!(all, vec![0, 1, 2, 3, 4]);
+
+        let mut none = Vec::new();
+        sample_indices(&mut rng, 5, 0.0, |i| none.push(i));
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_sample_indices_are_increasing() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let mut seen = Vec::new();
+        sample_indices(&mut rng, 10_000, 0.1, |i| seen.push(i));
+        assert!(seen.windows(2).all(|w| w[0] < w[1]));
+        assert!(seen.len() > 800 && seen.len() < 1200);
+    }
+}
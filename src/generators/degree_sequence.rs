@@ -0,0 +1,395 @@
+//! # Degree-Sequence Generators
+//!
+//! Generators that realise a prescribed degree sequence, so that generated
+//! graphs match degree statistics measured from real systems. Node `{prefix}i`
+//! receives the `i`-th degree of the sequence.
+
+use super::{
+    get_param_bool, get_param_float, get_param_int, get_param_int_list, get_param_int_or,
+    get_param_string, get_rng,
+};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Means above which Poisson variates are drawn from a normal approximation.
+const POISSON_NORMAL_MEAN: f64 = 64.0;
+
+/// Samples a Poisson variate by counting unit-rate arrivals before `mean`.
+///
+/// Counting takes time proportional to the mean, so large means use the
+/// normal approximation N(mean, mean) instead, rounded and clamped at zero.
+fn sample_poisson(rng: &mut SmallRng, mean: f64) -> usize {
+    if mean > POISSON_NORMAL_MEAN {
+        // Box-Muller transform; 1 - u keeps the logarithm finite
+        let u1 = 1.0 - rng.gen::<f64>();
+        let u2 = rng.gen::<f64>();
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        return (mean + mean.sqrt() * z).round().max(0.0) as usize;
+    }
+
+    let mut count = 0;
+    let mut time = -(1.0 - rng.gen::<f64>()).ln();
+    while time < mean {
+        count += 1;
+        time -= (1.0 - rng.gen::<f64>()).ln();
+    }
+    count
+}
+
+/// Reads the degree sequence from `degrees`, or samples one from a `distribution` spec.
+fn degree_sequence(
+    params: &HashMap<String, MetadataValue>,
+    rng: &mut SmallRng,
+) -> Result<Vec<usize>, String> {
+    if params.contains_key("degrees") {
+        return get_param_int_list(params, "degrees");
+    }
+
+    let distribution = match params.get("distribution") {
+        Some(MetadataValue::String(name)) => name.as_str(),
+        _ => {
+            return Err(
+                "Missing degree sequence: give a degrees list or a distribution name".to_string(),
+            )
+        }
+    };
+    let n = get_param_int(params, "nodes")?;
+    let max_degree = get_param_int_or(params, "max_degree", n.saturating_sub(1))?;
+
+    let mut degrees: Vec<usize> = match distribution {
+        "regular" => {
+            let degree = get_param_int(params, "degree")?;
+            if n * degree % 2 == 1 {
+                return Err(format!(
+                    "A {}-regular graph on {} nodes does not exist: nodes × degree must be even",
+                    degree, n
+                ));
+            }
+            return Ok(vec![degree; n]);
+        }
+        "uniform" => {
+            let min_degree = get_param_int_or(params, "min_degree", 0)?;
+            if min_degree > max_degree {
+                return Err(format!(
+                    "min_degree ({}) must not exceed max_degree ({})",
+                    min_degree, max_degree
+                ));
+            }
+            (0..n)
+                .map(|_| rng.gen_range(min_degree..=max_degree))
+                .collect()
+        }
+        "poisson" => {
+            let mean = get_param_float(params, "mean", 4.0);
+            if mean < 0.0 {
+                return Err(format!("mean must not be negative, got {}", mean));
+            }
+            (0..n)
+                .map(|_| sample_poisson(rng, mean).min(max_degree))
+                .collect()
+        }
+        "powerlaw" => {
+            let exponent = get_param_float(params, "exponent", 2.5);
+            let min_degree = get_param_int_or(params, "min_degree", 1)?.max(1);
+            if exponent <= 1.0 {
+                return Err(format!("exponent must be greater than 1, got {}", exponent));
+            }
+            if min_degree > max_degree {
+                return Err(format!(
+                    "min_degree ({}) must not exceed max_degree ({})",
+                    min_degree, max_degree
+                ));
+            }
+            // Inverse-transform sampling of a discretised Pareto tail, truncated at max_degree
+            (0..n)
+                .map(|_| loop {
+                    let u: f64 = rng.gen();
+                    let degree =
+                        (min_degree as f64 * (1.0 - u).powf(-1.0 / (exponent - 1.0))) as usize;
+                    if degree <= max_degree {
+                        break degree;
+                    }
+                })
+                .collect()
+        }
+        other => {
+            return Err(format!(
+                "Unknown degree distribution '{}', expected regular, uniform, poisson or powerlaw",
+                other
+            ))
+        }
+    };
+
+    // A sampled sequence needs an even sum; bump one node that still has room
+    if degrees.iter().sum::<usize>() % 2 == 1 {
+        let candidates: Vec<usize> = (0..n).filter(|&i| degrees[i] < max_degree).collect();
+        match candidates.choose(rng) {
+            Some(&i) => degrees[i] += 1,
+            None => {
+                let i = rng.gen_range(0..n);
+                degrees[i] -= 1;
+            }
+        }
+    }
+    Ok(degrees)
+}
+
+/// Adds nodes `0..n` with their target degree as a `degree` attribute.
+fn degree_nodes(prefix: &str, degrees: &[usize]) -> Graph {
+    let mut graph = Graph::new();
+    for (i, &degree) in degrees.iter().enumerate() {
+        graph.add_node(
+            Node::new(format!("{}{}", prefix, i))
+                .with_metadata("degree".to_string(), MetadataValue::Integer(degree as i64)),
+        );
+    }
+    graph
+}
+
+/// Generates a configuration-model random graph.
+///
+/// Each node gets as many edge "stubs" as its degree and the stubs are paired
+/// uniformly at random. The raw model can produce self-loops and parallel
+/// edges; parallel edges get IDs `e{u}_{v}_1`, `e{u}_{v}_2`, …. Removing them
+/// with `remove_self_loops`/`remove_multi_edges` gives a simple graph whose
+/// degrees fall slightly short of the sequence on the affected nodes.
+///
+/// The sequence is given directly with `degrees`, or sampled for `nodes`
+/// nodes from a `distribution`:
+///
+/// * `"regular"` - every node has `degree`
+/// * `"uniform"` - uniform between `min_degree` (default 0) and `max_degree`
+/// * `"poisson"` - Poisson with the given `mean` (default 4.0), approximated by
+///   a normal distribution for means above 64
+/// * `"powerlaw"` - P(k) ∝ k^-`exponent` (default 2.5) from `min_degree` (default 1)
+///
+/// Sampled degrees are capped at `max_degree` (default `nodes - 1`), and one
+/// sampled degree is adjusted by one when needed to make the sum even.
+///
+/// # Parameters
+///
+/// * `degrees` (optional) - List with the degree of each node
+/// * `distribution` (optional) - Distribution to sample degrees from, when `degrees` is absent
+/// * `nodes` (required with `distribution`) - Number of nodes
+/// * `remove_self_loops` (optional) - Drop edges from a node to itself (default: false)
+/// * `remove_multi_edges` (optional) - Drop repeated edges between the same pair (default: false)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: one per degree, with the target in a `degree` attribute
+/// * **Edges**: half the degree sum, before any removal
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_configuration_model;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let degrees = [3, 3, 2, 2, 1, 1];
+/// let mut params = HashMap::new();
+/// params.insert(
+///     "degrees".to_string(),
+///     MetadataValue::List(degrees.iter().map(|&d| MetadataValue::Integer(d)).collect()),
+/// );
+/// params.insert("seed".to_string(), MetadataValue::Integer(4));
+///
+/// let graph = generate_configuration_model(&params).unwrap();
+/// assert_eq!(graph.edge_count(), 6);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate configuration_model {
+///     distribution: "powerlaw";
+///     nodes: 1000;
+///     exponent: 2.2;
+///     remove_self_loops: true;
+///     remove_multi_edges: true;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Null models that preserve an observed degree distribution
+/// * Heavy-tailed networks without a growth process
+pub fn generate_configuration_model(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let prefix = get_param_string(params, "prefix", "n");
+    let remove_self_loops = get_param_bool(params, "remove_self_loops", false);
+    let remove_multi_edges = get_param_bool(params, "remove_multi_edges", false);
+    let mut rng = get_rng(params)?;
+
+    let degrees = degree_sequence(params, &mut rng)?;
+    if degrees.iter().sum::<usize>() % 2 == 1 {
+        return Err("The sum of the degree sequence must be even".to_string());
+    }
+
+    let mut stubs: Vec<usize> = degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &degree)| std::iter::repeat_n(i, degree))
+        .collect();
+    stubs.shuffle(&mut rng);
+
+    let mut graph = degree_nodes(&prefix, &degrees);
+    let mut multiplicity: HashMap<(usize, usize), usize> = HashMap::new();
+    for pair in stubs.chunks_exact(2) {
+        let (u, v) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+        if remove_self_loops && u == v {
+            continue;
+        }
+        let count = multiplicity.entry((u, v)).or_insert(0);
+        if remove_multi_edges && *count > 0 {
+            continue;
+        }
+        let id = match *count {
+            0 => format!("e{}_{}", u, v),
+            k => format!("e{}_{}_{}", u, v, k),
+        };
+        *count += 1;
+        graph.add_edge(Edge::new(
+            id,
+            format!("{}{}", prefix, u),
+            format!("{}{}", prefix, v),
+        ));
+    }
+
+    Ok(graph)
+}
+
+/// Generates the Havel-Hakimi realisation of a degree sequence.
+///
+/// Repeatedly connects the node with the largest remaining degree to the
+/// nodes with the next largest remaining degrees (ties broken by lower index).
+/// The result is deterministic and simple, and every node has exactly its
+/// requested degree. Sequences that no simple graph can realise are rejected
+/// with an explanation.
+///
+/// # Parameters
+///
+/// * `degrees` (required) - List with the degree of each node
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: one per degree, with the target in a `degree` attribute
+/// * **Edges**: half the degree sum
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate havel_hakimi {
+///     degrees: [3, 3, 2, 2, 2, 1, 1];
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Deterministic fixtures with an exact degree sequence
+/// * Starting points for degree-preserving rewiring
+pub fn generate_havel_hakimi(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let degrees = get_param_int_list(params, "degrees")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    let n = degrees.len();
+    if degrees.iter().sum::<usize>() % 2 == 1 {
+        return Err("Degree sequence is not graphical: the sum of the degrees is odd".to_string());
+    }
+    if let Some((i, &degree)) = degrees.iter().enumerate().find(|(_, &d)| d >= n.max(1)) {
+        return Err(format!(
+            "Degree sequence is not graphical: node {} has degree {} but there are only {} other nodes",
+            i,
+            degree,
+            n.saturating_sub(1)
+        ));
+    }
+
+    let mut graph = degree_nodes(&prefix, &degrees);
+    let mut remaining: Vec<(usize, usize)> = degrees.iter().copied().zip(0..n).collect();
+
+    loop {
+        remaining.retain(|&(degree, _)| degree > 0);
+        remaining.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        let Some(&(degree, u)) = remaining.first() else {
+            break;
+        };
+        if degree >= remaining.len() {
+            return Err(format!(
+                "Degree sequence is not graphical: node {} still needs {} neighbours but only {} nodes have spare degree",
+                u,
+                degree,
+                remaining.len() - 1
+            ));
+        }
+
+        remaining[0].0 = 0;
+        for entry in &mut remaining[1..=degree] {
+            entry.0 -= 1;
+            let v = entry.1;
+            graph.add_edge(Edge::new(
+                format!("e{}_{}", u.min(v), u.max(v)),
+                format!("{}{}", prefix, u.min(v)),
+                format!("{}{}", prefix, u.max(v)),
+            ));
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_sample_poisson_mean() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let total: usize = (0..10_000).map(|_| sample_poisson(&mut rng, 3.0)).sum();
+        let mean = total as f64 / 10_000.0;
+        assert!((mean - 3.0).abs() < 0.1, "mean was {}", mean);
+    }
+
+    #[test]
+    fn test_sample_poisson_large_mean() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let samples: Vec<usize> = (0..10_000)
+            .map(|_| sample_poisson(&mut rng, 400.0))
+            .collect();
+        let mean = samples.iter().sum::<usize>() as f64 / 10_000.0;
+        let variance = samples
+            .iter()
+            .map(|&k| (k as f64 - mean).powi(2))
+            .sum::<f64>()
+            / 10_000.0;
+        assert!((mean - 400.0).abs() < 1.0, "mean was {}", mean);
+        assert!((variance - 400.0).abs() < 40.0, "variance was {}", variance);
+
+        // Would take about a billion steps per draw by counting arrivals
+        assert!(sample_poisson(&mut rng, 1e9) > 0);
+    }
+
+    #[test]
+    fn test_sampled_sequence_has_even_sum() {
+        let mut rng = SmallRng::seed_from_u64(8);
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(101));
+        params.insert(
+            "distribution".to_string(),
+            MetadataValue::String("uniform".to_string()),
+        );
+        for _ in 0..20 {
+            let degrees = degree_sequence(&params, &mut rng).unwrap();
+            assert_eq!(degrees.len(), 101);
+            assert_eq!(degrees.iter().sum::<usize>() % 2, 0);
+        }
+    }
+}
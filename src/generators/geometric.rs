@@ -0,0 +1,518 @@
+//! # Geometric Generators
+//!
+//! Generators that place nodes in space and connect them according to their
+//! distance. These are the generators that populate the `x`/`y` position of
+//! every node, and they record the distance of each edge in a `weight`
+//! attribute: Euclidean for points in the unit square, hyperbolic for points
+//! in the hyperbolic disk.
+
+use super::{
+    get_param_float, get_param_float_required, get_param_int, get_param_int_or,
+    get_param_probability, get_param_string, get_rng,
+};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Euclidean distance between two points of equal dimension.
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(p, q)| (p - q) * (p - q))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Builds a node at the given point, storing a third coordinate as a `z` attribute.
+fn positioned_node(id: String, point: &[f64]) -> Node {
+    let mut node = Node::new(id).with_position(point[0], point[1]);
+    if let Some(z) = point.get(2) {
+        node = node.with_metadata("z".to_string(), MetadataValue::Float(*z));
+    }
+    node
+}
+
+/// Builds an edge between nodes `u` and `v` weighted by their distance.
+fn weighted_edge(prefix: &str, u: usize, v: usize, weight: f64) -> Edge {
+    Edge::new(
+        format!("e{}_{}", u, v),
+        format!("{}{}", prefix, u),
+        format!("{}{}", prefix, v),
+    )
+    .with_metadata("weight".to_string(), MetadataValue::Float(weight))
+}
+
+/// Generates a random geometric graph.
+///
+/// Nodes are placed uniformly at random in the unit square (or unit cube when
+/// `dim` is 3) and every pair closer than `radius` is connected. Points are
+/// bucketed into cells of side `radius`, so only neighbouring cells are
+/// compared and sparse graphs are built in roughly linear time.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `radius` (required) - Maximum distance between connected nodes
+/// * `dim` (optional) - Dimension of the space, 2 or 3 (default: 2)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n, with `x`/`y` set to the sampled coordinates (`z` attribute in 3D)
+/// * **Edges**: every pair within `radius`, with the distance as `weight`
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_random_geometric;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(50));
+/// params.insert("radius".to_string(), MetadataValue::Float(0.2));
+/// params.insert("seed".to_string(), MetadataValue::Integer(1));
+///
+/// let graph = generate_random_geometric(&params).unwrap();
+/// for node in graph.nodes.values() {
+///     assert!((0.0..1.0).contains(&node.x) && (0.0..1.0).contains(&node.y));
+/// }
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate random_geometric {
+///     nodes: 200;
+///     radius: 0.1;
+///     seed: 3;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Wireless ad-hoc and sensor networks
+/// * Spatial contact networks
+pub fn generate_random_geometric(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let radius = get_param_float_required(params, "radius")?;
+    let dim = get_param_int_or(params, "dim", 2)?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    if radius <= 0.0 {
+        return Err(format!("radius must be positive, got {}", radius));
+    }
+    if dim != 2 && dim != 3 {
+        return Err(format!("dim must be 2 or 3, got {}", dim));
+    }
+
+    let points: Vec<Vec<f64>> = (0..n)
+        .map(|_| (0..dim).map(|_| rng.gen::<f64>()).collect())
+        .collect();
+
+    let mut graph = Graph::new();
+    for (i, point) in points.iter().enumerate() {
+        graph.add_node(positioned_node(format!("{}{}", prefix, i), point));
+    }
+
+    // Bucket points into cells of side `radius`; neighbours lie in adjacent cells
+    let cells_per_side = ((1.0 / radius).floor() as usize).max(1);
+    let cell_of = |point: &[f64]| -> Vec<usize> {
+        point
+            .iter()
+            .map(|c| ((c * cells_per_side as f64) as usize).min(cells_per_side - 1))
+            .collect()
+    };
+    let mut cells: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+    for (i, point) in points.iter().enumerate() {
+        cells.entry(cell_of(point)).or_default().push(i);
+    }
+
+    let offsets: Vec<Vec<i64>> = (0..3usize.pow(dim as u32))
+        .map(|code| {
+            (0..dim)
+                .map(|d| (code / 3usize.pow(d as u32) % 3) as i64 - 1)
+                .collect()
+        })
+        .collect();
+
+    for (u, point) in points.iter().enumerate() {
+        let cell = cell_of(point);
+        for offset in &offsets {
+            let neighbour_cell: Option<Vec<usize>> = cell
+                .iter()
+                .zip(offset)
+                .map(|(&c, &o)| {
+                    let shifted = c as i64 + o;
+                    (0..cells_per_side as i64)
+                        .contains(&shifted)
+                        .then_some(shifted as usize)
+                })
+                .collect();
+            let Some(candidates) = neighbour_cell.and_then(|c| cells.get(&c)) else {
+                continue;
+            };
+            for &v in candidates {
+                if v <= u {
+                    continue;
+                }
+                let d = distance(point, &points[v]);
+                if d <= radius {
+                    graph.add_edge(weighted_edge(&prefix, u, v, d));
+                }
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a Waxman random graph.
+///
+/// Nodes are placed uniformly at random in the unit square and each pair is
+/// connected with probability `beta · exp(-d / (alpha · L))`, where `d` is
+/// their distance and `L` the maximum distance between any two nodes. Unlike
+/// [`generate_random_geometric`], long links remain possible but become
+/// exponentially rare.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `beta` (optional) - Overall link probability scale between 0 and 1 (default: 0.4)
+/// * `alpha` (optional) - Distance sensitivity, larger values favour long links (default: 0.1)
+/// * `max_distance` (optional) - Value of `L` (default: maximum pairwise distance)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n, with `x`/`y` set to the sampled coordinates
+/// * **Edges**: random, with the distance as `weight`
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate waxman {
+///     nodes: 100;
+///     beta: 0.5;
+///     alpha: 0.15;
+///     seed: 3;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Router-level internet topologies
+/// * Wireless networks with distance-dependent link quality
+pub fn generate_waxman(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let beta = get_param_probability(params, "beta", 0.4)?;
+    let alpha = get_param_float(params, "alpha", 0.1);
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    if alpha <= 0.0 {
+        return Err(format!("alpha must be positive, got {}", alpha));
+    }
+
+    let points: Vec<[f64; 2]> = (0..n).map(|_| [rng.gen(), rng.gen()]).collect();
+
+    let max_distance = match params.get("max_distance") {
+        Some(_) => get_param_float_required(params, "max_distance")?,
+        None => {
+            let mut max = 0.0f64;
+            for u in 0..n {
+                for v in u + 1..n {
+                    max = max.max(distance(&points[u], &points[v]));
+                }
+            }
+            max
+        }
+    };
+    if n > 1 && max_distance <= 0.0 {
+        return Err(format!(
+            "max_distance must be positive, got {}",
+            max_distance
+        ));
+    }
+
+    let mut graph = Graph::new();
+    for (i, point) in points.iter().enumerate() {
+        graph.add_node(positioned_node(format!("{}{}", prefix, i), point));
+    }
+
+    for u in 0..n {
+        for v in u + 1..n {
+            let d = distance(&points[u], &points[v]);
+            if rng.gen::<f64>() < beta * (-d / (alpha * max_distance)).exp() {
+                graph.add_edge(weighted_edge(&prefix, u, v, d));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Connection probability of two points at hyperbolic distance `d` in a disk of radius `radius`.
+///
+/// At temperature zero this is a step function; above zero it is the Fermi-Dirac
+/// function of Krioukov et al., which also allows links longer than the radius.
+fn hyperbolic_connection_probability(d: f64, radius: f64, temperature: f64) -> f64 {
+    if temperature == 0.0 {
+        if d <= radius {
+            1.0
+        } else {
+            0.0
+        }
+    } else {
+        1.0 / (1.0 + ((d - radius) / (2.0 * temperature)).exp())
+    }
+}
+
+/// Splits `[0, 1]` into cells that are uniform in `-ln(u)` up to `-ln(u) = depth`.
+///
+/// Returns the midpoint and width of each cell. Fine cells near zero resolve
+/// integrands that change on an exponential scale, such as the degree of the
+/// few nodes near the centre of the disk.
+fn log_cells(depth: f64, step: f64) -> Vec<(f64, f64)> {
+    let count = (depth / step).ceil().max(1.0) as usize;
+    let step = depth / count as f64;
+    (0..count)
+        .map(|i| {
+            let (outer, inner) = ((-(i as f64) * step).exp(), (-((i + 1) as f64) * step).exp());
+            ((outer + inner) / 2.0, outer - inner)
+        })
+        .collect()
+}
+
+/// Expected average degree of a hyperbolic random graph with disk radius `radius`.
+///
+/// Radial positions are integrated over their quantiles and angle differences
+/// over `θ / π`, both on logarithmic cells. At temperature zero the angular
+/// integral is taken exactly instead.
+fn expected_hyperbolic_degree(n: usize, alpha: f64, temperature: f64, radius: f64) -> f64 {
+    let radial: Vec<(f64, f64)> = log_cells(alpha * radius + (n as f64).ln() + 5.0, 0.25)
+        .into_iter()
+        .map(|(u, mass)| {
+            let r = (1.0 + u * ((alpha * radius).cosh() - 1.0)).acosh() / alpha;
+            (r, mass)
+        })
+        .collect();
+    let angular = log_cells(radius + 5.0, 0.25);
+    let cosh_radius = radius.cosh();
+
+    let mut total = 0.0;
+    for (i, &(r1, mass1)) in radial.iter().enumerate() {
+        for &(r2, mass2) in &radial[i..] {
+            let (a, b) = (r1.cosh() * r2.cosh(), r1.sinh() * r2.sinh());
+            let probability = if temperature == 0.0 {
+                // Connected exactly when the angle difference is below a threshold
+                let threshold = (a - cosh_radius) / b;
+                if a <= cosh_radius - b {
+                    1.0
+                } else if b == 0.0 || threshold >= 1.0 {
+                    0.0
+                } else {
+                    threshold.acos() / std::f64::consts::PI
+                }
+            } else {
+                let distance = |theta: f64| (a - b * theta.cos()).max(1.0).acosh();
+                // Below the last cell the distance has settled at |r1 - r2|
+                let covered = angular.iter().map(|&(_, width)| width).sum::<f64>();
+                angular
+                    .iter()
+                    .map(|&(x, width)| {
+                        let d = distance(std::f64::consts::PI * x);
+                        width * hyperbolic_connection_probability(d, radius, temperature)
+                    })
+                    .sum::<f64>()
+                    + (1.0 - covered)
+                        * hyperbolic_connection_probability((r1 - r2).abs(), radius, temperature)
+            };
+            let weight = if r1 == r2 { 1.0 } else { 2.0 };
+            total += weight * mass1 * mass2 * probability;
+        }
+    }
+    (n - 1) as f64 * total
+}
+
+/// Generates a hyperbolic random graph.
+///
+/// Nodes are placed at random in a hyperbolic disk of radius `R`: angles are
+/// uniform and radii follow the density `α sinh(αr) / (cosh(αR) - 1)` with
+/// `α = (exponent - 1) / 2`, so most nodes lie near the rim. Two nodes are
+/// connected when their hyperbolic distance is at most `R` or, at a positive
+/// `temperature`, with a probability that decays smoothly with distance. The
+/// result has a power-law degree distribution with the given exponent, strong
+/// clustering, and a latent geometry in which greedy routing succeeds, as
+/// described by Krioukov et al. `R` is solved numerically so that the expected
+/// average degree matches `average_degree`.
+///
+/// Every pair of nodes is considered, so generation takes O(n²) time.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `average_degree` (optional) - Target average degree (default: 6)
+/// * `exponent` (optional) - Power-law exponent of the degree distribution, greater than 2 (default: 3)
+/// * `temperature` (optional) - Between 0 and 1; higher values weaken clustering (default: 0)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n, with polar coordinates as `radius` and `angle` attributes and
+///   `x`/`y` set to `radius · cos(angle)` and `radius · sin(angle)`
+/// * **Edges**: about n · average_degree / 2, with the hyperbolic distance as `weight`
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_hyperbolic_random_graph;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(200));
+/// params.insert("average_degree".to_string(), MetadataValue::Integer(8));
+/// params.insert("exponent".to_string(), MetadataValue::Float(2.5));
+/// params.insert("seed".to_string(), MetadataValue::Integer(4));
+///
+/// let graph = generate_hyperbolic_random_graph(&params).unwrap();
+/// let node = &graph.nodes["n0"];
+/// assert!(node.metadata.contains_key("radius") && node.metadata.contains_key("angle"));
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate hyperbolic_random_graph {
+///     nodes: 1000;
+///     average_degree: 10;
+///     exponent: 2.5;
+///     temperature: 0.2;
+///     seed: 17;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Greedy routing and network navigability research
+/// * Internet AS-level and other scale-free, clustered topologies
+/// * Embedding benchmarks with known latent coordinates
+pub fn generate_hyperbolic_random_graph(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let average_degree = get_param_float(params, "average_degree", 6.0);
+    let exponent = get_param_float(params, "exponent", 3.0);
+    let temperature = get_param_float(params, "temperature", 0.0);
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    if exponent <= 2.0 {
+        return Err(format!("exponent must be greater than 2, got {}", exponent));
+    }
+    if !(0.0..1.0).contains(&temperature) {
+        return Err(format!(
+            "temperature must be at least 0 and below 1, got {}",
+            temperature
+        ));
+    }
+    if n > 1 && (average_degree <= 0.0 || average_degree >= (n - 1) as f64) {
+        return Err(format!(
+            "average_degree must be between 0 and nodes - 1, got {}",
+            average_degree
+        ));
+    }
+    let alpha = (exponent - 1.0) / 2.0;
+
+    // The expected degree falls roughly as e^(-R/2), so step along that slope,
+    // bisecting whenever a step would leave the bracket around the solution
+    let mut radius = 0.0;
+    if n > 1 {
+        let (mut low, mut high) = (0.0, f64::INFINITY);
+        radius = 2.0 * (n as f64 / average_degree).ln().max(1.0);
+        for _ in 0..100 {
+            let degree = expected_hyperbolic_degree(n, alpha, temperature, radius);
+            if degree > average_degree {
+                low = radius;
+            } else {
+                high = radius;
+            }
+            let mut next = radius + 2.0 * (degree / average_degree).ln();
+            if !(next > low && next < high) {
+                next = if high.is_finite() {
+                    (low + high) / 2.0
+                } else {
+                    2.0 * radius
+                };
+            }
+            if (next - radius).abs() < 1e-6 {
+                break;
+            }
+            radius = next;
+        }
+    }
+
+    let points: Vec<(f64, f64)> = (0..n)
+        .map(|_| {
+            let u: f64 = rng.gen();
+            let r = (1.0 + u * ((alpha * radius).cosh() - 1.0)).acosh() / alpha;
+            (r, rng.gen_range(0.0..std::f64::consts::TAU))
+        })
+        .collect();
+
+    let mut graph = Graph::new();
+    for (i, &(r, angle)) in points.iter().enumerate() {
+        graph.add_node(
+            Node::new(format!("{}{}", prefix, i))
+                .with_position(r * angle.cos(), r * angle.sin())
+                .with_metadata("radius".to_string(), MetadataValue::Float(r))
+                .with_metadata("angle".to_string(), MetadataValue::Float(angle)),
+        );
+    }
+
+    let hyperbolic: Vec<(f64, f64)> = points.iter().map(|&(r, _)| (r.cosh(), r.sinh())).collect();
+    for u in 0..n {
+        for v in u + 1..n {
+            let cosh_d = hyperbolic[u].0 * hyperbolic[v].0
+                - hyperbolic[u].1 * hyperbolic[v].1 * (points[u].1 - points[v].1).cos();
+            let d = cosh_d.max(1.0).acosh();
+            let p = hyperbolic_connection_probability(d, radius, temperature);
+            if p == 1.0 || (p > 0.0 && rng.gen::<f64>() < p) {
+                graph.add_edge(weighted_edge(&prefix, u, v, d));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(&[0.0, 0.0], &[3.0, 4.0]), 5.0);
+        assert_eq!(distance(&[1.0, 1.0, 1.0], &[1.0, 1.0, 3.0]), 2.0);
+    }
+
+    #[test]
+    fn test_hyperbolic_connection_probability() {
+        assert_eq!(hyperbolic_connection_probability(4.9, 5.0, 0.0), 1.0);
+        assert_eq!(hyperbolic_connection_probability(5.1, 5.0, 0.0), 0.0);
+        assert_eq!(hyperbolic_connection_probability(5.0, 5.0, 0.5), 0.5);
+        assert!(hyperbolic_connection_probability(6.0, 5.0, 0.5) > 0.0);
+    }
+
+    #[test]
+    fn test_positioned_node_stores_z() {
+        let node = positioned_node("a".to_string(), &[0.1, 0.2, 0.3]);
+        assert_eq!((node.x, node.y), (0.1, 0.2));
+        assert_eq!(node.metadata.get("z"), Some(&MetadataValue::Float(0.3)));
+    }
+}
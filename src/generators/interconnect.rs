@@ -0,0 +1,555 @@
+//! # Interconnection Network Generators
+//!
+//! Structured topologies used for HPC and data-centre networks. Node IDs
+//! encode each node's coordinates (for example `n1_0_3` in a torus or
+//! `n0110` in a hypercube) and the same coordinates are stored as node
+//! attributes so that rules can match on them.
+
+use super::{get_param_bool, get_param_int, get_param_int_list, get_param_string};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::HashMap;
+
+/// Largest number of nodes the generators in this module build.
+const MAX_NODES: usize = 1 << 24;
+
+/// Converts a list of coordinates into a `MetadataValue::List` of integers.
+fn coords_value(coords: &[usize]) -> MetadataValue {
+    MetadataValue::List(
+        coords
+            .iter()
+            .map(|&c| MetadataValue::Integer(c as i64))
+            .collect(),
+    )
+}
+
+/// Joins coordinates with underscores, matching the grid generator's `i_j` IDs.
+fn coords_label(coords: &[usize]) -> String {
+    coords
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Writes a word over an alphabet as digits, separating symbols only when
+/// the alphabet is too large for single digits.
+fn word_label(word: &[usize], symbols: usize) -> String {
+    if symbols <= 10 {
+        word.iter().map(|s| s.to_string()).collect()
+    } else {
+        coords_label(word)
+    }
+}
+
+/// Decodes `index` into its mixed-radix digits, most significant first.
+fn mixed_radix(mut index: usize, radices: &[usize]) -> Vec<usize> {
+    let mut digits = vec![0; radices.len()];
+    for (digit, &radix) in digits.iter_mut().zip(radices).rev() {
+        *digit = index % radix;
+        index /= radix;
+    }
+    digits
+}
+
+/// Generates a hypercube graph.
+///
+/// The `d`-dimensional hypercube has one node per binary string of length `d`
+/// and connects strings that differ in exactly one bit. Node IDs append the
+/// bit string to the prefix and the bits are stored in a `coords` attribute.
+///
+/// # Parameters
+///
+/// * `dimension` (required) - Number of dimensions `d`, at most 24
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2^d
+/// * **Edges**: d·2^(d-1)
+/// * **Regularity**: Every node has degree d
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_hypercube;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("dimension".to_string(), MetadataValue::Integer(3));
+///
+/// let graph = generate_hypercube(&params).unwrap();
+/// assert_eq!(graph.node_count(), 8);
+/// assert_eq!(graph.edge_count(), 12);
+/// assert!(graph.get_edge("e000_100").is_some());
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate hypercube {
+///     dimension: 4;
+///     prefix: "cpu";
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Hypercube-connected multiprocessors
+/// * Routing and broadcast algorithm benchmarks
+pub fn generate_hypercube(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let d = get_param_int(params, "dimension")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    let max_dimension = MAX_NODES.trailing_zeros() as usize;
+    if d > max_dimension {
+        return Err(format!(
+            "dimension must be at most {}, got {}",
+            max_dimension, d
+        ));
+    }
+
+    let bits = |index: usize| -> Vec<usize> { (0..d).rev().map(|b| (index >> b) & 1).collect() };
+    let label = |index: usize| -> String { word_label(&bits(index), 2) };
+
+    let mut graph = Graph::new();
+    for i in 0..1usize << d {
+        graph.add_node(
+            Node::new(format!("{}{}", prefix, label(i)))
+                .with_metadata("coords".to_string(), coords_value(&bits(i))),
+        );
+    }
+
+    for i in 0..1usize << d {
+        for b in 0..d {
+            let j = i ^ (1 << b);
+            if i < j {
+                graph.add_edge(Edge::new(
+                    format!("e{}_{}", label(i), label(j)),
+                    format!("{}{}", prefix, label(i)),
+                    format!("{}{}", prefix, label(j)),
+                ));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates an n-dimensional torus (or mesh) graph.
+///
+/// Nodes sit on an integer lattice with side lengths given by `dims`, and each
+/// node is connected to its neighbours along every axis. With `periodic` set
+/// (the default) each axis wraps around, generalising the periodic
+/// [`generate_grid`](super::generate_grid) to any number of dimensions.
+///
+/// Node IDs append the coordinates joined by underscores (`n1_0_3`), and the
+/// coordinates are stored in a `coords` attribute.
+///
+/// # Parameters
+///
+/// * `dims` (required) - List of side lengths, one per dimension, whose
+///   product is at most 2^24
+/// * `periodic` (optional) - Whether axes wrap around (default: true)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: product of `dims`
+/// * **Edges**: n·d for a periodic torus with every side longer than 2
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate torus {
+///     dims: [4, 4, 4];
+///     prefix: "node";
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * 3D torus interconnects (Blue Gene, Cray Gemini)
+/// * Periodic lattices for simulation
+pub fn generate_torus(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let dims = get_param_int_list(params, "dims")?;
+    let periodic = get_param_bool(params, "periodic", true);
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if dims.is_empty() || dims.contains(&0) {
+        return Err("dims must be a non-empty list of positive side lengths".to_string());
+    }
+
+    let total = dims
+        .iter()
+        .try_fold(1usize, |acc, &side| acc.checked_mul(side))
+        .filter(|&total| total <= MAX_NODES)
+        .ok_or_else(|| {
+            format!(
+                "torus would have more than {} nodes, reduce dims",
+                MAX_NODES
+            )
+        })?;
+
+    let mut graph = Graph::new();
+    for i in 0..total {
+        let coords = mixed_radix(i, &dims);
+        graph.add_node(
+            Node::new(format!("{}{}", prefix, coords_label(&coords)))
+                .with_metadata("coords".to_string(), coords_value(&coords)),
+        );
+    }
+
+    for i in 0..total {
+        let coords = mixed_radix(i, &dims);
+        for (axis, &side) in dims.iter().enumerate() {
+            let at_end = coords[axis] + 1 == side;
+            // Wrapping a side of length 2 would duplicate the existing edge
+            if side < 2 || (at_end && (!periodic || side == 2)) {
+                continue;
+            }
+
+            let mut neighbour = coords.clone();
+            neighbour[axis] = (coords[axis] + 1) % side;
+            graph.add_edge(Edge::new(
+                format!("e{}_{}", axis, coords_label(&coords)),
+                format!("{}{}", prefix, coords_label(&coords)),
+                format!("{}{}", prefix, coords_label(&neighbour)),
+            ));
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a butterfly network.
+///
+/// The `d`-dimensional butterfly has `d + 1` levels of `2^d` rows. Node
+/// `(level, row)` is connected to `(level + 1, row)` by a straight edge and to
+/// `(level + 1, row XOR 2^level)` by a cross edge. With `wrapped` set, the last
+/// level is merged into the first, giving the wrapped butterfly.
+///
+/// Node IDs are `{prefix}{level}_{row}` and each node stores `level` and `row`
+/// attributes.
+///
+/// # Parameters
+///
+/// * `dimension` (required) - Number of dimensions `d`
+/// * `wrapped` (optional) - Merge the last level into the first (default: false)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: (d + 1)·2^d, or d·2^d when wrapped
+/// * **Edges**: d·2^(d+1)
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate butterfly {
+///     dimension: 3;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Multistage switching networks
+/// * FFT communication patterns
+pub fn generate_butterfly(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let d = get_param_int(params, "dimension")?;
+    let wrapped = get_param_bool(params, "wrapped", false);
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if d == 0 || d > 20 {
+        return Err(format!("dimension must be between 1 and 20, got {}", d));
+    }
+    if wrapped && d < 3 {
+        return Err("wrapped butterflies need a dimension of at least 3".to_string());
+    }
+
+    let rows = 1usize << d;
+    let levels = if wrapped { d } else { d + 1 };
+    let id = |level: usize, row: usize| format!("{}{}_{}", prefix, level % levels, row);
+
+    let mut graph = Graph::new();
+    for level in 0..levels {
+        for row in 0..rows {
+            graph.add_node(
+                Node::new(id(level, row))
+                    .with_metadata("level".to_string(), MetadataValue::Integer(level as i64))
+                    .with_metadata("row".to_string(), MetadataValue::Integer(row as i64)),
+            );
+        }
+    }
+
+    for level in 0..d {
+        for row in 0..rows {
+            let cross = row ^ (1 << level);
+            graph.add_edge(Edge::new(
+                format!("es{}_{}", level, row),
+                id(level, row),
+                id(level + 1, row),
+            ));
+            graph.add_edge(Edge::new(
+                format!("ec{}_{}", level, row),
+                id(level, row),
+                id(level + 1, cross),
+            ));
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Shared implementation of the shift-register graphs.
+///
+/// Every word over the alphabet is a node, and an edge runs from `s1 s2 … sn`
+/// to `s2 … sn x` for every symbol `x`. When `kautz` is set, only words without
+/// two equal consecutive symbols are used.
+fn shift_register_graph(
+    params: &HashMap<String, MetadataValue>,
+    kautz: bool,
+) -> Result<Graph, String> {
+    let symbols = get_param_int(params, "symbols")?;
+    let length = get_param_int(params, "length")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if symbols < 2 {
+        return Err(format!("symbols must be at least 2, got {}", symbols));
+    }
+    if length == 0 {
+        return Err("length must be at least 1".to_string());
+    }
+    let total = symbols
+        .checked_pow(length as u32)
+        .filter(|&total| total <= MAX_NODES)
+        .ok_or_else(|| "graph is too large, reduce symbols or length".to_string())?;
+
+    let radices = vec![symbols; length];
+    let words: Vec<Vec<usize>> = (0..total)
+        .map(|i| mixed_radix(i, &radices))
+        .filter(|word| !kautz || word.windows(2).all(|pair| pair[0] != pair[1]))
+        .collect();
+
+    let label = |word: &[usize]| word_label(word, symbols);
+
+    let mut graph = Graph::new();
+    for word in &words {
+        graph.add_node(
+            Node::new(format!("{}{}", prefix, label(word)))
+                .with_metadata("word".to_string(), coords_value(word)),
+        );
+    }
+
+    for word in &words {
+        for x in 0..symbols {
+            if kautz && word[length - 1] == x {
+                continue;
+            }
+            let mut next = word[1..].to_vec();
+            next.push(x);
+            graph.add_edge(Edge::new(
+                format!("e{}_{}", label(word), label(&next)),
+                format!("{}{}", prefix, label(word)),
+                format!("{}{}", prefix, label(&next)),
+            ));
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a directed de Bruijn graph.
+///
+/// Nodes are all words of the given `length` over an alphabet of `symbols`
+/// symbols, with an edge from `s1 s2 … sn` to `s2 … sn x` for every symbol `x`.
+/// Constant words such as `000` therefore carry a self-loop. Node IDs append the
+/// word (digits, or underscore-separated symbols for alphabets larger than 10),
+/// and the word is stored in a `word` attribute.
+///
+/// # Parameters
+///
+/// * `symbols` (required) - Alphabet size (at least 2)
+/// * `length` (required) - Word length (at least 1)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: symbols^length
+/// * **Edges**: symbols^(length+1), directed
+/// * **Diameter**: length
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate de_bruijn {
+///     symbols: 2;
+///     length: 4;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Low-diameter interconnects and peer-to-peer overlays
+/// * Sequence assembly and shift-register models
+pub fn generate_de_bruijn(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    shift_register_graph(params, false)
+}
+
+/// Generates a directed Kautz graph.
+///
+/// Like [`generate_de_bruijn`], but only words in which no two consecutive
+/// symbols are equal are used, which removes the self-loops while keeping
+/// every node at the same degree.
+///
+/// # Parameters
+///
+/// * `symbols` (required) - Alphabet size (at least 2)
+/// * `length` (required) - Word length (at least 1)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: symbols·(symbols-1)^(length-1)
+/// * **Edges**: symbols·(symbols-1)^length, directed
+/// * **Regularity**: In- and out-degree symbols-1
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate kautz {
+///     symbols: 3;
+///     length: 3;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Interconnects with near-optimal degree/diameter trade-off
+/// * Overlay networks
+pub fn generate_kautz(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    shift_register_graph(params, true)
+}
+
+/// Generates a k-ary fat-tree data-centre topology.
+///
+/// The fat-tree has `k` pods, each with `k/2` edge and `k/2` aggregation
+/// switches, `(k/2)^2` core switches, and `k/2` hosts below every edge switch.
+/// Aggregation switch `i` of every pod connects to core switches
+/// `i·k/2 … (i+1)·k/2 - 1`.
+///
+/// Nodes are typed `core`, `aggregation`, `edge` or `host` and store their
+/// `pod` and `index`; IDs follow the same scheme (`core3`, `agg1_0`, `edge1_1`,
+/// `host1_1_0`).
+///
+/// # Parameters
+///
+/// * `k` (required) - Switch port count (even, at least 2)
+/// * `hosts` (optional) - Whether to generate the host layer (default: true)
+/// * `prefix` (optional) - Node name prefix (default: "")
+///
+/// # Properties
+///
+/// * **Switches**: 5k²/4
+/// * **Hosts**: k³/4
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate fat_tree {
+///     k: 4;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Data-centre network simulation
+/// * Multipath routing experiments
+pub fn generate_fat_tree(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let k = get_param_int(params, "k")?;
+    let with_hosts = get_param_bool(params, "hosts", true);
+    let prefix = get_param_string(params, "prefix", "");
+
+    if k < 2 || k % 2 != 0 || k > 128 {
+        return Err(format!(
+            "k must be an even number between 2 and 128, got {}",
+            k
+        ));
+    }
+    let half = k / 2;
+
+    let mut graph = Graph::new();
+    let switch = |id: String, role: &str, pod: Option<usize>, index: usize| {
+        let mut node = Node::new(id)
+            .with_type(role.to_string())
+            .with_metadata("index".to_string(), MetadataValue::Integer(index as i64));
+        if let Some(pod) = pod {
+            node = node.with_metadata("pod".to_string(), MetadataValue::Integer(pod as i64));
+        }
+        node
+    };
+    let core = |i: usize| format!("{}core{}", prefix, i);
+    let agg = |pod: usize, i: usize| format!("{}agg{}_{}", prefix, pod, i);
+    let edge = |pod: usize, i: usize| format!("{}edge{}_{}", prefix, pod, i);
+
+    for i in 0..half * half {
+        graph.add_node(switch(core(i), "core", None, i));
+    }
+
+    for pod in 0..k {
+        for i in 0..half {
+            graph.add_node(switch(agg(pod, i), "aggregation", Some(pod), i));
+            graph.add_node(switch(edge(pod, i), "edge", Some(pod), i));
+        }
+
+        for i in 0..half {
+            for j in 0..half {
+                let c = i * half + j;
+                graph.add_edge(Edge::new(
+                    format!("e{}_{}", core(c), agg(pod, i)),
+                    core(c),
+                    agg(pod, i),
+                ));
+                graph.add_edge(Edge::new(
+                    format!("e{}_{}", agg(pod, i), edge(pod, j)),
+                    agg(pod, i),
+                    edge(pod, j),
+                ));
+            }
+        }
+
+        if with_hosts {
+            for i in 0..half {
+                for h in 0..half {
+                    let host = format!("{}host{}_{}_{}", prefix, pod, i, h);
+                    graph.add_node(switch(host.clone(), "host", Some(pod), h));
+                    graph.add_edge(Edge::new(
+                        format!("e{}_{}", edge(pod, i), host),
+                        edge(pod, i),
+                        host,
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_radix() {
+        assert_eq!(mixed_radix(0, &[2, 3]), vec![0, 0]);
+        assert_eq!(mixed_radix(5, &[2, 3]), vec![1, 2]);
+        assert_eq!(mixed_radix(6, &[4, 4, 4]), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_word_label() {
+        assert_eq!(word_label(&[0, 1, 1], 2), "011");
+        assert_eq!(word_label(&[0, 11, 3], 12), "0_11_3");
+    }
+}
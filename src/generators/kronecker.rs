@@ -0,0 +1,320 @@
+//! # Kronecker Generators
+//!
+//! Recursive-matrix generators for large synthetic benchmark graphs. Both
+//! place each edge by descending through the levels of a Kronecker power of a
+//! small initiator matrix, picking one cell per level, so generation costs
+//! O(edges · levels) regardless of the number of node pairs. Edges are
+//! directed from source to target.
+
+use super::{
+    get_param_bool, get_param_float, get_param_float_matrix, get_param_int, get_param_int_or,
+    get_param_string, get_rng,
+};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+/// Largest R-MAT scale, keeping `2^scale` nodes addressable on 32-bit targets
+/// and the graph within memory at the default edge factor.
+const MAX_RMAT_SCALE: usize = 26;
+
+/// Largest number of edges a Kronecker generator will sample.
+const MAX_EDGES: usize = 1_000_000_000;
+
+/// Samples cells of a k × k initiator matrix in proportion to their weight.
+struct Initiator {
+    k: usize,
+    /// Running totals of the row-major cell weights, normalised to end at 1
+    cumulative: Vec<f64>,
+}
+
+impl Initiator {
+    fn new(matrix: &[Vec<f64>]) -> Result<Self, String> {
+        let k = matrix.len();
+        if k < 2 || matrix.iter().any(|row| row.len() != k) {
+            return Err("initiator must be a square matrix of size at least 2x2".to_string());
+        }
+        if matrix.iter().flatten().any(|&p| !(0.0..=1.0).contains(&p)) {
+            return Err("initiator entries must be between 0 and 1".to_string());
+        }
+        let total: f64 = matrix.iter().flatten().sum();
+        if total <= 0.0 {
+            return Err("initiator must have at least one non-zero entry".to_string());
+        }
+
+        let mut running = 0.0;
+        let cumulative = matrix
+            .iter()
+            .flatten()
+            .map(|&p| {
+                running += p / total;
+                running
+            })
+            .collect();
+        Ok(Initiator { k, cumulative })
+    }
+
+    /// Picks a `(source, target)` pair among `k^levels` nodes.
+    fn sample_edge(&self, rng: &mut SmallRng, levels: usize) -> (usize, usize) {
+        let (mut u, mut v) = (0, 0);
+        for _ in 0..levels {
+            let r: f64 = rng.gen();
+            let cell = self
+                .cumulative
+                .iter()
+                .position(|&c| r < c)
+                .unwrap_or(self.cumulative.len() - 1);
+            u = u * self.k + cell / self.k;
+            v = v * self.k + cell % self.k;
+        }
+        (u, v)
+    }
+}
+
+/// Builds the graph from sampled node pairs, optionally relabelling nodes at random.
+///
+/// Repeated pairs become parallel edges `e{u}_{v}_1`, `e{u}_{v}_2`, … unless
+/// `remove_multi_edges` is set.
+fn build_graph(
+    params: &HashMap<String, MetadataValue>,
+    rng: &mut SmallRng,
+    n: usize,
+    pairs: Vec<(usize, usize)>,
+    permute: bool,
+) -> Graph {
+    let prefix = get_param_string(params, "prefix", "n");
+    let remove_self_loops = get_param_bool(params, "remove_self_loops", false);
+    let remove_multi_edges = get_param_bool(params, "remove_multi_edges", false);
+
+    let mut label: Vec<usize> = (0..n).collect();
+    if permute {
+        label.shuffle(rng);
+    }
+
+    let ids: Vec<String> = (0..n).map(|i| format!("{}{}", prefix, i)).collect();
+    let mut graph = Graph::new();
+    graph.nodes.reserve(n);
+    graph.edges.reserve(pairs.len());
+    for id in &ids {
+        graph.add_node(Node::new(id.clone()));
+    }
+
+    let mut multiplicity: HashMap<(usize, usize), usize> = HashMap::with_capacity(pairs.len());
+    for (u, v) in pairs {
+        let (u, v) = (label[u], label[v]);
+        if remove_self_loops && u == v {
+            continue;
+        }
+        let count = multiplicity.entry((u, v)).or_insert(0);
+        if remove_multi_edges && *count > 0 {
+            continue;
+        }
+        let id = match *count {
+            0 => format!("e{}_{}", u, v),
+            k => format!("e{}_{}_{}", u, v, k),
+        };
+        *count += 1;
+        graph.add_edge(Edge::new(id, ids[u].clone(), ids[v].clone()));
+    }
+    graph
+}
+
+/// Generates an R-MAT graph in the style of the Graph500 benchmark.
+///
+/// The adjacency matrix of `2^scale` nodes is recursively split into four
+/// quadrants chosen with probabilities `a`, `b`, `c` and `d`; each of the
+/// `edge_factor · 2^scale` edges descends `scale` levels to find its cell.
+/// Skewed probabilities give a heavy-tailed, community-like structure. As in
+/// Graph500, node labels are shuffled afterwards so that high-degree nodes are
+/// not clustered at low indices, and the raw edge list keeps self-loops and
+/// repeated edges unless asked to remove them.
+///
+/// # Parameters
+///
+/// * `scale` (required) - Base-2 logarithm of the number of nodes (at most 26)
+/// * `edge_factor` (optional) - Edges per node (default: 16)
+/// * `a`, `b`, `c`, `d` (optional) - Quadrant probabilities summing to 1
+///   (default: 0.57, 0.19, 0.19, 0.05)
+/// * `permute` (optional) - Shuffle node labels (default: true)
+/// * `remove_self_loops` (optional) - Drop edges from a node to itself (default: false)
+/// * `remove_multi_edges` (optional) - Drop repeated edges (default: false)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2^scale
+/// * **Edges**: edge_factor · 2^scale before any removal, directed
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_rmat;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("scale".to_string(), MetadataValue::Integer(8));
+/// params.insert("edge_factor".to_string(), MetadataValue::Integer(4));
+/// params.insert("seed".to_string(), MetadataValue::Integer(1));
+///
+/// let graph = generate_rmat(&params).unwrap();
+/// assert_eq!(graph.node_count(), 256);
+/// assert_eq!(graph.edge_count(), 1024);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate rmat {
+///     scale: 16;
+///     edge_factor: 16;
+///     seed: 42;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Graph500-style benchmark inputs
+/// * Stress tests for graph databases and analytics engines
+pub fn generate_rmat(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let scale = get_param_int(params, "scale")?;
+    let edge_factor = get_param_int_or(params, "edge_factor", 16)?;
+    let a = get_param_float(params, "a", 0.57);
+    let b = get_param_float(params, "b", 0.19);
+    let c = get_param_float(params, "c", 0.19);
+    let d = get_param_float(params, "d", 0.05);
+    let permute = get_param_bool(params, "permute", true);
+    let mut rng = get_rng(params)?;
+
+    if scale > MAX_RMAT_SCALE {
+        return Err(format!(
+            "scale must be at most {}, got {}",
+            MAX_RMAT_SCALE, scale
+        ));
+    }
+    if ((a + b + c + d) - 1.0).abs() > 1e-6 {
+        return Err(format!(
+            "a, b, c and d must sum to 1, got {}",
+            a + b + c + d
+        ));
+    }
+    let initiator = Initiator::new(&[vec![a, b], vec![c, d]])?;
+
+    let n = 1usize << scale;
+    let m = n
+        .checked_mul(edge_factor)
+        .filter(|&m| m <= MAX_EDGES)
+        .ok_or_else(|| {
+            format!(
+                "graph would have more than {} edges, reduce scale or edge_factor",
+                MAX_EDGES
+            )
+        })?;
+    let pairs: Vec<(usize, usize)> = (0..m)
+        .map(|_| initiator.sample_edge(&mut rng, scale))
+        .collect();
+
+    Ok(build_graph(params, &mut rng, n, pairs, permute))
+}
+
+/// Generates a stochastic Kronecker graph.
+///
+/// The edge probability matrix is the `power`-th Kronecker power of the
+/// `initiator` matrix, so nodes `u` and `v` written in base k are connected
+/// with probability `Π P[u_i][v_i]`. Rather than testing all k^(2·power) pairs,
+/// the expected number of edges (Σ P)^power is placed by descending through
+/// the levels in proportion to the initiator entries, and repeated pairs are
+/// redrawn, which approximates the exact model closely for sparse graphs.
+///
+/// # Parameters
+///
+/// * `initiator` (required) - Square matrix of probabilities, e.g. `[[0.9, 0.5], [0.5, 0.1]]`
+/// * `power` (required) - Number of Kronecker multiplications
+/// * `remove_self_loops` (optional) - Drop edges from a node to itself (default: false)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: k^power
+/// * **Edges**: about (Σ initiator)^power, directed and without repeats
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate stochastic_kronecker {
+///     initiator: [[0.9, 0.5], [0.5, 0.2]];
+///     power: 12;
+///     seed: 7;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Synthetic graphs matching a fitted Kronecker model
+/// * Self-similar benchmark inputs
+pub fn generate_stochastic_kronecker(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let matrix = get_param_float_matrix(params, "initiator")?;
+    let power = get_param_int(params, "power")?;
+    let mut rng = get_rng(params)?;
+
+    let initiator = Initiator::new(&matrix)?;
+    let n = initiator
+        .k
+        .checked_pow(power as u32)
+        .filter(|&n| (n as u64) <= 1u64 << 32)
+        .ok_or_else(|| "graph is too large, reduce power".to_string())?;
+
+    let total: f64 = matrix.iter().flatten().sum();
+    let expected = total.powi(power as i32).round();
+    if expected > MAX_EDGES as f64 {
+        return Err(format!(
+            "expected edge count {} is too large, reduce power",
+            expected
+        ));
+    }
+    let m = expected.min((n as f64) * (n as f64)) as usize;
+
+    // Redraw repeated pairs, bounded in case the matrix concentrates on few cells
+    let mut seen = HashSet::with_capacity(m);
+    let mut pairs = Vec::with_capacity(m);
+    let mut attempts = 0;
+    while pairs.len() < m && attempts < 10 * m + 100 {
+        attempts += 1;
+        let pair = initiator.sample_edge(&mut rng, power);
+        if seen.insert(pair) {
+            pairs.push(pair);
+        }
+    }
+
+    Ok(build_graph(params, &mut rng, n, pairs, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_initiator_sampling_follows_weights() {
+        let initiator = Initiator::new(&[vec![1.0, 0.0], vec![0.0, 0.0]]).unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(initiator.sample_edge(&mut rng, 5), (0, 0));
+
+        let initiator = Initiator::new(&[vec![0.0, 1.0], vec![0.0, 0.0]]).unwrap();
+        assert_eq!(initiator.sample_edge(&mut rng, 3), (0, 7));
+    }
+
+    #[test]
+    fn test_initiator_rejects_bad_matrices() {
+        assert!(Initiator::new(&[vec![0.5]]).is_err());
+        assert!(Initiator::new(&[vec![0.5, 0.5], vec![0.5]]).is_err());
+        assert!(Initiator::new(&[vec![0.0, 0.0], vec![0.0, 0.0]]).is_err());
+        assert!(Initiator::new(&[vec![1.5, 0.0], vec![0.0, 0.0]]).is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! # Lattice Generators
+//!
+//! Planar tilings beyond the square [`generate_grid`](super::generate_grid).
+//! Each lattice is built by repeating a unit cell over `rows` × `cols` cells
+//! along two lattice vectors, so the patch is a parallelogram. Nodes get their
+//! geometric `x`/`y` positions with every bond of unit length, and `periodic`
+//! wraps both lattice directions into a torus.
+
+use super::{get_param_bool, get_param_int, get_param_string};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::HashMap;
+
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+/// A lattice described by its unit cell.
+struct UnitCell {
+    /// Lattice vector between neighbouring columns
+    a1: (f64, f64),
+    /// Lattice vector between neighbouring rows
+    a2: (f64, f64),
+    /// Position of each site inside the cell
+    sites: &'static [(f64, f64)],
+    /// Bonds as `(site, other site, row offset, column offset)`
+    bonds: &'static [(usize, usize, isize, isize)],
+}
+
+const TRIANGULAR: UnitCell = UnitCell {
+    a1: (1.0, 0.0),
+    a2: (0.5, SQRT_3 / 2.0),
+    sites: &[(0.0, 0.0)],
+    bonds: &[(0, 0, 0, 1), (0, 0, 1, 0), (0, 0, 1, -1)],
+};
+
+const HEXAGONAL: UnitCell = UnitCell {
+    a1: (SQRT_3, 0.0),
+    a2: (SQRT_3 / 2.0, 1.5),
+    sites: &[(0.0, 0.0), (0.0, 1.0)],
+    bonds: &[(0, 1, 0, 0), (1, 0, 1, 0), (1, 0, 1, -1)],
+};
+
+const KAGOME: UnitCell = UnitCell {
+    a1: (2.0, 0.0),
+    a2: (1.0, SQRT_3),
+    sites: &[(0.0, 0.0), (1.0, 0.0), (0.5, SQRT_3 / 2.0)],
+    bonds: &[
+        (0, 1, 0, 0),
+        (0, 2, 0, 0),
+        (1, 2, 0, 0),
+        (1, 0, 0, 1),
+        (2, 0, 1, 0),
+        (2, 1, 1, -1),
+    ],
+};
+
+/// Builds `rows` × `cols` copies of a unit cell.
+///
+/// Single-site lattices use the grid's `{prefix}{row}_{col}` node IDs; lattices
+/// with several sites per cell append the site index.
+fn build_lattice(
+    params: &HashMap<String, MetadataValue>,
+    cell: &UnitCell,
+) -> Result<Graph, String> {
+    let rows = get_param_int(params, "rows")?;
+    let cols = get_param_int(params, "cols")?;
+    let periodic = get_param_bool(params, "periodic", false);
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if periodic && (rows < 3 || cols < 3) {
+        return Err(format!(
+            "periodic lattices need at least 3 rows and 3 cols, got {}x{}",
+            rows, cols
+        ));
+    }
+
+    let single_site = cell.sites.len() == 1;
+    let id = |row: usize, col: usize, site: usize| {
+        if single_site {
+            format!("{}{}_{}", prefix, row, col)
+        } else {
+            format!("{}{}_{}_{}", prefix, row, col, site)
+        }
+    };
+
+    let mut graph = Graph::new();
+    for row in 0..rows {
+        for col in 0..cols {
+            for (site, &(sx, sy)) in cell.sites.iter().enumerate() {
+                let x = col as f64 * cell.a1.0 + row as f64 * cell.a2.0 + sx;
+                let y = col as f64 * cell.a1.1 + row as f64 * cell.a2.1 + sy;
+                let mut node = Node::new(id(row, col, site))
+                    .with_position(x, y)
+                    .with_metadata("row".to_string(), MetadataValue::Integer(row as i64))
+                    .with_metadata("col".to_string(), MetadataValue::Integer(col as i64));
+                if !single_site {
+                    node =
+                        node.with_metadata("site".to_string(), MetadataValue::Integer(site as i64));
+                }
+                graph.add_node(node);
+            }
+        }
+    }
+
+    let shift = |value: usize, offset: isize, size: usize| -> Option<usize> {
+        let shifted = value as isize + offset;
+        if periodic {
+            Some(shifted.rem_euclid(size as isize) as usize)
+        } else {
+            (0..size as isize)
+                .contains(&shifted)
+                .then_some(shifted as usize)
+        }
+    };
+
+    for row in 0..rows {
+        for col in 0..cols {
+            for (bond, &(from, to, d_row, d_col)) in cell.bonds.iter().enumerate() {
+                let (Some(other_row), Some(other_col)) =
+                    (shift(row, d_row, rows), shift(col, d_col, cols))
+                else {
+                    continue;
+                };
+                graph.add_edge(Edge::new(
+                    format!("e{}_{}_{}", bond, row, col),
+                    id(row, col, from),
+                    id(other_row, other_col, to),
+                ));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a triangular lattice.
+///
+/// Every interior node has six neighbours at unit distance, arranged in a
+/// parallelogram of `rows` × `cols` nodes. Node IDs follow the grid's
+/// `{prefix}{row}_{col}` scheme and `row`/`col` are stored as attributes.
+///
+/// # Parameters
+///
+/// * `rows` (required) - Number of rows
+/// * `cols` (required) - Number of columns
+/// * `periodic` (optional) - Wrap both directions into a torus (default: false)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: rows × cols
+/// * **Edges**: 3 · rows · cols when periodic
+/// * **Degree**: 6 for interior or periodic nodes
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_triangular_lattice;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("rows".to_string(), MetadataValue::Integer(2));
+/// params.insert("cols".to_string(), MetadataValue::Integer(2));
+///
+/// let graph = generate_triangular_lattice(&params).unwrap();
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 5); // two triangles sharing an edge
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate triangular_lattice {
+///     rows: 10;
+///     cols: 10;
+///     periodic: true;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Close-packed crystal structures
+/// * Triangular finite-element meshes
+pub fn generate_triangular_lattice(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    build_lattice(params, &TRIANGULAR)
+}
+
+/// Generates a hexagonal (honeycomb) lattice.
+///
+/// Each of the `rows` × `cols` unit cells holds two sites, so node IDs are
+/// `{prefix}{row}_{col}_{site}` with `site` 0 or 1, and every interior node has
+/// three neighbours at unit distance.
+///
+/// # Parameters
+///
+/// * `rows` (required) - Number of unit-cell rows
+/// * `cols` (required) - Number of unit-cell columns
+/// * `periodic` (optional) - Wrap both directions into a torus (default: false)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 2 · rows · cols
+/// * **Edges**: 3 · rows · cols when periodic
+/// * **Degree**: 3 for interior or periodic nodes
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate hexagonal_lattice {
+///     rows: 6;
+///     cols: 8;
+///     prefix: "c";
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Graphene and other 2D materials
+/// * Hexagonal cellular networks
+pub fn generate_hexagonal_lattice(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    build_lattice(params, &HEXAGONAL)
+}
+
+/// Generates a kagome lattice.
+///
+/// Corner-sharing triangles around hexagonal holes. Each of the `rows` × `cols`
+/// unit cells holds three sites, so node IDs are `{prefix}{row}_{col}_{site}`
+/// with `site` 0 to 2, and every interior node has four neighbours at unit
+/// distance.
+///
+/// # Parameters
+///
+/// * `rows` (required) - Number of unit-cell rows
+/// * `cols` (required) - Number of unit-cell columns
+/// * `periodic` (optional) - Wrap both directions into a torus (default: false)
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: 3 · rows · cols
+/// * **Edges**: 6 · rows · cols when periodic
+/// * **Degree**: 4 for interior or periodic nodes
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate kagome {
+///     rows: 5;
+///     cols: 5;
+///     periodic: true;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Frustrated magnetic materials
+/// * Mesh and tiling experiments
+pub fn generate_kagome(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    build_lattice(params, &KAGOME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_cell_bonds_have_unit_length() {
+        for cell in [&TRIANGULAR, &HEXAGONAL, &KAGOME] {
+            for &(from, to, d_row, d_col) in cell.bonds {
+                let (fx, fy) = cell.sites[from];
+                let (tx, ty) = cell.sites[to];
+                let dx = tx + d_col as f64 * cell.a1.0 + d_row as f64 * cell.a2.0 - fx;
+                let dy = ty + d_col as f64 * cell.a1.1 + d_row as f64 * cell.a2.1 - fy;
+                assert!(((dx * dx + dy * dy).sqrt() - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+}
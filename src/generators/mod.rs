@@ -0,0 +1,1147 @@
+//! # Graph Generators
+//!
+//! This module provides built-in graph generators for creating common graph topologies.
+//! Generators are invoked using the `generate` statement in GGL programs and can create
+//! various types of graph structures with customizable parameters.
+//!
+//! ## Available Generators
+//!
+//! * [`generate_complete`] - Complete graphs where every node connects to every other node
+//! * [`generate_path`] - Linear chains of connected nodes
+//! * [`generate_cycle`] - Circular chains of nodes
+//! * [`generate_grid`] - 2D grid structures with optional periodic boundaries
+//! * [`generate_star`] - Star topologies with one central hub
+//! * [`generate_tree`] - Tree structures with specified branching and depth
+//! * [`generate_barabasi_albert`] - Scale-free networks using linear-time preferential attachment
+//! * [`generate_powerlaw_cluster`] - Scale-free networks with tunable clustering (Holme-Kim)
+//! * [`generate_forest_fire`] - Directed, densifying networks grown by forest-fire burning
+//! * [`generate_watts_strogatz`] - Small-world networks built by rewiring a ring lattice
+//! * [`generate_newman_watts`] - Small-world networks built by adding shortcuts to a ring lattice
+//! * [`generate_kleinberg`] - Navigable small-world grids with distance-dependent long-range links
+//! * [`generate_stochastic_block_model`] - Random graphs with planted communities
+//! * [`generate_random_geometric`] - Random points connected within a radius
+//! * [`generate_waxman`] - Random points connected with distance-decaying probability
+//! * [`generate_hyperbolic_random_graph`] - Scale-free, clustered graphs from random points in the hyperbolic disk
+//! * [`generate_delaunay`] - Delaunay triangulations with optional Gabriel or relative-neighbourhood pruning
+//! * [`generate_hypercube`] - Hypercubes connecting bit strings that differ in one bit
+//! * [`generate_torus`] - n-dimensional tori and meshes
+//! * [`generate_butterfly`] - Butterfly and wrapped butterfly networks
+//! * [`generate_de_bruijn`] - Directed de Bruijn shift-register graphs
+//! * [`generate_kautz`] - Directed Kautz shift-register graphs
+//! * [`generate_fat_tree`] - k-ary fat-tree data-centre topologies
+//! * [`generate_triangular_lattice`] - Triangular lattices with node positions
+//! * [`generate_hexagonal_lattice`] - Hexagonal (honeycomb) lattices with node positions
+//! * [`generate_kagome`] - Kagome lattices with node positions
+//! * [`generate_wheel`] - A hub joined to every node of a cycle
+//! * [`generate_ladder`] - Two paths joined by rungs
+//! * [`generate_circular_ladder`] - Two cycles joined by rungs (prism graphs)
+//! * [`generate_lollipop`] - A clique with a path attached
+//! * [`generate_barbell`] - Two cliques joined by a path
+//! * [`generate_complete_bipartite`] - Complete bipartite graphs
+//! * [`generate_complete_multipartite`] - Complete multipartite graphs
+//! * [`generate_turan`] - Turán graphs (balanced complete multipartite)
+//! * [`generate_friendship`] - Triangles sharing a common hub
+//! * [`generate_named`] - Well-known fixed graphs such as Petersen or Zachary's karate club
+//! * [`generate_random_tree`] - Uniformly random labelled trees
+//! * [`generate_random_recursive_tree`] - Trees grown by attaching to uniformly chosen nodes
+//! * [`generate_preferential_attachment_tree`] - Trees grown by degree-proportional attachment
+//! * [`generate_caterpillar`] - Random caterpillars (a path with attached leaves)
+//! * [`generate_random_forest`] - Random forests with a given number of trees
+//! * [`generate_configuration_model`] - Random graphs with a given or sampled degree sequence
+//! * [`generate_havel_hakimi`] - Deterministic realisations of a graphical degree sequence
+//! * [`generate_rmat`] - Graph500-style R-MAT benchmark graphs
+//! * [`generate_stochastic_kronecker`] - Stochastic Kronecker graphs from an initiator matrix
+//!
+//! ## Usage in GGL
+//!
+//! Generators are invoked using the `generate` statement:
+//! ```ggl
+//! generate complete {
+//!     nodes: 5;
+//!     prefix: "vertex";
+//! }
+//! ```
+//!
+//! ## Common Parameters
+//!
+//! Most generators support these parameters:
+//! * `nodes` - Number of nodes to generate (required for most generators)
+//! * `prefix` - Node name prefix (optional, default: "n")
+//! * `directed` - Whether edges should be directed (optional, default: false)
+//! * `seed` - Seed for the random number generator (optional, random generators only)
+//!
+//! Every `generate` statement also accepts `node_type`, `edge_type`,
+//! `node_attrs` and `edge_attrs` to label its output, with attribute values
+//! that may be sampled per element; see [`attributes`](crate::attributes).
+//!
+//! Each generator declares its parameters in a [`ParamSpec`] schema. The
+//! engine resolves `generate` statements through a [`GeneratorRegistry`],
+//! which starts with every generator above and accepts custom [`Generator`]
+//! implementations. Before running a generator the engine checks the
+//! statement against the schema, so `node: 5;` fails with "did you mean
+//! nodes?" and `prefix: 5;` fails instead of falling back to the default.
+//! The `generate_*` functions themselves stay lenient when called from Rust.
+//!
+//! Parameter values may be strings, numbers, booleans or lists; nested lists
+//! express matrices, e.g. `probabilities: [[0.5, 0.1], [0.1, 0.5]];`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use graph_generation_language::generators::generate_complete;
+//! use graph_generation_language::types::MetadataValue;
+//! use std::collections::HashMap;
+//!
+//! let mut params = HashMap::new();
+//! params.insert("nodes".to_string(), MetadataValue::Integer(4));
+//! params.insert("prefix".to_string(), MetadataValue::String("vertex".to_string()));
+//!
+//! let graph = generate_complete(&params).unwrap();
+//! assert_eq!(graph.node_count(), 4);
+//! assert_eq!(graph.edge_count(), 6); // Complete graph: n*(n-1)/2 edges
+//! ```
+
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+mod builtins;
+mod classic;
+mod community;
+mod degree_sequence;
+mod geometric;
+mod interconnect;
+mod kronecker;
+mod lattice;
+mod named;
+mod planar;
+mod registry;
+mod small_world;
+mod trees;
+
+pub use classic::{
+    generate_barbell, generate_circular_ladder, generate_complete_bipartite,
+    generate_complete_multipartite, generate_friendship, generate_ladder, generate_lollipop,
+    generate_turan, generate_wheel,
+};
+pub use community::generate_stochastic_block_model;
+pub use degree_sequence::{generate_configuration_model, generate_havel_hakimi};
+pub use geometric::{generate_hyperbolic_random_graph, generate_random_geometric, generate_waxman};
+pub use interconnect::{
+    generate_butterfly, generate_de_bruijn, generate_fat_tree, generate_hypercube, generate_kautz,
+    generate_torus,
+};
+pub use kronecker::{generate_rmat, generate_stochastic_kronecker};
+pub use lattice::{generate_hexagonal_lattice, generate_kagome, generate_triangular_lattice};
+pub use named::generate_named;
+pub use planar::generate_delaunay;
+pub(crate) use registry::{check_params, suggest};
+pub use registry::{FnGenerator, Generator, GeneratorRegistry, ParamSpec, ParamType};
+pub use small_world::{generate_kleinberg, generate_newman_watts, generate_watts_strogatz};
+pub use trees::{
+    generate_caterpillar, generate_preferential_attachment_tree, generate_random_forest,
+    generate_random_recursive_tree, generate_random_tree,
+};
+
+/// Function signature for graph generator functions.
+///
+/// All generators take a parameter map and return either a generated graph or an error message.
+pub type GeneratorFn = fn(&HashMap<String, MetadataValue>) -> Result<Graph, String>;
+
+/// Returns the generator function for the given name.
+///
+/// Looks the name up among the built-in generators. The engine resolves
+/// `generate` statements through its [`GeneratorRegistry`] instead, which also
+/// holds custom generators; `ggl generators` lists every built-in generator
+/// with its parameters.
+///
+/// # Arguments
+///
+/// * `name` - Name of the generator to retrieve
+///
+/// # Returns
+///
+/// `Some(GeneratorFn)` if the generator exists, `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::get_generator;
+///
+/// let generator = get_generator("complete");
+/// assert!(generator.is_some());
+///
+/// let unknown = get_generator("unknown");
+/// assert!(unknown.is_none());
+/// ```
+pub fn get_generator(name: &str) -> Option<GeneratorFn> {
+    // Built once, since the built-ins carry their parameter specs as well
+    static FUNCTIONS: OnceLock<HashMap<String, GeneratorFn>> = OnceLock::new();
+    FUNCTIONS
+        .get_or_init(|| {
+            builtins::builtin_generators()
+                .into_iter()
+                .map(|generator| (generator.name().to_string(), generator.function()))
+                .collect()
+        })
+        .get(name)
+        .copied()
+}
+
+/// Helper function to extract integer parameters from the parameter map.
+///
+/// Converts both integer and float values to usize, with negative values clamped to 0.
+fn get_param_int(params: &HashMap<String, MetadataValue>, key: &str) -> Result<usize, String> {
+    match params.get(key) {
+        Some(MetadataValue::Integer(n)) => {
+            if *n < 0 {
+                Ok(0)
+            } else {
+                Ok(*n as usize)
+            }
+        }
+        Some(MetadataValue::Float(n)) => {
+            if *n < 0.0 {
+                Ok(0)
+            } else {
+                Ok(*n as usize)
+            }
+        }
+        _ => Err(format!("Missing or invalid {} parameter", key)),
+    }
+}
+
+/// Helper function to extract optional integer parameters with default values.
+///
+/// Unlike the other defaulted helpers, a present but invalid value is an error.
+pub(crate) fn get_param_int_or(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+    default: usize,
+) -> Result<usize, String> {
+    match params.get(key) {
+        Some(_) => get_param_int(params, key),
+        None => Ok(default),
+    }
+}
+
+/// Helper function to extract required floating-point parameters.
+fn get_param_float_required(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+) -> Result<f64, String> {
+    match params.get(key) {
+        Some(MetadataValue::Float(f)) => Ok(*f),
+        Some(MetadataValue::Integer(n)) => Ok(*n as f64),
+        _ => Err(format!("Missing or invalid {} parameter", key)),
+    }
+}
+
+/// Helper function to extract floating-point parameters with default values.
+///
+/// Integer values are widened to `f64`; any other value falls back to the default.
+fn get_param_float(params: &HashMap<String, MetadataValue>, key: &str, default: f64) -> f64 {
+    match params.get(key) {
+        Some(MetadataValue::Float(f)) => *f,
+        Some(MetadataValue::Integer(n)) => *n as f64,
+        _ => default,
+    }
+}
+
+/// Helper function to extract a probability parameter, rejecting values outside `[0, 1]`.
+fn get_param_probability(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+    default: f64,
+) -> Result<f64, String> {
+    let p = get_param_float(params, key, default);
+    if !(0.0..=1.0).contains(&p) {
+        return Err(format!("{} must be between 0 and 1, got {}", key, p));
+    }
+    Ok(p)
+}
+
+/// Helper function to extract a list parameter of non-negative integers.
+fn get_param_int_list(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+) -> Result<Vec<usize>, String> {
+    match params.get(key) {
+        Some(MetadataValue::List(items)) => items
+            .iter()
+            .map(|item| match item {
+                MetadataValue::Integer(n) if *n >= 0 => Ok(*n as usize),
+                other => Err(format!(
+                    "{} must contain non-negative integers, got {:?}",
+                    key, other
+                )),
+            })
+            .collect(),
+        _ => Err(format!("Missing or invalid {} parameter", key)),
+    }
+}
+
+/// Helper function to extract an optional list of strings.
+fn get_param_string_list(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+) -> Result<Option<Vec<String>>, String> {
+    match params.get(key) {
+        None => Ok(None),
+        Some(MetadataValue::List(items)) => items
+            .iter()
+            .map(|item| match item {
+                MetadataValue::String(s) => Ok(s.clone()),
+                other => Err(format!("{} must contain strings, got {:?}", key, other)),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some),
+        Some(other) => Err(format!("{} must be a list, got {:?}", key, other)),
+    }
+}
+
+/// Helper function to extract a matrix parameter given as a list of rows.
+fn get_param_float_matrix(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+) -> Result<Vec<Vec<f64>>, String> {
+    let rows = match params.get(key) {
+        Some(MetadataValue::List(rows)) => rows,
+        _ => return Err(format!("Missing or invalid {} parameter", key)),
+    };
+
+    rows.iter()
+        .map(|row| match row {
+            MetadataValue::List(cells) => cells
+                .iter()
+                .map(|cell| match cell {
+                    MetadataValue::Float(f) => Ok(*f),
+                    MetadataValue::Integer(n) => Ok(*n as f64),
+                    other => Err(format!("{} must contain numbers, got {:?}", key, other)),
+                })
+                .collect(),
+            other => Err(format!("{} must be a list of rows, got {:?}", key, other)),
+        })
+        .collect()
+}
+
+/// Helper function to build the random number generator for a generator.
+///
+/// Uses the `seed` parameter when present so that output is reproducible,
+/// and falls back to an entropy-seeded generator otherwise.
+pub(crate) fn get_rng(params: &HashMap<String, MetadataValue>) -> Result<SmallRng, String> {
+    match params.get("seed") {
+        Some(MetadataValue::Integer(seed)) => Ok(SmallRng::seed_from_u64(*seed as u64)),
+        Some(other) => Err(format!("seed must be an integer, got {:?}", other)),
+        None => Ok(SmallRng::from_entropy()),
+    }
+}
+
+/// Helper function to extract string parameters with default values.
+pub(crate) fn get_param_string(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+    default: &str,
+) -> String {
+    match params.get(key) {
+        Some(MetadataValue::String(s)) => s.clone(),
+        _ => default.to_string(),
+    }
+}
+
+/// Helper function to extract boolean parameters with default values.
+pub(crate) fn get_param_bool(
+    params: &HashMap<String, MetadataValue>,
+    key: &str,
+    default: bool,
+) -> bool {
+    match params.get(key) {
+        Some(MetadataValue::Boolean(b)) => *b,
+        _ => default,
+    }
+}
+
+/// Generates a complete graph where every node is connected to every other node.
+///
+/// A complete graph is a graph where every pair of distinct nodes is connected by a unique edge.
+/// This creates the maximum possible number of edges for a given number of nodes.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `prefix` (optional) - Node name prefix (default: "n")
+/// * `directed` (optional) - Whether edges should be directed (default: false)
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: n(n-1)/2 for undirected, n(n-1) for directed
+/// * **Connectivity**: Every node connected to every other node
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_complete;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(4));
+/// params.insert("prefix".to_string(), MetadataValue::String("vertex".to_string()));
+///
+/// let graph = generate_complete(&params).unwrap();
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 6); // 4*(4-1)/2 = 6 edges
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate complete {
+///     nodes: 5;
+///     prefix: "vertex";
+///     directed: false;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Fully connected networks
+/// * Cliques in social networks
+/// * Reference topologies for comparison
+/// * Dense communication networks
+pub fn generate_complete(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let _directed = get_param_bool(params, "directed", false);
+
+    let mut graph = Graph::new();
+
+    // Add nodes
+    for i in 0..n {
+        let node_id = format!("{}{}", prefix, i);
+        graph.add_node(Node::new(node_id));
+    }
+
+    // Add edges (all-to-all)
+    for i in 0..n {
+        for j in (if _directed { 0 } else { i + 1 })..n {
+            if i != j {
+                let source = format!("{}{}", prefix, i);
+                let target = format!("{}{}", prefix, j);
+                let edge_id = format!("e{}_{}", i, j);
+                graph.add_edge(Edge::new(edge_id, source, target));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a path graph (linear chain of connected nodes).
+///
+/// A path graph consists of nodes arranged in a line, where each node is connected
+/// to its immediate neighbors, forming a linear chain.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `prefix` (optional) - Node name prefix (default: "n")
+/// * `directed` (optional) - Whether edges should be directed (default: false)
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: n-1
+/// * **Connectivity**: Linear chain
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate path {
+///     nodes: 6;
+///     prefix: "step";
+///     directed: true;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Sequences and pipelines
+/// * Linear processes
+/// * Chain of command structures
+/// * Sequential workflows
+pub fn generate_path(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let _directed = get_param_bool(params, "directed", false);
+    if n == 0 {
+        return Err("path needs at least 1 node".to_string());
+    }
+
+    let mut graph = Graph::new();
+
+    // Add nodes
+    for i in 0..n {
+        let node_id = format!("{}{}", prefix, i);
+        graph.add_node(Node::new(node_id));
+    }
+
+    // Add edges
+    for i in 0..n - 1 {
+        let source = format!("{}{}", prefix, i);
+        let target = format!("{}{}", prefix, i + 1);
+        let edge_id = format!("e{}_{}", i, i + 1);
+        graph.add_edge(Edge::new(edge_id, source, target));
+    }
+
+    Ok(graph)
+}
+
+/// Generates a cycle graph (circular chain of nodes).
+///
+/// A cycle graph forms a closed loop where each node is connected to exactly two neighbors,
+/// and the last node connects back to the first, creating a circular structure.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: n
+/// * **Connectivity**: Circular chain
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate cycle {
+///     nodes: 5;
+///     prefix: "vertex";
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Ring topologies
+/// * Circular processes
+/// * Closed loops
+/// * Round-robin systems
+pub fn generate_cycle(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let _directed = get_param_bool(params, "directed", false);
+
+    let mut graph = Graph::new();
+
+    // Add nodes
+    for i in 0..n {
+        let node_id = format!("{}{}", prefix, i);
+        graph.add_node(Node::new(node_id));
+    }
+
+    // Add edges
+    for i in 0..n {
+        let source = format!("{}{}", prefix, i);
+        let target = format!("{}{}", prefix, (i + 1) % n);
+        let edge_id = format!("e{}_{}", i, (i + 1) % n);
+        graph.add_edge(Edge::new(edge_id, source, target));
+    }
+
+    Ok(graph)
+}
+
+pub fn generate_grid(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let rows = get_param_int(params, "rows")?;
+    let cols = get_param_int(params, "cols")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let periodic = get_param_bool(params, "periodic", false);
+    if rows == 0 || cols == 0 {
+        return Err("grid needs at least 1 row and 1 column".to_string());
+    }
+
+    let mut graph = Graph::new();
+
+    // Add nodes
+    for i in 0..rows {
+        for j in 0..cols {
+            let node_id = format!("{}{}_{}", prefix, i, j);
+            graph.add_node(Node::new(node_id));
+        }
+    }
+
+    // Add horizontal edges
+    for i in 0..rows {
+        for j in 0..cols - 1 {
+            let source = format!("{}{}_{}", prefix, i, j);
+            let target = format!("{}{}_{}", prefix, i, j + 1);
+            let edge_id = format!("eh{}_{}", i, j);
+            graph.add_edge(Edge::new(edge_id, source, target));
+        }
+        // Add periodic horizontal edges if requested
+        if periodic {
+            let source = format!("{}{}_{}", prefix, i, cols - 1);
+            let target = format!("{}{}_{}", prefix, i, 0);
+            let edge_id = format!("ehp_{}", i);
+            graph.add_edge(Edge::new(edge_id, source, target));
+        }
+    }
+
+    // Add vertical edges
+    for j in 0..cols {
+        // Regular vertical edges
+        for i in 0..rows - 1 {
+            let source = format!("{}{}_{}", prefix, i, j);
+            let target = format!("{}{}_{}", prefix, i + 1, j);
+            let edge_id = format!("ev{}_{}", i, j);
+            graph.add_edge(Edge::new(edge_id, source, target));
+        }
+        // Add periodic vertical edges if requested
+        if periodic {
+            let source = format!("{}{}_{}", prefix, rows - 1, j);
+            let target = format!("{}{}_{}", prefix, 0, j);
+            let edge_id = format!("evp_{}", j);
+            graph.add_edge(Edge::new(edge_id, source, target));
+        }
+    }
+
+    Ok(graph)
+}
+
+pub fn generate_star(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let directed = get_param_bool(params, "directed", false);
+
+    let mut graph = Graph::new();
+
+    // Add center node
+    let center = format!("{}0", prefix);
+    graph.add_node(Node::new(center.clone()));
+
+    // Add leaf nodes and connect to center
+    for i in 1..n {
+        let node_id = format!("{}{}", prefix, i);
+        graph.add_node(Node::new(node_id.clone()));
+
+        let edge_id = format!("e0_{}", i);
+        if directed {
+            graph.add_edge(Edge::new(edge_id, center.clone(), node_id));
+        } else {
+            graph.add_edge(Edge::new(edge_id, node_id, center.clone()));
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a balanced tree with `branching` children per node and `depth` levels.
+///
+/// Nodes are numbered breadth-first from the root `{prefix}0`. Every node stores
+/// its distance from the root in a `depth` attribute and every non-root node the
+/// ID of its parent in a `parent` attribute, as do the random trees in
+/// [`generate_random_tree`] and its siblings.
+pub fn generate_tree(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let branching = get_param_int(params, "branching")?;
+    let depth = get_param_int(params, "depth")?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    let mut graph = Graph::new();
+
+    // Add root node
+    let root = format!("{}0", prefix);
+    graph.add_node(
+        Node::new(root.clone()).with_metadata("depth".to_string(), MetadataValue::Integer(0)),
+    );
+
+    // Generate tree recursively
+    generate_tree_recursive(&mut graph, &root, 0, depth, branching, prefix);
+
+    Ok(graph)
+}
+
+fn generate_tree_recursive(
+    graph: &mut Graph,
+    parent: &str,
+    current_depth: usize,
+    max_depth: usize,
+    branching: usize,
+    prefix: String,
+) {
+    if max_depth == 0 || current_depth >= max_depth - 1 {
+        return;
+    }
+
+    let parent_index = parent.trim_start_matches(&prefix).parse::<usize>().unwrap();
+
+    for i in 0..branching {
+        let child_index = parent_index * branching + i + 1;
+        let child_id = format!("{}{}", prefix, child_index);
+
+        graph.add_node(
+            Node::new(child_id.clone())
+                .with_metadata(
+                    "depth".to_string(),
+                    MetadataValue::Integer(current_depth as i64 + 1),
+                )
+                .with_metadata(
+                    "parent".to_string(),
+                    MetadataValue::String(parent.to_string()),
+                ),
+        );
+        graph.add_edge(Edge::new(
+            format!("e{}_{}", parent_index, child_index),
+            parent.to_string(),
+            child_id.clone(),
+        ));
+
+        generate_tree_recursive(
+            graph,
+            &child_id,
+            current_depth + 1,
+            max_depth,
+            branching,
+            prefix.clone(),
+        );
+    }
+}
+
+/// Builds the edge list of the seed graph used to start preferential attachment.
+fn initial_graph_edges(kind: &str, size: usize) -> Result<Vec<(usize, usize)>, String> {
+    let edges = match kind {
+        "complete" => (0..size)
+            .flat_map(|i| (i + 1..size).map(move |j| (i, j)))
+            .collect(),
+        "star" => (1..size).map(|i| (0, i)).collect(),
+        "path" => (1..size).map(|i| (i - 1, i)).collect(),
+        "cycle" => {
+            let mut edges: Vec<_> = (1..size).map(|i| (i - 1, i)).collect();
+            if size > 2 {
+                edges.push((size - 1, 0));
+            }
+            edges
+        }
+        other => {
+            return Err(format!(
+                "Unknown initial_graph {}, expected one of complete, star, path, cycle",
+                other
+            ))
+        }
+    };
+    Ok(edges)
+}
+
+/// Generates a scale-free network using Barabási-Albert preferential attachment.
+///
+/// The generator starts from a small seed graph and adds the remaining nodes one
+/// at a time. Each new node connects to exactly `edges_per_node` distinct existing
+/// nodes, chosen with probability proportional to their degree. Degree-weighted
+/// sampling uses a list holding every edge endpoint, so each step is constant
+/// time and the whole graph is built in O(n·m).
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `edges_per_node` (required) - Number of edges `m` added with each new node
+/// * `initial_graph` (optional) - Seed graph: "complete", "star", "path" or "cycle" (default: "complete")
+/// * `initial_nodes` (optional) - Number of nodes in the seed graph (default: m+1)
+/// * `directed` (optional) - Attach proportionally to in-degree + 1 instead of degree (default: false)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: edges of the seed graph + (n - initial_nodes)·m
+/// * **Degree distribution**: Power law with exponent 3
+///
+/// New edges always point from the new node to the existing node it attached to.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_barabasi_albert;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(100));
+/// params.insert("edges_per_node".to_string(), MetadataValue::Integer(3));
+/// params.insert("seed".to_string(), MetadataValue::Integer(1));
+///
+/// let graph = generate_barabasi_albert(&params).unwrap();
+/// assert_eq!(graph.node_count(), 100);
+/// assert_eq!(graph.edge_count(), 6 + 96 * 3); // K4 seed + 3 edges per new node
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate barabasi_albert {
+///     nodes: 1000;
+///     edges_per_node: 2;
+///     initial_graph: "star";
+///     seed: 42;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Citation and web graphs
+/// * Social networks with hubs
+/// * Benchmarks with heavy-tailed degree distributions
+pub fn generate_barabasi_albert(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    use rand::Rng;
+
+    let n = get_param_int(params, "nodes")?;
+    let m = get_param_int(params, "edges_per_node")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let initial_graph = get_param_string(params, "initial_graph", "complete");
+    let directed = get_param_bool(params, "directed", false);
+
+    if m >= n {
+        return Err("edges_per_node must be less than nodes".to_string());
+    }
+
+    if m == 0 {
+        return Err("edges_per_node must be greater than 0".to_string());
+    }
+
+    let initial_nodes = get_param_int_or(params, "initial_nodes", std::cmp::max(m + 1, 2))?;
+    if initial_nodes <= m || initial_nodes > n {
+        return Err(format!(
+            "initial_nodes must be between {} and {}, got {}",
+            m + 1,
+            n,
+            initial_nodes
+        ));
+    }
+
+    let mut rng = get_rng(params)?;
+    let mut graph = Graph::new();
+    graph.nodes.reserve(n);
+    graph
+        .edges
+        .reserve(initial_nodes * initial_nodes / 2 + (n - initial_nodes) * m);
+    let node_ids: Vec<String> = (0..n).map(|i| format!("{}{}", prefix, i)).collect();
+
+    // Every entry is a node index; a node appears once per unit of attachment weight
+    let mut endpoints: Vec<usize> = Vec::with_capacity(2 * (n * m + initial_nodes * initial_nodes));
+
+    for node_id in &node_ids[..initial_nodes] {
+        graph.add_node(Node::new(node_id.clone()));
+    }
+    if directed {
+        endpoints.extend(0..initial_nodes);
+    }
+    for (i, j) in initial_graph_edges(&initial_graph, initial_nodes)? {
+        let edge_id = format!("e{}_{}", i, j);
+        graph.add_edge(Edge::new(edge_id, node_ids[i].clone(), node_ids[j].clone()));
+        if !directed {
+            endpoints.push(i);
+        }
+        endpoints.push(j);
+    }
+
+    // Add remaining nodes using preferential attachment
+    let mut selected = Vec::with_capacity(m);
+    for i in initial_nodes..n {
+        graph.add_node(Node::new(node_ids[i].clone()));
+
+        // Sample until m distinct targets are found; the seed graph guarantees
+        // at least m+1 candidates, so this always terminates
+        selected.clear();
+        while selected.len() < m {
+            let target = endpoints[rng.gen_range(0..endpoints.len())];
+            if !selected.contains(&target) {
+                selected.push(target);
+            }
+        }
+
+        for &target in &selected {
+            let edge_id = format!("e{}_{}", i, target);
+            graph.add_edge(Edge::new(
+                edge_id,
+                node_ids[i].clone(),
+                node_ids[target].clone(),
+            ));
+            if !directed {
+                endpoints.push(i);
+            }
+            endpoints.push(target);
+        }
+        if directed {
+            endpoints.push(i);
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a scale-free network with tunable clustering (Holme-Kim model).
+///
+/// Grows like [`generate_barabasi_albert`], starting from a complete graph on
+/// `edges_per_node + 1` nodes, but after each preferential-attachment step the
+/// new node closes a triangle with probability `triad_probability`: it also
+/// links to a random neighbour of the node it just attached to. This keeps the
+/// power-law degree distribution while raising the clustering coefficient
+/// towards that of real social networks.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `edges_per_node` (required) - Number of edges `m` added with each new node
+/// * `triad_probability` (optional) - Probability of a triad-formation step (default: 0.5)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: m(m+1)/2 + (n - m - 1)·m
+/// * **Degree distribution**: Power law, as in Barabási-Albert
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate powerlaw_cluster {
+///     nodes: 1000;
+///     edges_per_node: 3;
+///     triad_probability: 0.8;
+///     seed: 42;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Social networks with both hubs and tight friend groups
+/// * Clustering-sensitive benchmarks
+pub fn generate_powerlaw_cluster(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    use rand::Rng;
+
+    let n = get_param_int(params, "nodes")?;
+    let m = get_param_int(params, "edges_per_node")?;
+    let triad_probability = get_param_probability(params, "triad_probability", 0.5)?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if m == 0 || m >= n {
+        return Err(format!(
+            "edges_per_node must be between 1 and nodes - 1, got {}",
+            m
+        ));
+    }
+
+    let mut rng = get_rng(params)?;
+    let mut graph = Graph::new();
+    let node_ids: Vec<String> = (0..n).map(|i| format!("{}{}", prefix, i)).collect();
+    let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut endpoints: Vec<usize> = Vec::with_capacity(2 * n * m + m * m);
+    let mut selected: Vec<usize> = Vec::with_capacity(m);
+
+    for i in 0..n {
+        graph.add_node(Node::new(node_ids[i].clone()));
+
+        selected.clear();
+        if i <= m {
+            // Seed clique
+            selected.extend(0..i);
+        } else {
+            let mut last_attached: Option<usize> = None;
+            while selected.len() < m {
+                if let Some(anchor) = last_attached {
+                    if rng.gen::<f64>() < triad_probability {
+                        let candidates: Vec<usize> = neighbours[anchor]
+                            .iter()
+                            .copied()
+                            .filter(|v| !selected.contains(v))
+                            .collect();
+                        if !candidates.is_empty() {
+                            selected.push(candidates[rng.gen_range(0..candidates.len())]);
+                            continue;
+                        }
+                    }
+                }
+
+                // Preferential attachment; the seed clique guarantees m+1 candidates
+                let target = endpoints[rng.gen_range(0..endpoints.len())];
+                if !selected.contains(&target) {
+                    selected.push(target);
+                    last_attached = Some(target);
+                }
+            }
+        }
+
+        for &target in &selected {
+            graph.add_edge(Edge::new(
+                format!("e{}_{}", i, target),
+                node_ids[i].clone(),
+                node_ids[target].clone(),
+            ));
+            neighbours[i].push(target);
+            neighbours[target].push(i);
+            endpoints.extend([i, target]);
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Generates a directed network with Leskovec's forest-fire model.
+///
+/// Nodes arrive one at a time. Each new node links to a uniformly chosen
+/// "ambassador" and then spreads a fire: from every burning node it burns a
+/// geometrically distributed number of not-yet-burnt out-neighbours (mean
+/// p/(1-p) for `forward_probability` p) and in-neighbours (likewise for
+/// `backward_probability`), linking to each node it burns. The resulting graphs
+/// densify over time, have shrinking diameters and heavy-tailed in- and
+/// out-degrees.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `forward_probability` (optional) - Forward burning probability, below 1 (default: 0.37)
+/// * `backward_probability` (optional) - Backward burning probability, below 1 (default: 0.32)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: at least n - 1, always from the newer node to the older one
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate forest_fire {
+///     nodes: 1000;
+///     forward_probability: 0.35;
+///     backward_probability: 0.3;
+///     seed: 42;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Citation, web and social graphs that densify as they grow
+/// * Directed benchmarks with community structure
+pub fn generate_forest_fire(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    use rand::seq::SliceRandom;
+    use rand::Rng;
+
+    let n = get_param_int(params, "nodes")?;
+    let forward = get_param_probability(params, "forward_probability", 0.37)?;
+    let backward = get_param_probability(params, "backward_probability", 0.32)?;
+    let prefix = get_param_string(params, "prefix", "n");
+
+    if forward >= 1.0 || backward >= 1.0 {
+        return Err("burning probabilities must be below 1".to_string());
+    }
+
+    let mut rng = get_rng(params)?;
+    let mut graph = Graph::new();
+    let node_ids: Vec<String> = (0..n).map(|i| format!("{}{}", prefix, i)).collect();
+    let mut out_links: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut in_links: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    // Number of successes before the first failure of a Bernoulli(p) process
+    let burn_count = |rng: &mut SmallRng, p: f64| {
+        let mut count = 0;
+        while rng.gen::<f64>() < p {
+            count += 1;
+        }
+        count
+    };
+
+    let mut burnt_at = vec![usize::MAX; n];
+    for v in 0..n {
+        graph.add_node(Node::new(node_ids[v].clone()));
+        if v == 0 {
+            continue;
+        }
+
+        let ambassador = rng.gen_range(0..v);
+        burnt_at[ambassador] = v;
+        let mut burnt = vec![ambassador];
+        let mut frontier = vec![ambassador];
+        while let Some(w) = frontier.pop() {
+            let mut candidates: Vec<usize> = Vec::new();
+            for (links, p) in [(&out_links[w], forward), (&in_links[w], backward)] {
+                let mut unburnt: Vec<usize> = links
+                    .iter()
+                    .copied()
+                    .filter(|&u| burnt_at[u] != v)
+                    .collect();
+                let count = burn_count(&mut rng, p).min(unburnt.len());
+                let (chosen, _) = unburnt.partial_shuffle(&mut rng, count);
+                for &u in chosen.iter() {
+                    burnt_at[u] = v;
+                    candidates.push(u);
+                }
+            }
+            burnt.extend(&candidates);
+            frontier.extend(candidates);
+        }
+
+        for target in burnt {
+            graph.add_edge(Edge::new(
+                format!("e{}_{}", v, target),
+                node_ids[v].clone(),
+                node_ids[target].clone(),
+            ));
+            out_links[v].push(target);
+            in_links[target].push(v);
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_graph() {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(5));
+
+        let graph = generate_complete(&params).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 10); // n*(n-1)/2 edges for undirected
+    }
+
+    #[test]
+    fn test_path_graph() {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(5));
+
+        let graph = generate_path(&params).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 4); // n-1 edges
+    }
+
+    #[test]
+    fn test_cycle_graph() {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(5));
+
+        let graph = generate_cycle(&params).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 5); // n edges
+    }
+
+    #[test]
+    fn test_grid_graph() {
+        let mut params = HashMap::new();
+        params.insert("rows".to_string(), MetadataValue::Integer(3));
+        params.insert("cols".to_string(), MetadataValue::Integer(4));
+
+        let graph = generate_grid(&params).unwrap();
+        assert_eq!(graph.node_count(), 12); // rows * cols
+        assert_eq!(graph.edge_count(), 17); // (rows-1)*cols + rows*(cols-1)
+    }
+}
@@ -0,0 +1,315 @@
+//! # Planar Generators
+//!
+//! Planar, road-like networks built from the Delaunay triangulation of a
+//! point set. Nodes carry their `x`/`y` position and edges their Euclidean
+//! `length`, ready for routing experiments.
+
+use super::{get_param_float_matrix, get_param_int, get_param_string, get_rng};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::Rng;
+use std::collections::{BTreeSet, HashMap};
+
+type Point = (f64, f64);
+
+fn squared_distance(a: Point, b: Point) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+/// A triangle of point indices with its circumcircle.
+struct Triangle {
+    vertices: [usize; 3],
+    center: Point,
+    radius_squared: f64,
+}
+
+impl Triangle {
+    fn new(vertices: [usize; 3], points: &[Point]) -> Self {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+        let center = if d.abs() < f64::EPSILON {
+            // Collinear vertices: an unbounded circle that contains every later point
+            (a.0, f64::INFINITY)
+        } else {
+            let (a2, b2, c2) = (
+                a.0 * a.0 + a.1 * a.1,
+                b.0 * b.0 + b.1 * b.1,
+                c.0 * c.0 + c.1 * c.1,
+            );
+            (
+                (a2 * (b.1 - c.1) + b2 * (c.1 - a.1) + c2 * (a.1 - b.1)) / d,
+                (a2 * (c.0 - b.0) + b2 * (a.0 - c.0) + c2 * (b.0 - a.0)) / d,
+            )
+        };
+        Triangle {
+            vertices,
+            center,
+            radius_squared: squared_distance(center, a),
+        }
+    }
+}
+
+/// Computes the edges of the Delaunay triangulation with the Bowyer-Watson algorithm.
+///
+/// Points are inserted in order of x-coordinate, so a triangle whose
+/// circumcircle lies entirely to the left of the current point can never be
+/// affected again and is set aside, which keeps the working set small.
+fn delaunay_edges(points: &[Point]) -> BTreeSet<(usize, usize)> {
+    let n = points.len();
+    if n < 2 {
+        return BTreeSet::new();
+    }
+    if n == 2 {
+        return BTreeSet::from([(0, 1)]);
+    }
+
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+    for &(x, y) in points {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let span = (max_x - min_x).max(max_y - min_y).max(1e-9) * 100.0;
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    // Work on a copy extended with the three vertices of a super-triangle
+    let mut all: Vec<Point> = points.to_vec();
+    all.extend([
+        (mid_x - 2.0 * span, mid_y - span),
+        (mid_x + 2.0 * span, mid_y - span),
+        (mid_x, mid_y + 2.0 * span),
+    ]);
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| points[a].0.total_cmp(&points[b].0));
+
+    let mut open = vec![Triangle::new([n, n + 1, n + 2], &all)];
+    let mut closed: Vec<Triangle> = Vec::new();
+    let mut boundary: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for p in order {
+        let point = all[p];
+        boundary.clear();
+
+        let mut kept = Vec::with_capacity(open.len());
+        for triangle in open {
+            let dx = point.0 - triangle.center.0;
+            if dx > 0.0 && dx * dx > triangle.radius_squared {
+                closed.push(triangle);
+            } else if squared_distance(point, triangle.center) < triangle.radius_squared {
+                let [a, b, c] = triangle.vertices;
+                for (u, v) in [(a, b), (b, c), (c, a)] {
+                    *boundary.entry((u.min(v), u.max(v))).or_insert(0) += 1;
+                }
+            } else {
+                kept.push(triangle);
+            }
+        }
+
+        // Edges shared by two removed triangles are interior to the cavity
+        for (&(u, v), &count) in &boundary {
+            if count == 1 {
+                kept.push(Triangle::new([u, v, p], &all));
+            }
+        }
+        open = kept;
+    }
+
+    let mut edges = BTreeSet::new();
+    for triangle in closed.iter().chain(&open) {
+        let [a, b, c] = triangle.vertices;
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            if u < n && v < n {
+                edges.insert((u.min(v), u.max(v)));
+            }
+        }
+    }
+    edges
+}
+
+/// Removes every edge with another point inside its region of influence.
+///
+/// For the Gabriel graph the region is the circle with the edge as diameter;
+/// for the relative-neighbourhood graph it is the lune of points closer to both
+/// endpoints than they are to each other. Both regions lie within the edge's
+/// length of its first endpoint, so only points in that x-window are checked.
+fn prune_edges(points: &[Point], edges: &mut BTreeSet<(usize, usize)>, gabriel: bool) {
+    let mut by_x: Vec<usize> = (0..points.len()).collect();
+    by_x.sort_by(|&a, &b| points[a].0.total_cmp(&points[b].0));
+    let xs: Vec<f64> = by_x.iter().map(|&i| points[i].0).collect();
+
+    edges.retain(|&(u, v)| {
+        let (pu, pv) = (points[u], points[v]);
+        let length_squared = squared_distance(pu, pv);
+        let length = length_squared.sqrt();
+        let start = xs.partition_point(|&x| x < pu.0 - length);
+        let end = xs.partition_point(|&x| x <= pu.0 + length);
+
+        !by_x[start..end].iter().any(|&w| {
+            if w == u || w == v {
+                return false;
+            }
+            let (du, dv) = (
+                squared_distance(points[w], pu),
+                squared_distance(points[w], pv),
+            );
+            if gabriel {
+                du + dv < length_squared
+            } else {
+                du.max(dv) < length_squared
+            }
+        })
+    });
+}
+
+/// Generates a Delaunay triangulation, optionally pruned to a Gabriel or
+/// relative-neighbourhood graph.
+///
+/// Points are either sampled uniformly in the unit square or given explicitly,
+/// and the Delaunay triangulation connects every pair of points whose
+/// circumscribing circle contains no other point. The result is planar and
+/// looks like a road network; the sparser `prune` variants remove long
+/// diagonal edges while staying connected:
+///
+/// * `"gabriel"` - keep edges whose diametral circle is empty
+/// * `"relative_neighborhood"` - keep edges whose lune is empty (a subgraph of
+///   the Gabriel graph containing the Euclidean minimum spanning tree)
+///
+/// # Parameters
+///
+/// * `nodes` (required unless `points` is given) - Number of points to sample
+/// * `points` (optional) - List of `[x, y]` positions to triangulate instead of sampling
+/// * `prune` (optional) - "none", "gabriel" or "relative_neighborhood" (default: "none")
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: one per point, with `x`/`y` set
+/// * **Edges**: at most 3n - 6, with the Euclidean distance as `length`
+/// * **Planarity**: No two edges cross
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_delaunay;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let point = |x: f64, y: f64| MetadataValue::List(vec![MetadataValue::Float(x), MetadataValue::Float(y)]);
+///
+/// let mut params = HashMap::new();
+/// params.insert(
+///     "points".to_string(),
+///     MetadataValue::List(vec![point(0.0, 0.0), point(1.0, 0.0), point(0.0, 1.0), point(1.0, 1.2)]),
+/// );
+///
+/// let graph = generate_delaunay(&params).unwrap();
+/// assert_eq!(graph.edge_count(), 5); // four sides and one diagonal
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate delaunay {
+///     nodes: 500;
+///     prune: "gabriel";
+///     seed: 11;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Synthetic road and street networks
+/// * Routing and shortest-path experiments on planar graphs
+/// * Mesh generation
+pub fn generate_delaunay(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let prune = get_param_string(params, "prune", "none");
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    let points: Vec<Point> = if params.contains_key("points") {
+        get_param_float_matrix(params, "points")?
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| match row[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(format!(
+                    "points[{}] must be an [x, y] pair, got {} values",
+                    i,
+                    row.len()
+                )),
+            })
+            .collect::<Result<_, _>>()?
+    } else {
+        let n = get_param_int(params, "nodes")?;
+        (0..n).map(|_| (rng.gen(), rng.gen())).collect()
+    };
+
+    let mut sorted = points.clone();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(format!(
+            "points must be distinct, ({}, {}) appears more than once",
+            pair[0].0, pair[0].1
+        ));
+    }
+
+    let mut edges = delaunay_edges(&points);
+    match prune.as_str() {
+        "none" => {}
+        "gabriel" => prune_edges(&points, &mut edges, true),
+        "relative_neighborhood" => prune_edges(&points, &mut edges, false),
+        other => {
+            return Err(format!(
+                "Unknown prune {}, expected one of none, gabriel, relative_neighborhood",
+                other
+            ))
+        }
+    }
+
+    let mut graph = Graph::new();
+    for (i, &(x, y)) in points.iter().enumerate() {
+        graph.add_node(Node::new(format!("{}{}", prefix, i)).with_position(x, y));
+    }
+    for (u, v) in edges {
+        graph.add_edge(
+            Edge::new(
+                format!("e{}_{}", u, v),
+                format!("{}{}", prefix, u),
+                format!("{}{}", prefix, v),
+            )
+            .with_metadata(
+                "length".to_string(),
+                MetadataValue::Float(squared_distance(points[u], points[v]).sqrt()),
+            ),
+        );
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delaunay_square_grid() {
+        let points: Vec<Point> = (0..9).map(|i| ((i % 3) as f64, (i / 3) as f64)).collect();
+        let edges = delaunay_edges(&points);
+        // 3n - h - 3 edges for n = 9 points with h = 8 on the hull
+        assert_eq!(edges.len(), 16);
+    }
+
+    #[test]
+    fn test_prune_removes_edge_opposite_obtuse_angle() {
+        // The apex lies inside the circle with the base as diameter
+        let points = [(0.0, 0.0), (2.0, 0.0), (1.0, 0.3)];
+        for gabriel in [true, false] {
+            let mut edges = delaunay_edges(&points);
+            assert_eq!(edges.len(), 3);
+            prune_edges(&points, &mut edges, gabriel);
+            assert_eq!(edges, BTreeSet::from([(0, 2), (1, 2)]));
+        }
+    }
+}
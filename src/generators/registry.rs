@@ -0,0 +1,785 @@
+//! # Generator Registry
+//!
+//! The [`Generator`] trait describes a generator to the engine: its name, a
+//! schema of its parameters, and how to run it. A [`GeneratorRegistry`] maps
+//! names to generators, and `generate <name> { ... }` statements resolve
+//! against the registry owned by the [`GGLEngine`](crate::GGLEngine). The
+//! built-in topologies are registered through the same trait, so a generator
+//! added from Rust is as much a part of the language as `complete` or `grid`.
+
+use crate::types::{Graph, MetadataValue};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::GeneratorFn;
+
+/// The type of value a generator parameter accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamType {
+    /// A whole number, e.g. `nodes: 10;`
+    Integer,
+    /// A number; integers are accepted and widened, e.g. `p: 0.1;`
+    Float,
+    /// A string or bare identifier, e.g. `prefix: "host";`
+    String,
+    /// `true` or `false`
+    Boolean,
+    /// A list of whole numbers, e.g. `sizes: [10, 20];`
+    IntegerList,
+    /// A list of strings, e.g. `block_types: ["a", "b"];`
+    StringList,
+    /// A list of rows of numbers, e.g. `probabilities: [[0.5, 0.1], [0.1, 0.5]];`
+    Matrix,
+    /// A value of any type, e.g. `value: 3;`
+    Any,
+}
+
+impl fmt::Display for ParamType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ParamType::Integer => "integer",
+            ParamType::Float => "float",
+            ParamType::String => "string",
+            ParamType::Boolean => "boolean",
+            ParamType::IntegerList => "integer list",
+            ParamType::StringList => "string list",
+            ParamType::Matrix => "matrix",
+            ParamType::Any => "value",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Describes one parameter of a generator.
+///
+/// Parameters are optional unless marked [`required`](ParamSpec::required).
+/// An optional parameter with a default is filled in by the engine before the
+/// generator runs, so generators can rely on it being present. Numeric
+/// parameters may be bounded with [`with_min`](ParamSpec::with_min) and
+/// [`with_max`](ParamSpec::with_max); the bounds apply to every element of a
+/// list or matrix.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::{ParamSpec, ParamType};
+/// use graph_generation_language::types::MetadataValue;
+///
+/// let nodes = ParamSpec::new("nodes", ParamType::Integer, "Number of nodes")
+///     .required()
+///     .with_min(1.0);
+/// let prefix = ParamSpec::new("prefix", ParamType::String, "Node name prefix")
+///     .with_default(MetadataValue::String("n".to_string()));
+///
+/// assert!(nodes.required && nodes.default.is_none());
+/// assert!(!prefix.required);
+///
+/// assert!(nodes.check(&MetadataValue::Integer(5)).is_ok());
+/// assert!(nodes.check(&MetadataValue::Integer(0)).is_err());
+/// assert!(prefix.check(&MetadataValue::Integer(5)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamSpec {
+    /// Name of the parameter as written in GGL
+    pub name: String,
+    /// Type of value the parameter accepts
+    pub param_type: ParamType,
+    /// Whether the parameter must be given
+    pub required: bool,
+    /// Value used when the parameter is omitted
+    pub default: Option<MetadataValue>,
+    /// One-line description for listings and editor tooling
+    pub description: String,
+    /// Smallest accepted value of a numeric parameter
+    pub min: Option<f64>,
+    /// Largest accepted value of a numeric parameter
+    pub max: Option<f64>,
+}
+
+impl ParamSpec {
+    /// Creates an optional parameter without a default.
+    pub fn new(name: &str, param_type: ParamType, description: &str) -> Self {
+        ParamSpec {
+            name: name.to_string(),
+            param_type,
+            required: false,
+            default: None,
+            description: description.to_string(),
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Marks the parameter as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the value used when the parameter is omitted.
+    pub fn with_default(mut self, default: MetadataValue) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the smallest accepted value.
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the largest accepted value.
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Checks a value against the type and bounds of the parameter.
+    pub fn check(&self, value: &MetadataValue) -> Result<(), String> {
+        let numbers = match (self.param_type, value) {
+            (ParamType::Integer, MetadataValue::Integer(n)) => vec![*n as f64],
+            (ParamType::Float, MetadataValue::Integer(n)) => vec![*n as f64],
+            (ParamType::Float, MetadataValue::Float(x)) => vec![*x],
+            (ParamType::String, MetadataValue::String(_))
+            | (ParamType::Boolean, MetadataValue::Boolean(_))
+            | (ParamType::Any, _) => Vec::new(),
+            (ParamType::IntegerList, MetadataValue::List(items))
+                if items
+                    .iter()
+                    .all(|item| matches!(item, MetadataValue::Integer(_))) =>
+            {
+                items.iter().filter_map(number).collect()
+            }
+            (ParamType::StringList, MetadataValue::List(items))
+                if items
+                    .iter()
+                    .all(|item| matches!(item, MetadataValue::String(_))) =>
+            {
+                Vec::new()
+            }
+            (ParamType::Matrix, MetadataValue::List(rows)) if rows.iter().all(is_number_list) => {
+                rows.iter()
+                    .flat_map(|row| match row {
+                        MetadataValue::List(items) => items.iter().filter_map(number).collect(),
+                        _ => Vec::new(),
+                    })
+                    .collect()
+            }
+            _ => {
+                return Err(format!(
+                    "parameter {} must be {} {}, got {}",
+                    self.name,
+                    article(self.param_type),
+                    self.param_type,
+                    describe(value)
+                ))
+            }
+        };
+
+        for x in numbers {
+            if let Some(min) = self.min.filter(|&min| x < min) {
+                return Err(format!(
+                    "parameter {} must be at least {}, got {}",
+                    self.name, min, x
+                ));
+            }
+            if let Some(max) = self.max.filter(|&max| x > max) {
+                return Err(format!(
+                    "parameter {} must be at most {}, got {}",
+                    self.name, max, x
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn number(value: &MetadataValue) -> Option<f64> {
+    match value {
+        MetadataValue::Integer(n) => Some(*n as f64),
+        MetadataValue::Float(x) => Some(*x),
+        _ => None,
+    }
+}
+
+fn is_number_list(value: &MetadataValue) -> bool {
+    matches!(value, MetadataValue::List(items) if items.iter().all(|item| number(item).is_some()))
+}
+
+fn article(param_type: ParamType) -> &'static str {
+    match param_type {
+        ParamType::Integer | ParamType::IntegerList => "an",
+        _ => "a",
+    }
+}
+
+/// Names the type and value of a parameter for error messages.
+fn describe(value: &MetadataValue) -> String {
+    match value {
+        MetadataValue::Integer(n) => format!("integer {}", n),
+        MetadataValue::Float(x) => format!("float {}", x),
+        MetadataValue::String(s) => format!("string \"{}\"", s),
+        MetadataValue::Boolean(b) => format!("boolean {}", b),
+        MetadataValue::List(_) => "a list".to_string(),
+    }
+}
+
+/// Edit distance between two names, counting insertions, deletions and substitutions.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Returns the candidate closest to a misspelt name, if any is plausibly meant.
+pub(crate) fn suggest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Checks parameters against their specs and fills in the defaults.
+///
+/// `owner` names the generator or transform in error messages.
+pub(crate) fn check_params(
+    owner: &str,
+    specs: Vec<ParamSpec>,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<HashMap<String, MetadataValue>, String> {
+    let mut names: Vec<&String> = params.keys().collect();
+    names.sort();
+    for name in names {
+        if specs.iter().all(|spec| &spec.name != name) {
+            let known = specs.iter().map(|spec| spec.name.as_str());
+            return Err(match suggest(name, known.clone()) {
+                Some(candidate) => format!(
+                    "unknown parameter {} for {}, did you mean {}?",
+                    name, owner, candidate
+                ),
+                None if specs.is_empty() => {
+                    format!("unknown parameter {}, {} takes no parameters", name, owner)
+                }
+                None => format!(
+                    "unknown parameter {} for {}, expected one of {}",
+                    name,
+                    owner,
+                    known.collect::<Vec<_>>().join(", ")
+                ),
+            });
+        }
+    }
+
+    let mut validated = params.clone();
+    for spec in specs {
+        match params.get(&spec.name) {
+            Some(value) => spec.check(value)?,
+            None if spec.required => {
+                return Err(format!(
+                    "missing required parameter {} ({})",
+                    spec.name, spec.param_type
+                ))
+            }
+            None => {
+                if let Some(default) = spec.default {
+                    validated.insert(spec.name, default);
+                }
+            }
+        }
+    }
+    Ok(validated)
+}
+
+/// A graph generator that can be invoked with `generate <name> { ... }`.
+///
+/// The engine checks the parameters of the `generate` statement with
+/// [`validate_params`](Generator::validate_params) and passes them with the
+/// defaults filled in. A generator that declares a `seed` parameter also
+/// receives a seed derived from the engine's random number generator unless
+/// the program gave one, so seeding its randomness from `seed` makes it
+/// reproducible.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::{Generator, ParamSpec, ParamType};
+/// use graph_generation_language::types::{Graph, MetadataValue, Node};
+/// use graph_generation_language::GGLEngine;
+/// use std::collections::HashMap;
+///
+/// struct Isolated;
+///
+/// impl Generator for Isolated {
+///     fn name(&self) -> &str {
+///         "isolated"
+///     }
+///
+///     fn description(&self) -> &str {
+///         "Nodes without any edges"
+///     }
+///
+///     fn params(&self) -> Vec<ParamSpec> {
+///         vec![
+///             ParamSpec::new("nodes", ParamType::Integer, "Number of nodes").required(),
+///             ParamSpec::new("prefix", ParamType::String, "Node name prefix")
+///                 .with_default(MetadataValue::String("n".to_string())),
+///         ]
+///     }
+///
+///     fn generate(&self, params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+///         let (Some(MetadataValue::Integer(n)), Some(MetadataValue::String(prefix))) =
+///             (params.get("nodes"), params.get("prefix"))
+///         else {
+///             return Err("nodes must be an integer".to_string());
+///         };
+///         let mut graph = Graph::new();
+///         for i in 0..*n {
+///             graph.add_node(Node::new(format!("{}{}", prefix, i)));
+///         }
+///         Ok(graph)
+///     }
+/// }
+///
+/// let mut engine = GGLEngine::new();
+/// engine.register_generator(Isolated);
+///
+/// let json = engine
+///     .generate_from_ggl("graph { generate isolated { nodes: 3; } }")
+///     .unwrap();
+/// assert!(json.contains("\"n2\""));
+/// ```
+pub trait Generator {
+    /// Name used in `generate <name> { ... }` statements.
+    fn name(&self) -> &str;
+
+    /// One-line description for listings and editor tooling.
+    fn description(&self) -> &str;
+
+    /// Schema of the accepted parameters.
+    fn params(&self) -> Vec<ParamSpec>;
+
+    /// Builds a graph from the given parameters.
+    fn generate(&self, params: &HashMap<String, MetadataValue>) -> Result<Graph, String>;
+
+    /// Checks parameters against [`params`](Generator::params) and fills in defaults.
+    ///
+    /// Unknown parameters are rejected, with a suggestion when one is close to
+    /// a declared name, as are missing required parameters and values of the
+    /// wrong type or out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::{Generator, GeneratorRegistry};
+    /// use graph_generation_language::types::MetadataValue;
+    /// use std::collections::HashMap;
+    ///
+    /// let registry = GeneratorRegistry::default();
+    /// let complete = registry.get("complete").unwrap();
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert("node".to_string(), MetadataValue::Integer(5));
+    /// let error = complete.validate_params(&params).unwrap_err();
+    /// assert!(error.contains("did you mean nodes?"));
+    /// ```
+    fn validate_params(
+        &self,
+        params: &HashMap<String, MetadataValue>,
+    ) -> Result<HashMap<String, MetadataValue>, String> {
+        check_params(self.name(), self.params(), params)
+    }
+
+    /// Describes the generator and its parameters for a terminal listing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::{Generator, GeneratorRegistry};
+    ///
+    /// let registry = GeneratorRegistry::default();
+    /// let usage = registry.get("grid").unwrap().usage();
+    /// assert!(usage.starts_with("grid - "));
+    /// assert!(usage.contains("    rows: integer (required, at least 1) - Number of rows"));
+    /// assert!(usage.contains("    prefix: string (default: \"n\") - Node name prefix"));
+    /// ```
+    fn usage(&self) -> String {
+        let mut usage = format!("{} - {}\n", self.name(), self.description());
+        for spec in self.params() {
+            let mut notes = Vec::new();
+            if spec.required {
+                notes.push("required".to_string());
+            }
+            if let Some(default) = &spec.default {
+                notes.push(format!(
+                    "default: {}",
+                    serde_json::to_string(default).unwrap()
+                ));
+            }
+            match (spec.min, spec.max) {
+                (Some(min), Some(max)) => notes.push(format!("{} to {}", min, max)),
+                (Some(min), None) => notes.push(format!("at least {}", min)),
+                (None, Some(max)) => notes.push(format!("at most {}", max)),
+                (None, None) => {}
+            }
+
+            usage.push_str(&format!("    {}: {}", spec.name, spec.param_type));
+            if !notes.is_empty() {
+                usage.push_str(&format!(" ({})", notes.join(", ")));
+            }
+            usage.push_str(&format!(" - {}\n", spec.description));
+        }
+        usage
+    }
+
+    /// Returns a JSON Schema for the parameters of the generator.
+    ///
+    /// The schema describes the parameter block of a `generate` statement as
+    /// an object, so editors can offer completion and flag mistakes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::{Generator, GeneratorRegistry};
+    ///
+    /// let registry = GeneratorRegistry::default();
+    /// let schema = registry.get("watts_strogatz").unwrap().json_schema();
+    /// assert_eq!(schema["properties"]["p"]["type"], "number");
+    /// assert_eq!(schema["properties"]["p"]["maximum"], 1.0);
+    /// assert_eq!(schema["required"][0], "nodes");
+    /// ```
+    fn json_schema(&self) -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+        for spec in self.params() {
+            let element = |type_name: &str| {
+                let mut element = serde_json::json!({ "type": type_name });
+                let bound = |x: f64| match type_name {
+                    "integer" => serde_json::json!(x as i64),
+                    _ => serde_json::json!(x),
+                };
+                if let Some(min) = spec.min {
+                    element["minimum"] = bound(min);
+                }
+                if let Some(max) = spec.max {
+                    element["maximum"] = bound(max);
+                }
+                element
+            };
+            let mut property = match spec.param_type {
+                ParamType::Integer => element("integer"),
+                ParamType::Float => element("number"),
+                ParamType::String => element("string"),
+                ParamType::Boolean => element("boolean"),
+                ParamType::IntegerList => {
+                    serde_json::json!({ "type": "array", "items": element("integer") })
+                }
+                ParamType::StringList => {
+                    serde_json::json!({ "type": "array", "items": element("string") })
+                }
+                ParamType::Matrix => serde_json::json!({
+                    "type": "array",
+                    "items": { "type": "array", "items": element("number") },
+                }),
+                ParamType::Any => serde_json::json!({}),
+            };
+            property["description"] = serde_json::json!(spec.description);
+            if let Some(default) = &spec.default {
+                property["default"] = serde_json::json!(default);
+            }
+            if spec.required {
+                required.push(spec.name.clone());
+            }
+            properties.insert(spec.name, property);
+        }
+
+        serde_json::json!({
+            "type": "object",
+            "description": self.description(),
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+}
+
+/// A [`Generator`] backed by a plain generator function.
+///
+/// This is how the built-in generators are registered, and the quickest way to
+/// register an existing function.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::{generate_cycle, FnGenerator, ParamSpec, ParamType};
+/// use graph_generation_language::GGLEngine;
+///
+/// let mut engine = GGLEngine::new();
+/// engine.register_generator(
+///     FnGenerator::new("ring", "Cycle graphs under another name", generate_cycle)
+///         .with_param(ParamSpec::new("nodes", ParamType::Integer, "Number of nodes").required()),
+/// );
+///
+/// assert!(engine.generators().get("ring").is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FnGenerator {
+    name: String,
+    description: String,
+    params: Vec<ParamSpec>,
+    function: GeneratorFn,
+}
+
+impl FnGenerator {
+    /// Wraps a generator function with no declared parameters.
+    pub fn new(name: &str, description: &str, function: GeneratorFn) -> Self {
+        FnGenerator {
+            name: name.to_string(),
+            description: description.to_string(),
+            params: Vec::new(),
+            function,
+        }
+    }
+
+    /// Declares a parameter of the generator.
+    pub fn with_param(mut self, param: ParamSpec) -> Self {
+        self.params.push(param);
+        self
+    }
+
+    /// Returns the wrapped generator function.
+    pub fn function(&self) -> GeneratorFn {
+        self.function
+    }
+}
+
+impl Generator for FnGenerator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn params(&self) -> Vec<ParamSpec> {
+        self.params.clone()
+    }
+
+    fn generate(&self, params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+        (self.function)(params)
+    }
+}
+
+/// A set of generators addressable by name.
+///
+/// [`GeneratorRegistry::default`] contains every built-in generator, while
+/// [`GeneratorRegistry::new`] starts empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::GeneratorRegistry;
+///
+/// let registry = GeneratorRegistry::default();
+/// let complete = registry.get("complete").unwrap();
+/// assert_eq!(complete.name(), "complete");
+/// assert!(complete.params().iter().any(|param| param.name == "nodes" && param.required));
+///
+/// assert!(GeneratorRegistry::new().is_empty());
+/// ```
+pub struct GeneratorRegistry {
+    generators: BTreeMap<String, Box<dyn Generator>>,
+}
+
+impl Default for GeneratorRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+impl GeneratorRegistry {
+    /// Creates a registry without any generators.
+    pub fn new() -> Self {
+        GeneratorRegistry {
+            generators: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a registry containing every built-in generator.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        for generator in super::builtins::builtin_generators() {
+            registry.register(generator);
+        }
+        registry
+    }
+
+    /// Adds a generator, replacing and returning any generator of the same name.
+    pub fn register<G: Generator + 'static>(&mut self, generator: G) -> Option<Box<dyn Generator>> {
+        self.generators
+            .insert(generator.name().to_string(), Box::new(generator))
+    }
+
+    /// Returns the generator with the given name.
+    pub fn get(&self, name: &str) -> Option<&dyn Generator> {
+        self.generators
+            .get(name)
+            .map(|generator| generator.as_ref())
+    }
+
+    /// Returns the generator names in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.generators.keys().map(String::as_str)
+    }
+
+    /// Iterates over the generators in alphabetical order of name.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Generator> {
+        self.generators.values().map(|generator| generator.as_ref())
+    }
+
+    /// Returns the number of registered generators.
+    pub fn len(&self) -> usize {
+        self.generators.len()
+    }
+
+    /// Returns whether no generators are registered.
+    pub fn is_empty(&self) -> bool {
+        self.generators.is_empty()
+    }
+
+    /// Describes every generator and its parameters for a terminal listing.
+    ///
+    /// This is the output of `ggl generators`.
+    pub fn usage(&self) -> String {
+        self.iter()
+            .map(|generator| generator.usage())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns a JSON Schema for the parameters of every generator.
+    ///
+    /// Each generator's schema is listed under `definitions`, keyed by name.
+    /// This is the output of `ggl generators --json`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::GeneratorRegistry;
+    ///
+    /// let schema = GeneratorRegistry::default().json_schema();
+    /// let complete = &schema["definitions"]["complete"];
+    /// assert_eq!(complete["properties"]["nodes"]["type"], "integer");
+    /// assert_eq!(complete["additionalProperties"], false);
+    /// ```
+    pub fn json_schema(&self) -> serde_json::Value {
+        let definitions: serde_json::Map<String, serde_json::Value> = self
+            .iter()
+            .map(|generator| (generator.name().to_string(), generator.json_schema()))
+            .collect();
+        serde_json::json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "GGL generator parameters",
+            "definitions": definitions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{generate_cycle, generate_path, get_generator};
+
+    #[test]
+    fn test_register_replaces_by_name() {
+        let mut registry = GeneratorRegistry::new();
+        assert!(registry
+            .register(FnGenerator::new("line", "Paths", generate_path))
+            .is_none());
+        let replaced = registry.register(FnGenerator::new("line", "Cycles", generate_cycle));
+        assert_eq!(replaced.unwrap().description(), "Paths");
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("line").unwrap().description(), "Cycles");
+    }
+
+    #[test]
+    fn test_check_types_and_bounds() {
+        let sizes = ParamSpec::new("sizes", ParamType::IntegerList, "Sizes").with_min(1.0);
+        let sizes_of = |values: Vec<MetadataValue>| sizes.check(&MetadataValue::List(values));
+        assert!(sizes_of(vec![MetadataValue::Integer(1), MetadataValue::Integer(4)]).is_ok());
+        assert!(sizes_of(vec![MetadataValue::Integer(0)]).is_err());
+        assert!(sizes_of(vec![MetadataValue::Float(1.0)]).is_err());
+
+        let p = ParamSpec::new("p", ParamType::Float, "Probability").with_max(1.0);
+        assert!(p.check(&MetadataValue::Integer(1)).is_ok());
+        assert!(p.check(&MetadataValue::Boolean(true)).is_err());
+
+        let matrix = ParamSpec::new("m", ParamType::Matrix, "Matrix").with_max(1.0);
+        let row = |values: &[f64]| {
+            MetadataValue::List(values.iter().map(|&x| MetadataValue::Float(x)).collect())
+        };
+        assert!(matrix
+            .check(&MetadataValue::List(vec![
+                row(&[0.5, 0.1]),
+                row(&[0.1, 0.5])
+            ]))
+            .is_ok());
+        assert!(matrix
+            .check(&MetadataValue::List(vec![row(&[0.5, 1.1])]))
+            .unwrap_err()
+            .contains("at most 1, got 1.1"));
+        assert!(matrix.check(&row(&[0.5])).is_err());
+    }
+
+    #[test]
+    fn test_suggest_closest_name() {
+        assert_eq!(edit_distance("node", "nodes"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        let names = ["nodes", "prefix", "directed"];
+        assert_eq!(suggest("node", names), Some("nodes"));
+        assert_eq!(suggest("prefx", names), Some("prefix"));
+        assert_eq!(suggest("directd", names), Some("directed"));
+        assert_eq!(suggest("colour", names), None);
+    }
+
+    #[test]
+    fn test_builtins_match_get_generator() {
+        let registry = GeneratorRegistry::with_builtins();
+        for generator in super::super::builtins::builtin_generators() {
+            let function = get_generator(generator.name()).unwrap();
+            assert!(
+                function as usize == generator.function() as usize,
+                "{}",
+                generator.name()
+            );
+            let mut names: Vec<String> = generator.params().into_iter().map(|p| p.name).collect();
+            names.sort();
+            names.dedup();
+            assert_eq!(
+                names.len(),
+                generator.params().len(),
+                "{}",
+                generator.name()
+            );
+        }
+        assert_eq!(
+            registry.len(),
+            super::super::builtins::builtin_generators().len()
+        );
+    }
+}
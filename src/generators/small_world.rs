@@ -0,0 +1,440 @@
+//! # Small-World Generators
+//!
+//! Generators for small-world networks built on top of a lattice: the
+//! Watts-Strogatz model, which rewires the edges of a ring lattice, the
+//! Newman-Watts variant, which keeps the ring intact and adds random shortcuts,
+//! and Kleinberg's navigable model, which adds distance-dependent long-range
+//! links to a grid.
+
+use super::{
+    get_param_float, get_param_int, get_param_int_or, get_param_probability, get_param_string,
+    get_rng,
+};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::Rng;
+use std::collections::{BTreeSet, HashMap};
+
+/// Builds the adjacency sets of a ring lattice where each node is joined to
+/// its `k / 2` nearest neighbours on either side.
+fn ring_lattice(n: usize, k: usize) -> Vec<BTreeSet<usize>> {
+    let mut adjacency = vec![BTreeSet::new(); n];
+    for offset in 1..=k / 2 {
+        for u in 0..n {
+            let v = (u + offset) % n;
+            adjacency[u].insert(v);
+            adjacency[v].insert(u);
+        }
+    }
+    adjacency
+}
+
+/// Validates the shared `nodes`/`k` parameters of the ring-lattice based generators.
+fn lattice_params(params: &HashMap<String, MetadataValue>) -> Result<(usize, usize), String> {
+    let n = get_param_int(params, "nodes")?;
+    let k = get_param_int(params, "k")?;
+
+    if k % 2 != 0 {
+        return Err(format!("k must be even, got {}", k));
+    }
+    if k >= n {
+        return Err(format!(
+            "k must be less than nodes, got k={} nodes={}",
+            k, n
+        ));
+    }
+
+    Ok((n, k))
+}
+
+/// Converts adjacency sets into a graph, emitting each undirected edge once.
+fn adjacency_to_graph(adjacency: &[BTreeSet<usize>], prefix: &str) -> Graph {
+    let mut graph = Graph::new();
+
+    for i in 0..adjacency.len() {
+        graph.add_node(Node::new(format!("{}{}", prefix, i)));
+    }
+
+    for (u, neighbours) in adjacency.iter().enumerate() {
+        for &v in neighbours.range(u + 1..) {
+            let source = format!("{}{}", prefix, u);
+            let target = format!("{}{}", prefix, v);
+            let edge_id = format!("e{}_{}", u, v);
+            graph.add_edge(Edge::new(edge_id, source, target));
+        }
+    }
+
+    graph
+}
+
+/// Generates a Watts-Strogatz small-world graph.
+///
+/// The generator starts from a ring lattice in which every node is connected to
+/// its `k` nearest neighbours (`k / 2` on each side). Each lattice edge is then
+/// rewired with probability `p` to a uniformly chosen node, avoiding self-loops
+/// and duplicate edges. Small values of `p` keep the high clustering of the
+/// lattice while drastically shortening path lengths.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `k` (required) - Number of nearest neighbours in the ring lattice (must be even)
+/// * `p` (optional) - Rewiring probability between 0 and 1 (default: 0.1)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: n·k/2
+/// * **Connectivity**: Ring lattice for p = 0, random graph for p = 1
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_watts_strogatz;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(20));
+/// params.insert("k".to_string(), MetadataValue::Integer(4));
+/// params.insert("p".to_string(), MetadataValue::Float(0.2));
+/// params.insert("seed".to_string(), MetadataValue::Integer(42));
+///
+/// let graph = generate_watts_strogatz(&params).unwrap();
+/// assert_eq!(graph.node_count(), 20);
+/// assert_eq!(graph.edge_count(), 40); // n*k/2 edges
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate watts_strogatz {
+///     nodes: 100;
+///     k: 6;
+///     p: 0.05;
+///     seed: 7;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Social networks with high clustering and short paths
+/// * Neural and epidemic spreading simulations
+/// * Baselines for navigability experiments
+pub fn generate_watts_strogatz(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let (n, k) = lattice_params(params)?;
+    let p = get_param_probability(params, "p", 0.1)?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    let mut adjacency = ring_lattice(n, k);
+
+    for offset in 1..=k / 2 {
+        for u in 0..n {
+            let v = (u + offset) % n;
+            if rng.gen::<f64>() >= p {
+                continue;
+            }
+            // A node already connected to everything else cannot be rewired
+            if adjacency[u].len() >= n - 1 {
+                continue;
+            }
+
+            let mut w = rng.gen_range(0..n);
+            while w == u || adjacency[u].contains(&w) {
+                w = rng.gen_range(0..n);
+            }
+
+            adjacency[u].remove(&v);
+            adjacency[v].remove(&u);
+            adjacency[u].insert(w);
+            adjacency[w].insert(u);
+        }
+    }
+
+    Ok(adjacency_to_graph(&adjacency, &prefix))
+}
+
+/// Generates a Newman-Watts small-world graph.
+///
+/// Like [`generate_watts_strogatz`], the generator starts from a ring lattice
+/// with `k` nearest neighbours. Instead of rewiring, every lattice edge keeps
+/// its place and, with probability `p`, a shortcut is added from its first
+/// endpoint to a uniformly chosen node. Because the lattice is never broken
+/// the result is always connected.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes to generate
+/// * `k` (required) - Number of nearest neighbours in the ring lattice (must be even)
+/// * `p` (optional) - Shortcut probability per lattice edge between 0 and 1 (default: 0.1)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: at least n·k/2, expected n·k/2·(1 + p)
+/// * **Connectivity**: Always connected
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate newman_watts {
+///     nodes: 100;
+///     k: 4;
+///     p: 0.1;
+///     seed: 7;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Small-world models that must stay connected
+/// * Analytically tractable small-world baselines
+pub fn generate_newman_watts(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let (n, k) = lattice_params(params)?;
+    let p = get_param_probability(params, "p", 0.1)?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    let mut adjacency = ring_lattice(n, k);
+
+    for _ in 0..k / 2 {
+        for u in 0..n {
+            if rng.gen::<f64>() >= p {
+                continue;
+            }
+            if adjacency[u].len() >= n - 1 {
+                continue;
+            }
+
+            let mut w = rng.gen_range(0..n);
+            while w == u || adjacency[u].contains(&w) {
+                w = rng.gen_range(0..n);
+            }
+
+            adjacency[u].insert(w);
+            adjacency[w].insert(u);
+        }
+    }
+
+    Ok(adjacency_to_graph(&adjacency, &prefix))
+}
+
+/// Generates a Kleinberg navigable small-world graph.
+///
+/// Nodes sit on a `rows` × `cols` grid and are joined to every node within
+/// lattice (Manhattan) distance `p`. Each node also gets `q` directed
+/// long-range links whose targets are drawn with probability proportional to
+/// `d^-r`, where `d` is the lattice distance. Kleinberg showed that greedy
+/// routing with only local knowledge finds short paths exactly when `r` equals
+/// the lattice dimension 2; smaller values give links that are too random and
+/// larger values links that are too short. Targets are drawn independently,
+/// so a node may receive parallel long-range links.
+///
+/// # Parameters
+///
+/// * `rows` (required) - Number of rows in the grid
+/// * `cols` (required) - Number of columns in the grid
+/// * `p` (optional) - Lattice distance of local links (default: 1)
+/// * `q` (optional) - Long-range links per node (default: 1)
+/// * `r` (optional) - Distance exponent of long-range links (default: 2)
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: rows · cols, named `{prefix}{row}_{col}` with `row`/`col`
+///   attributes and `x`/`y` set to the column and row
+/// * **Edges**: undirected `local` edges `e{row}_{col}_{row}_{col}` and directed
+///   `long_range` edges `l{row}_{col}_{k}`, distinguished by their type
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_kleinberg;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("rows".to_string(), MetadataValue::Integer(10));
+/// params.insert("cols".to_string(), MetadataValue::Integer(10));
+/// params.insert("seed".to_string(), MetadataValue::Integer(2));
+///
+/// let graph = generate_kleinberg(&params).unwrap();
+/// assert_eq!(graph.node_count(), 100);
+/// assert_eq!(graph.edge_count(), 180 + 100); // grid edges plus one long-range link per node
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate kleinberg {
+///     rows: 50;
+///     cols: 50;
+///     q: 1;
+///     r: 2.0;
+///     seed: 9;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Greedy routing and decentralised search experiments
+/// * Peer-to-peer overlay design
+/// * Social search models ("six degrees" experiments)
+pub fn generate_kleinberg(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let rows = get_param_int(params, "rows")?;
+    let cols = get_param_int(params, "cols")?;
+    let p = get_param_int_or(params, "p", 1)?;
+    let q = get_param_int_or(params, "q", 1)?;
+    let r = get_param_float(params, "r", 2.0);
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    if r < 0.0 {
+        return Err(format!("r must be non-negative, got {}", r));
+    }
+    if q > 0 && rows * cols < 2 {
+        return Err("long-range links need a grid of at least two nodes".to_string());
+    }
+
+    let id = |row: usize, col: usize| format!("{}{}_{}", prefix, row, col);
+    let mut graph = Graph::new();
+    for row in 0..rows {
+        for col in 0..cols {
+            graph.add_node(
+                Node::new(id(row, col))
+                    .with_position(col as f64, row as f64)
+                    .with_metadata("row".to_string(), MetadataValue::Integer(row as i64))
+                    .with_metadata("col".to_string(), MetadataValue::Integer(col as i64)),
+            );
+        }
+    }
+
+    // Local links to every later node within lattice distance p
+    let reach = p as i64;
+    for row in 0..rows {
+        for col in 0..cols {
+            for dr in 0..=reach {
+                for dc in -reach..=reach {
+                    if dr + dc.abs() > reach || (dr == 0 && dc <= 0) {
+                        continue;
+                    }
+                    let (row2, col2) = (row as i64 + dr, col as i64 + dc);
+                    if row2 >= rows as i64 || !(0..cols as i64).contains(&col2) {
+                        continue;
+                    }
+                    let (row2, col2) = (row2 as usize, col2 as usize);
+                    graph.add_edge(
+                        Edge::new(
+                            format!("e{}_{}_{}_{}", row, col, row2, col2),
+                            id(row, col),
+                            id(row2, col2),
+                        )
+                        .with_type("local".to_string()),
+                    );
+                }
+            }
+        }
+    }
+
+    // Long-range targets are proposed from the unbounded lattice, where 4d nodes
+    // lie at distance d, and proposals outside the grid are rejected
+    let max_distance = (rows + cols).saturating_sub(2).max(1);
+    let mut cumulative = Vec::with_capacity(max_distance);
+    let mut total = 0.0;
+    for d in 1..=max_distance {
+        total += 4.0 * d as f64 * (d as f64).powf(-r);
+        cumulative.push(total);
+    }
+
+    for row in 0..rows {
+        for col in 0..cols {
+            for k in 0..q {
+                let (row2, col2) = loop {
+                    let x = rng.gen::<f64>() * total;
+                    let d = cumulative
+                        .partition_point(|&c| c <= x)
+                        .min(max_distance - 1)
+                        + 1;
+                    let t = rng.gen_range(0..4 * d);
+                    let (i, side) = ((t % d) as i64, t / d);
+                    let d = d as i64;
+                    let (dr, dc) = match side {
+                        0 => (d - i, i),
+                        1 => (-i, d - i),
+                        2 => (i - d, -i),
+                        _ => (i, i - d),
+                    };
+                    let (row2, col2) = (row as i64 + dr, col as i64 + dc);
+                    if (0..rows as i64).contains(&row2) && (0..cols as i64).contains(&col2) {
+                        break (row2 as usize, col2 as usize);
+                    }
+                };
+                graph.add_edge(
+                    Edge::new(
+                        format!("l{}_{}_{}", row, col, k),
+                        id(row, col),
+                        id(row2, col2),
+                    )
+                    .with_type("long_range".to_string()),
+                );
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(n: i64, k: i64, p: f64) -> HashMap<String, MetadataValue> {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(n));
+        params.insert("k".to_string(), MetadataValue::Integer(k));
+        params.insert("p".to_string(), MetadataValue::Float(p));
+        params.insert("seed".to_string(), MetadataValue::Integer(1));
+        params
+    }
+
+    #[test]
+    fn test_ring_lattice_without_rewiring() {
+        let graph = generate_watts_strogatz(&params(10, 4, 0.0)).unwrap();
+        assert_eq!(graph.edge_count(), 20);
+        assert!(graph.get_edge("e0_1").is_some());
+        assert!(graph.get_edge("e0_2").is_some());
+        assert!(graph.get_edge("e0_8").is_some());
+    }
+
+    #[test]
+    fn test_newman_watts_keeps_lattice() {
+        let graph = generate_newman_watts(&params(30, 4, 0.5)).unwrap();
+        assert!(graph.edge_count() >= 60);
+        for i in 0..30 {
+            let j = (i + 1) % 30;
+            let (a, b) = if i < j { (i, j) } else { (j, i) };
+            assert!(graph.get_edge(&format!("e{}_{}", a, b)).is_some());
+        }
+    }
+
+    #[test]
+    fn test_kleinberg_local_range() {
+        let mut params = HashMap::new();
+        params.insert("rows".to_string(), MetadataValue::Integer(4));
+        params.insert("cols".to_string(), MetadataValue::Integer(4));
+        params.insert("p".to_string(), MetadataValue::Integer(2));
+        params.insert("q".to_string(), MetadataValue::Integer(0));
+        let graph = generate_kleinberg(&params).unwrap();
+        // 24 grid edges, 16 straight and 18 diagonal pairs at distance 2
+        assert_eq!(graph.edge_count(), 24 + 16 + 18);
+        assert!(graph.get_edge("e0_0_0_2").is_some());
+        assert!(graph.get_edge("e0_1_1_0").is_some());
+        assert!(graph.get_edge("e0_0_1_2").is_none());
+        assert!(graph.edges.values().all(|edge| edge.r#type == "local"));
+    }
+}
@@ -0,0 +1,389 @@
+//! # Random Tree Generators
+//!
+//! Seedable generators for irregular trees and forests. Every generator roots
+//! its trees, directs edges from parent to child, and tags nodes with the same
+//! `depth` and `parent` attributes as the balanced
+//! [`generate_tree`](super::generate_tree).
+
+use super::{get_param_int, get_param_string, get_rng};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, VecDeque};
+
+/// Builds a rooted forest from each node's parent.
+///
+/// Roots have no parent and no `parent` attribute; depths are computed by
+/// walking down from the roots, so parents may have higher indices than their
+/// children.
+fn forest_graph(prefix: &str, parents: &[Option<usize>]) -> Graph {
+    let n = parents.len();
+    let mut children = vec![Vec::new(); n];
+    for (child, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            children[*parent].push(child);
+        }
+    }
+
+    let mut depth = vec![0usize; n];
+    let mut queue: VecDeque<usize> = (0..n).filter(|&i| parents[i].is_none()).collect();
+    while let Some(u) = queue.pop_front() {
+        for &v in &children[u] {
+            depth[v] = depth[u] + 1;
+            queue.push_back(v);
+        }
+    }
+
+    let mut graph = Graph::new();
+    for (i, parent) in parents.iter().enumerate() {
+        let mut node = Node::new(format!("{}{}", prefix, i))
+            .with_metadata("depth".to_string(), MetadataValue::Integer(depth[i] as i64));
+        if let Some(parent) = parent {
+            node = node.with_metadata(
+                "parent".to_string(),
+                MetadataValue::String(format!("{}{}", prefix, parent)),
+            );
+        }
+        graph.add_node(node);
+    }
+    for (child, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            graph.add_edge(Edge::new(
+                format!("e{}_{}", parent, child),
+                format!("{}{}", prefix, parent),
+                format!("{}{}", prefix, child),
+            ));
+        }
+    }
+    graph
+}
+
+/// Orients an undirected forest away from the given roots.
+///
+/// Returns each node's parent and the index of the tree it belongs to; trees
+/// are numbered in the order their roots are first reached.
+fn orient(
+    n: usize,
+    edges: &[(usize, usize)],
+    roots: impl IntoIterator<Item = usize>,
+) -> (Vec<Option<usize>>, Vec<usize>) {
+    let mut adjacency = vec![Vec::new(); n];
+    for &(u, v) in edges {
+        adjacency[u].push(v);
+        adjacency[v].push(u);
+    }
+
+    let mut parents = vec![None; n];
+    let mut tree_of = vec![usize::MAX; n];
+    let mut trees = 0;
+    for root in roots {
+        if tree_of[root] != usize::MAX {
+            continue;
+        }
+        tree_of[root] = trees;
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            for &v in &adjacency[u] {
+                if tree_of[v] == usize::MAX {
+                    tree_of[v] = trees;
+                    parents[v] = Some(u);
+                    queue.push_back(v);
+                }
+            }
+        }
+        trees += 1;
+    }
+    (parents, tree_of)
+}
+
+/// Edges of a uniformly random labelled tree on `n` nodes, decoded from a
+/// random Prüfer sequence in linear time.
+fn prufer_tree(rng: &mut SmallRng, n: usize) -> Vec<(usize, usize)> {
+    if n < 2 {
+        return Vec::new();
+    }
+    let sequence: Vec<usize> = (0..n - 2).map(|_| rng.gen_range(0..n)).collect();
+
+    let mut degree = vec![1usize; n];
+    for &s in &sequence {
+        degree[s] += 1;
+    }
+
+    let mut edges = Vec::with_capacity(n - 1);
+    let mut ptr = (0..n).find(|&i| degree[i] == 1).unwrap();
+    let mut leaf = ptr;
+    for &s in &sequence {
+        edges.push((leaf, s));
+        degree[s] -= 1;
+        if degree[s] == 1 && s < ptr {
+            leaf = s;
+        } else {
+            ptr += 1;
+            while degree[ptr] != 1 {
+                ptr += 1;
+            }
+            leaf = ptr;
+        }
+    }
+    // The last remaining leaf joins node n - 1, which is never removed
+    edges.push((leaf, n - 1));
+    edges
+}
+
+/// Generates a uniformly random labelled tree.
+///
+/// Every one of the n^(n-2) labelled trees on `nodes` nodes is equally likely.
+/// The tree is decoded from a random Prüfer sequence and rooted at `{prefix}0`.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n, with `depth` and `parent` attributes
+/// * **Edges**: n - 1, directed from parent to child
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_random_tree;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(20));
+/// params.insert("seed".to_string(), MetadataValue::Integer(5));
+///
+/// let graph = generate_random_tree(&params).unwrap();
+/// assert_eq!(graph.edge_count(), 19);
+/// assert_eq!(
+///     graph.get_node("n0").unwrap().metadata.get("depth"),
+///     Some(&MetadataValue::Integer(0))
+/// );
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate random_tree {
+///     nodes: 50;
+///     seed: 7;
+/// }
+/// ```
+pub fn generate_random_tree(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    let edges = prufer_tree(&mut rng, n);
+    let (parents, _) = orient(n, &edges, (n > 0).then_some(0));
+    Ok(forest_graph(&prefix, &parents))
+}
+
+/// Generates a random recursive tree.
+///
+/// Nodes are added one at a time and each new node attaches to a uniformly
+/// chosen earlier node. The root `{prefix}0` tends to gain many children and
+/// depth grows logarithmically, like a filesystem that grows by random
+/// additions.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate random_recursive_tree {
+///     nodes: 100;
+/// }
+/// ```
+pub fn generate_random_recursive_tree(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    let parents: Vec<Option<usize>> = (0..n)
+        .map(|i| (i > 0).then(|| rng.gen_range(0..i)))
+        .collect();
+    Ok(forest_graph(&prefix, &parents))
+}
+
+/// Generates a preferential-attachment tree.
+///
+/// Nodes are added one at a time and each new node attaches to an earlier
+/// node chosen with probability proportional to its degree, which gives a
+/// heavy-tailed degree distribution. This is the Barabási-Albert process with
+/// one edge per node.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate preferential_attachment_tree {
+///     nodes: 100;
+///     seed: 2;
+/// }
+/// ```
+pub fn generate_preferential_attachment_tree(
+    params: &HashMap<String, MetadataValue>,
+) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    // Each node appears once per incident edge, so a uniform pick is degree-proportional
+    let mut endpoints: Vec<usize> = Vec::with_capacity(2 * n);
+    let mut parents = Vec::with_capacity(n);
+    for i in 0..n {
+        let parent = match i {
+            0 => None,
+            1 => Some(0),
+            _ => endpoints.choose(&mut rng).copied(),
+        };
+        if let Some(parent) = parent {
+            endpoints.extend([parent, i]);
+        }
+        parents.push(parent);
+    }
+    Ok(forest_graph(&prefix, &parents))
+}
+
+/// Generates a random caterpillar tree.
+///
+/// A path of `spine` nodes, rooted at its first node, with `leaves` further
+/// nodes each attached to a uniformly chosen spine node. Removing the leaves
+/// leaves a path, which is what makes it a caterpillar.
+///
+/// # Parameters
+///
+/// * `spine` (required) - Number of nodes on the central path (at least 1)
+/// * `leaves` (required) - Number of leaves to attach
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: spine + leaves, spine nodes first
+/// * **Edges**: spine + leaves - 1
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate caterpillar {
+///     spine: 10;
+///     leaves: 25;
+/// }
+/// ```
+pub fn generate_caterpillar(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let spine = get_param_int(params, "spine")?;
+    let leaves = get_param_int(params, "leaves")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    if spine == 0 {
+        return Err("spine must have at least 1 node".to_string());
+    }
+
+    let parents: Vec<Option<usize>> = (0..spine)
+        .map(|i| i.checked_sub(1))
+        .chain((0..leaves).map(|_| Some(rng.gen_range(0..spine))))
+        .collect();
+    Ok(forest_graph(&prefix, &parents))
+}
+
+/// Generates a random forest with a given number of trees.
+///
+/// A uniformly random labelled tree on `nodes` nodes is cut into `trees`
+/// components by removing `trees - 1` of its edges at random. Each tree is
+/// rooted at its lowest-numbered node, and every node stores the index of its
+/// tree in a `tree` attribute alongside `depth` and `parent`.
+///
+/// # Parameters
+///
+/// * `nodes` (required) - Number of nodes
+/// * `trees` (required) - Number of connected components, between 1 and `nodes`
+/// * `seed` (optional) - Seed for reproducible output
+/// * `prefix` (optional) - Node name prefix (default: "n")
+///
+/// # Properties
+///
+/// * **Nodes**: n
+/// * **Edges**: n - trees
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate random_forest {
+///     nodes: 60;
+///     trees: 4;
+/// }
+/// ```
+pub fn generate_random_forest(params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+    let n = get_param_int(params, "nodes")?;
+    let trees = get_param_int(params, "trees")?;
+    let prefix = get_param_string(params, "prefix", "n");
+    let mut rng = get_rng(params)?;
+
+    if trees == 0 || trees > n {
+        return Err(format!(
+            "trees must be between 1 and nodes ({}), got {}",
+            n, trees
+        ));
+    }
+
+    let mut edges = prufer_tree(&mut rng, n);
+    edges.shuffle(&mut rng);
+    edges.truncate(n - trees);
+
+    let (parents, tree_of) = orient(n, &edges, 0..n);
+    let mut graph = forest_graph(&prefix, &parents);
+    for (i, tree) in tree_of.iter().enumerate() {
+        if let Some(node) = graph.nodes.get_mut(&format!("{}{}", prefix, i)) {
+            node.metadata
+                .insert("tree".to_string(), MetadataValue::Integer(*tree as i64));
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_prufer_tree_is_spanning_tree() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        for n in [2, 3, 10, 200] {
+            let edges = prufer_tree(&mut rng, n);
+            assert_eq!(edges.len(), n - 1);
+            let (parents, _) = orient(n, &edges, [0]);
+            assert_eq!(parents.iter().filter(|p| p.is_none()).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_forest_graph_depths() {
+        // 2 -> 0 -> 1, parent index larger than child
+        let graph = forest_graph("n", &[Some(2), Some(0), None]);
+        let depth = |id: &str| graph.get_node(id).unwrap().metadata.get("depth").cloned();
+        assert_eq!(depth("n2"), Some(MetadataValue::Integer(0)));
+        assert_eq!(depth("n1"), Some(MetadataValue::Integer(2)));
+        assert!(graph.get_edge("e2_0").is_some());
+    }
+}
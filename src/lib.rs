@@ -9,7 +9,8 @@
 //! * Define graph structures using intuitive node and edge declarations
 //! * Generate common graph topologies with built-in generators
 //! * Apply transformation rules to modify graph structure
-//! * Export graphs in standard JSON format
+//! * Reshape whole graphs with built-in transforms such as spanning trees and line graphs
+//! * Export graphs in standard JSON format, or as Graphviz DOT and GraphML
 //!
 //! ## Quick Example
 //!
@@ -41,8 +42,16 @@
 //! ## Features
 //!
 //! * **Declarative Syntax**: Define graphs using intuitive node and edge declarations
-//! * **Built-in Generators**: Create common graph structures (complete, path, cycle, grid, star, tree, scale-free)
+//! * **Built-in Generators**: Create common graph structures such as complete, grid, tree, barabasi_albert, watts_strogatz and stochastic_block_model (run `ggl generators` for the full list)
+//! * **Custom Generators**: Register your own generators from Rust alongside the built-in ones
+//! * **Attribute Templates**: Label generated nodes and edges with types and sampled attributes
+//! * **Graph Operations**: Combine graphs with Cartesian, tensor, strong and lexicographic products, disjoint union and join
 //! * **Transformation Rules**: Apply pattern-based rules to modify graph structure
+//! * **Graph Transforms**: Reshape whole graphs or a selection, e.g. carve a maze out of a grid with a random spanning tree, or take the complement, line graph or k-th power
+//! * **Subgraphs**: Group declarations in named, nestable `subgraph` blocks and address their nodes as `cluster_a.gw`
+//! * **Bulk Edges**: Connect sets of nodes in one declaration with wildcard and attribute selectors, e.g. `edge: hosts.* -> core;` or `edge: [type=server] -- [type=switch];`
+//! * **Bulk Edits**: Update, delete or retype the nodes and edges matching a predicate, e.g. `set nodes where type == "server" and cpu > 8 [tier="big"];` or `delete edges where weight < 0.1;`
+//! * **Relabeling**: Rename nodes by mapping, format string or attribute, or renumber them densely in id, breadth-first or degree order, e.g. `relabel "v{index}" by bfs;`
 //! * **Rich Attributes**: Support for typed nodes and edges with metadata
 //! * **JSON Output**: Export graphs in standard JSON format
 //!
@@ -77,22 +86,45 @@
 //! * [`types`] - Core data structures for nodes, edges, and graphs
 //! * [`parser`] - GGL language parser and AST definitions
 //! * [`generators`] - Built-in graph generators for common topologies
+//! * [`attributes`] - Types and sampled attributes for generated nodes and edges
+//! * [`operations`] - Graph products, disjoint union and join
+//! * [`export`] - DOT and GraphML output
+//! * [`predicates`] - `where` clauses of the `set`, `delete` and `retype` statements
+//! * [`relabel`] - Renaming and renumbering of node ids
 //! * [`rules`] - Transformation rule engine for graph manipulation
+//! * [`selectors`] - Wildcard and attribute selectors for edge endpoints
+//! * [`transforms`] - Built-in whole-graph transforms
 
-use std::collections::HashMap;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
 
 // Use cfg(target_arch = "wasm32") instead of cfg(feature = "wasm") for wasm-pack compatibility
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+pub mod attributes;
+pub mod export;
 pub mod generators;
+pub mod operations;
 pub mod parser;
+pub mod predicates;
+pub mod relabel;
 pub mod rules;
+pub mod selectors;
+pub mod transforms;
 pub mod types;
 
-use crate::generators::get_generator;
-use crate::parser::{parse_ggl, GGLStatement};
-use crate::types::{Edge, Graph, Node};
+use crate::generators::{check_params, suggest, Generator, GeneratorRegistry};
+use crate::operations::{get_operation, OPERATIONS};
+use crate::parser::{
+    parse_ggl, EditAction, EditStatement, GGLStatement, GenerateStatement, GraphOperand,
+    SelectorEdgeDeclaration, SubgraphBlock,
+};
+use crate::predicates::ElementKind;
+use crate::selectors::resolve;
+use crate::transforms::{get_transform, transform_params};
+use crate::types::{Edge, Graph, MetadataValue, Node};
 
 // ! info: this is how you reference external functions from JS / the browser
 // #[cfg(target_arch = "wasm32")]
@@ -153,10 +185,22 @@ pub fn run() {
 /// let result = engine.generate_from_ggl(ggl_code).unwrap();
 /// println!("Generated graph: {}", result);
 /// ```
+///
+/// # Reproducibility
+///
+/// All randomness in a program is drawn from a single seeded random number
+/// generator owned by the engine. Generators without an explicit `seed`
+/// parameter receive a seed drawn from it, so fixing the engine seed (with
+/// [`GGLEngine::set_seed`] or a `seed 42;` statement) makes the whole program
+/// produce byte-identical output.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub struct GGLEngine {
     graph: Graph,
     rules: HashMap<String, rules::Rule>,
+    seed: Option<u64>,
+    rng: SmallRng,
+    generators: GeneratorRegistry,
+    named_graphs: HashMap<String, Graph>,
 }
 
 impl Default for GGLEngine {
@@ -182,9 +226,44 @@ impl GGLEngine {
         GGLEngine {
             graph: Graph::new(),
             rules: HashMap::new(),
+            seed: None,
+            rng: SmallRng::from_entropy(),
+            generators: GeneratorRegistry::with_builtins(),
+            named_graphs: HashMap::new(),
         }
     }
 
+    /// Fixes the seed used for every random operation in subsequent programs.
+    ///
+    /// A `seed` statement inside a program reseeds the generator from that point on,
+    /// and a `seed` parameter on a `generate` statement takes precedence for that
+    /// generator only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::GGLEngine;
+    ///
+    /// let ggl_code = r#"
+    ///     graph example {
+    ///         generate watts_strogatz { nodes: 20; k: 4; p: 0.3; }
+    ///     }
+    /// "#;
+    ///
+    /// let mut first = GGLEngine::new();
+    /// first.set_seed(42);
+    /// let mut second = GGLEngine::new();
+    /// second.set_seed(42);
+    ///
+    /// assert_eq!(
+    ///     first.generate_from_ggl(ggl_code).unwrap(),
+    ///     second.generate_from_ggl(ggl_code).unwrap()
+    /// );
+    /// ```
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+    }
+
     /// Parses and executes a GGL program, returning the resulting graph as JSON.
     ///
     /// This method works for both WebAssembly and native Rust usage.
@@ -262,20 +341,29 @@ impl GGLEngine {
     /// The method processes GGL programs in the following order:
     ///
     /// 1. **Parse** - Convert GGL source code into an abstract syntax tree
-    /// 2. **Reset State** - Clear any existing graph and rules
+    /// 2. **Reset State** - Clear any existing graph and rules, and reseed the random number generator
     /// 3. **Process Statements** - Execute each statement in order:
     ///    - Node declarations create new nodes with types and attributes
     ///    - Edge declarations create connections between nodes
     ///    - Generate statements invoke built-in graph generators
+    ///    - Let statements name a graph for later operations without adding it
+    ///    - Operation statements add a product, union or join of graphs
+    ///    - Subgraph blocks scope the nodes and edges they declare under their name
+    ///    - Transform statements rewrite the graph built so far
     ///    - Rule definitions register transformation patterns
     ///    - Apply statements execute transformation rules
+    ///    - Seed statements reseed the random number generator
     /// 4. **Serialize** - Convert the final graph to JSON format
     ///
     /// # Error Handling
     ///
     /// This method can return errors for various reasons:
     /// - **Parse errors**: Invalid GGL syntax
-    /// - **Generator errors**: Invalid generator parameters or unknown generators
+    /// - **Generator errors**: Unknown generators, or unknown, missing, mistyped or
+    ///   out-of-range parameters, reported with the line and column of the statement
+    /// - **Operation errors**: Unknown operations or graphs, or colliding node ids in
+    ///   a union or join
+    /// - **Transform errors**: Invalid transform parameters or unknown transforms
     /// - **Rule errors**: Pattern matching failures or transformation errors
     /// - **Serialization errors**: JSON conversion failures
     fn generate_from_ggl_native(&mut self, ggl_code: &str) -> Result<String, String> {
@@ -285,6 +373,11 @@ impl GGLEngine {
         // Reset graph state
         self.graph = Graph::new();
         self.rules.clear();
+        self.named_graphs.clear();
+        self.rng = match self.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        };
 
         // Process statements
         for stmt in statements {
@@ -302,20 +395,43 @@ impl GGLEngine {
                             .with_metadata_map(edge.attributes),
                     );
                 }
+                GGLStatement::SelectorEdgeDecl(edges) => self.add_selector_edges(edges, None)?,
                 GGLStatement::GenerateStmt(gen) => {
-                    if let Some(generator) = get_generator(&gen.name) {
-                        let generated = generator(&gen.params)
-                            .map_err(|e| format!("Generator error: {}", e))?;
+                    let generated = self.run_generator(gen)?;
+                    self.merge(generated);
+                }
+                GGLStatement::SubgraphStmt(block) => self.run_subgraph(block, None)?,
+                GGLStatement::EditStmt(edit) => self.run_edit(edit)?,
+                GGLStatement::RelabelStmt(relabeling) => self
+                    .graph
+                    .relabel(&relabeling)
+                    .map_err(|e| format!("Relabel error: {}", e))?,
+                GGLStatement::LetStmt(binding) => {
+                    let value = self.evaluate(binding.value)?;
+                    self.named_graphs.insert(binding.name, value);
+                }
+                GGLStatement::OperationStmt(call) => {
+                    let result = self.evaluate(GraphOperand::Call(call))?;
+                    self.merge(result);
+                }
+                GGLStatement::TransformStmt(transform) => {
+                    if let Some(apply) = get_transform(&transform.name) {
+                        let specs = transform_params(&transform.name).unwrap_or_default();
+                        let takes_seed = specs.iter().any(|spec| spec.name == "seed");
+                        let mut params = check_params(&transform.name, specs, &transform.params)
+                            .map_err(|e| format!("Transform error: {}", e))?;
 
-                        // Merge generated graph into current graph
-                        for (_, node) in generated.nodes {
-                            self.graph.add_node(node);
-                        }
-                        for (_, edge) in generated.edges {
-                            self.graph.add_edge(edge);
+                        // Random transforms are seeded from the engine RNG like generators,
+                        // and a seed is drawn even when given so later statements are unaffected
+                        let seed = MetadataValue::Integer(self.rng.gen());
+                        if takes_seed {
+                            params.entry("seed".to_string()).or_insert(seed);
                         }
+
+                        apply(&mut self.graph, &params)
+                            .map_err(|e| format!("Transform error: {}", e))?;
                     } else {
-                        return Err(format!("Unknown generator: {}", gen.name));
+                        return Err(format!("Unknown transform: {}", transform.name));
                     }
                 }
                 GGLStatement::RuleDefStmt(rule_def) => {
@@ -334,6 +450,9 @@ impl GGLEngine {
                         return Err(format!("Unknown rule: {}", apply.rule_name));
                     }
                 }
+                GGLStatement::SeedStmt(seed) => {
+                    self.rng = SmallRng::seed_from_u64(seed.seed);
+                }
             }
         }
 
@@ -341,3 +460,298 @@ impl GGLEngine {
         serde_json::to_string(&self.graph).map_err(|e| format!("Serialization error: {}", e))
     }
 }
+
+// Evaluation of the statements that produce graphs
+impl GGLEngine {
+    /// Runs a generator with validated parameters and applies its templates.
+    fn run_generator(&mut self, gen: GenerateStatement) -> Result<Graph, String> {
+        let location = format!("line {}, column {}", gen.line, gen.column);
+        let Some(generator) = self.generators.get(&gen.name) else {
+            return Err(match suggest(&gen.name, self.generators.names()) {
+                Some(name) => format!(
+                    "Unknown generator: {} at {}, did you mean {}?",
+                    gen.name, location, name
+                ),
+                None => format!("Unknown generator: {} at {}", gen.name, location),
+            });
+        };
+
+        let mut params = generator
+            .validate_params(&gen.params)
+            .map_err(|e| format!("Generator error at {}: {}", location, e))?;
+
+        // Every statement draws a seed, so adding a deterministic generator
+        // does not change the output of the random ones after it
+        let seed = MetadataValue::Integer(self.rng.gen());
+        if generator.params().iter().any(|param| param.name == "seed") {
+            params.entry("seed".to_string()).or_insert(seed);
+        }
+
+        let mut generated = generator
+            .generate(&params)
+            .map_err(|e| format!("Generator error at {}: {}", location, e))?;
+        gen.templates.apply(&mut generated, &mut self.rng);
+        Ok(generated)
+    }
+
+    /// Computes the graph an operand stands for, evaluating operands left to right.
+    fn evaluate(&mut self, operand: GraphOperand) -> Result<Graph, String> {
+        match operand {
+            GraphOperand::Generate(gen) => self.run_generator(gen),
+            GraphOperand::Named(name) => self
+                .named_graphs
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| format!("Unknown graph: {}", name)),
+            GraphOperand::Call(call) => {
+                let location = format!("line {}, column {}", call.line, call.column);
+                let Some(operation) = get_operation(&call.operation) else {
+                    return Err(match suggest(&call.operation, OPERATIONS) {
+                        Some(name) => format!(
+                            "Unknown operation: {} at {}, did you mean {}?",
+                            call.operation, location, name
+                        ),
+                        None => format!("Unknown operation: {} at {}", call.operation, location),
+                    });
+                };
+
+                let mut operands = call.operands.into_iter();
+                let mut result = self.evaluate(operands.next().unwrap())?;
+                for operand in operands {
+                    let next = self.evaluate(operand)?;
+                    result = operation(&result, &next)
+                        .map_err(|e| format!("Operation error at {}: {}", location, e))?;
+                }
+                Ok(result)
+            }
+        }
+    }
+
+    /// Runs the statements of a subgraph block, scoping their ids under its path.
+    fn run_subgraph(&mut self, block: SubgraphBlock, parent: Option<&str>) -> Result<(), String> {
+        let scope = match parent {
+            Some(parent) => format!("{}.{}", parent, block.name),
+            None => block.name,
+        };
+
+        for stmt in block.statements {
+            match stmt {
+                GGLStatement::NodeDecl(node) => {
+                    self.graph.add_node(
+                        Node::new(format!("{}.{}", scope, node.id))
+                            .with_type(node.node_type.unwrap_or_default())
+                            .with_metadata_map(node.attributes)
+                            .with_subgraph(scope.clone()),
+                    );
+                }
+                GGLStatement::EdgeDecl(edge) => {
+                    let source = resolve(&self.graph, &scope, &edge.source);
+                    let target = resolve(&self.graph, &scope, &edge.target);
+                    self.graph.add_edge(
+                        Edge::new(format!("{}.{}", scope, edge.id), source, target)
+                            .with_metadata_map(edge.attributes),
+                    );
+                }
+                GGLStatement::SelectorEdgeDecl(edges) => {
+                    self.add_selector_edges(edges, Some(&scope))?
+                }
+                GGLStatement::GenerateStmt(gen) => {
+                    let generated = self.run_generator(gen)?;
+                    for (id, node) in generated.nodes {
+                        self.graph.add_node(Node {
+                            id: format!("{}.{}", scope, id),
+                            subgraph: Some(scope.clone()),
+                            ..node
+                        });
+                    }
+                    for (id, edge) in generated.edges {
+                        self.graph.add_edge(Edge {
+                            id: format!("{}.{}", scope, id),
+                            source: format!("{}.{}", scope, edge.source),
+                            target: format!("{}.{}", scope, edge.target),
+                            ..edge
+                        });
+                    }
+                }
+                GGLStatement::SubgraphStmt(inner) => self.run_subgraph(inner, Some(&scope))?,
+                _ => unreachable!("the grammar only allows declarations in subgraphs"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a `set`, `delete` or `retype` statement to the matching nodes or edges.
+    fn run_edit(&mut self, edit: EditStatement) -> Result<(), String> {
+        let ids = edit
+            .predicate
+            .select(&self.graph, edit.kind)
+            .map_err(|e| format!("Edit error: {}", e))?;
+
+        match edit.action {
+            EditAction::Delete => {
+                let deleted: HashSet<String> = ids.into_iter().collect();
+                match edit.kind {
+                    ElementKind::Nodes => {
+                        // One pass over the edges, rather than one per deleted node
+                        self.graph.nodes.retain(|id, _| !deleted.contains(id));
+                        self.graph.edges.retain(|_, e| {
+                            !deleted.contains(&e.source) && !deleted.contains(&e.target)
+                        });
+                    }
+                    ElementKind::Edges => self.graph.edges.retain(|id, _| !deleted.contains(id)),
+                }
+            }
+            EditAction::Set(attributes) => {
+                for id in ids {
+                    let metadata = match edit.kind {
+                        ElementKind::Nodes => &mut self.graph.nodes.get_mut(&id).unwrap().metadata,
+                        ElementKind::Edges => &mut self.graph.edges.get_mut(&id).unwrap().metadata,
+                    };
+                    metadata.extend(attributes.clone());
+                }
+            }
+            EditAction::Retype(new_type) => {
+                for id in ids {
+                    let r#type = match edit.kind {
+                        ElementKind::Nodes => &mut self.graph.nodes.get_mut(&id).unwrap().r#type,
+                        ElementKind::Edges => &mut self.graph.edges.get_mut(&id).unwrap().r#type,
+                    };
+                    *r#type = new_type.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Connects every node matched by the source selector to every node matched by the target.
+    ///
+    /// Edges are named `e{source}_{target}`, or `{id}_{source}_{target}` when
+    /// the declaration has an id, under the subgraph path inside a subgraph.
+    /// Self-loops between a node and itself are skipped, and an undirected
+    /// declaration connects each pair of nodes once.
+    ///
+    /// Fails instead of replacing an edge that already has one of these ids,
+    /// as when `a` to `b_c` and `a_b` to `c` would both be named `ea_b_c`.
+    fn add_selector_edges(
+        &mut self,
+        decl: SelectorEdgeDeclaration,
+        scope: Option<&str>,
+    ) -> Result<(), String> {
+        let mut matches = Vec::new();
+        for selector in [&decl.source, &decl.target] {
+            let ids = selector.select(&self.graph, scope);
+            if ids.is_empty() {
+                return Err(format!("Selector {} matches no nodes", selector));
+            }
+            matches.push(ids);
+        }
+
+        let mut connected = HashSet::new();
+        for source in &matches[0] {
+            for target in &matches[1] {
+                if source == target {
+                    continue;
+                }
+                let pair = if decl.directed || source < target {
+                    (source, target)
+                } else {
+                    (target, source)
+                };
+                if !connected.insert(pair) {
+                    continue;
+                }
+                let id = match &decl.id {
+                    Some(id) => format!("{}_{}_{}", id, source, target),
+                    None => format!("e{}_{}", source, target),
+                };
+                let id = match scope {
+                    Some(scope) => format!("{}.{}", scope, id),
+                    None => id,
+                };
+                if self.graph.edges.contains_key(&id) {
+                    return Err(format!(
+                        "Edge {} from {} to {} already exists",
+                        id, source, target
+                    ));
+                }
+                self.graph.add_edge(
+                    Edge::new(id, source.clone(), target.clone())
+                        .with_metadata_map(decl.attributes.clone()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the nodes and edges of a graph to the current graph.
+    fn merge(&mut self, graph: Graph) {
+        for (_, node) in graph.nodes {
+            self.graph.add_node(node);
+        }
+        for (_, edge) in graph.edges {
+            self.graph.add_edge(edge);
+        }
+    }
+}
+
+// Generators are Rust trait objects and graphs are exported as JSON, so neither
+// the registry nor the graph is exposed to WASM directly
+impl GGLEngine {
+    /// Registers a generator for `generate` statements in subsequent programs.
+    ///
+    /// A generator with the same name as an existing one, including a built-in
+    /// generator, replaces it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::{generate_path, FnGenerator, ParamSpec, ParamType};
+    /// use graph_generation_language::GGLEngine;
+    ///
+    /// let mut engine = GGLEngine::new();
+    /// engine.register_generator(
+    ///     FnGenerator::new("chain", "Paths under another name", generate_path)
+    ///         .with_param(ParamSpec::new("nodes", ParamType::Integer, "Number of nodes").required()),
+    /// );
+    ///
+    /// let json = engine
+    ///     .generate_from_ggl("graph { generate chain { nodes: 3; } }")
+    ///     .unwrap();
+    /// assert!(json.contains("\"n2\""));
+    /// ```
+    pub fn register_generator<G: Generator + 'static>(&mut self, generator: G) {
+        self.generators.register(generator);
+    }
+
+    /// Returns the generators available to `generate` statements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::GGLEngine;
+    ///
+    /// let engine = GGLEngine::new();
+    /// assert!(engine.generators().get("barabasi_albert").is_some());
+    /// ```
+    pub fn generators(&self) -> &GeneratorRegistry {
+        &self.generators
+    }
+
+    /// Returns the graph built by the last program, e.g. to export it in another format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::export::to_dot;
+    /// use graph_generation_language::GGLEngine;
+    ///
+    /// let mut engine = GGLEngine::new();
+    /// engine
+    ///     .generate_from_ggl("graph { subgraph rack { node gw; } }")
+    ///     .unwrap();
+    /// assert!(to_dot(engine.graph(), false).contains("\"rack.gw\";"));
+    /// ```
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+}
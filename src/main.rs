@@ -1,15 +1,181 @@
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use graph_generation_language::export::{to_dot, to_graphml};
+use graph_generation_language::relabel::{NodeOrder, Relabeling};
+use graph_generation_language::GGLEngine;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
 
 #[derive(Parser, Debug)]
 #[clap(author = "Olive Casazza", version, about)]
+#[command(args_conflicts_with_subcommands = true)]
 /// Application configuration
 struct Args {
+    /// GGL program to run (reads from stdin when omitted)
+    input: Option<PathBuf>,
+
+    /// seed for every random operation, for reproducible output
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// output format
+    #[arg(short, long, value_enum, default_value_t = Format::Json)]
+    format: Format,
+
+    /// write edges as directed in DOT and GraphML output
+    #[arg(long)]
+    directed: bool,
+
+    /// renumber the nodes 0, 1, 2, ... in this order before writing the graph
+    #[arg(long, value_enum)]
+    relabel: Option<Order>,
+
+    /// name the renumbered nodes after a format string instead, e.g. "v{index}"
+    #[arg(long, requires = "relabel")]
+    relabel_format: Option<String>,
+
     /// whether to be verbose
     #[arg(short = 'v')]
     verbose: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    /// JSON with `nodes` and `edges` objects
+    Json,
+    /// Graphviz DOT, with subgraphs as clusters
+    Dot,
+    /// GraphML, with subgraphs as nested graphs
+    Graphml,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Order {
+    /// by id, with runs of digits compared as numbers
+    Id,
+    /// breadth-first, treating edges as undirected
+    Bfs,
+    /// by decreasing degree
+    Degree,
+}
+
+impl From<Order> for NodeOrder {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::Id => NodeOrder::Id,
+            Order::Bfs => NodeOrder::Bfs,
+            Order::Degree => NodeOrder::Degree,
+        }
+    }
 }
 
-fn main() {
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List the available generators and their parameters
+    Generators {
+        /// only describe this generator
+        name: Option<String>,
+
+        /// print a JSON Schema of the parameters instead, for editor tooling
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Prints the generator listing for `ggl generators`.
+fn list_generators(engine: &GGLEngine, name: Option<&str>, json: bool) -> Result<String, String> {
+    let registry = engine.generators();
+    match name {
+        Some(name) => {
+            let generator = registry
+                .get(name)
+                .ok_or_else(|| format!("Unknown generator: {}", name))?;
+            Ok(if json {
+                serde_json::to_string_pretty(&generator.json_schema()).unwrap()
+            } else {
+                generator.usage()
+            })
+        }
+        None if json => Ok(serde_json::to_string_pretty(&registry.json_schema()).unwrap()),
+        None => Ok(registry.usage()),
+    }
+}
+
+/// Runs a program and formats the graph it builds.
+fn run(args: &Args, code: &str) -> Result<String, String> {
+    let mut engine = GGLEngine::new();
+    if let Some(seed) = args.seed {
+        engine.set_seed(seed);
+    }
+    engine.generate_from_ggl(code)?;
+
+    let relabeled;
+    let graph = match args.relabel {
+        Some(order) => {
+            let relabeling = match &args.relabel_format {
+                Some(format) => Relabeling::Format {
+                    format: format.clone(),
+                    order: order.into(),
+                },
+                None => Relabeling::integers(order.into()),
+            };
+            let mut graph = engine.graph().clone();
+            graph.relabel(&relabeling)?;
+            relabeled = graph;
+            &relabeled
+        }
+        None => engine.graph(),
+    };
+
+    Ok(match args.format {
+        Format::Json => format!("{}\n", serde_json::to_string(graph).unwrap()),
+        Format::Dot => to_dot(graph, args.directed),
+        Format::Graphml => to_graphml(graph, args.directed)?,
+    })
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
-    println!("{:?}", args);
+    if args.verbose {
+        eprintln!("{:?}", args);
+    }
+
+    if let Some(Command::Generators { name, json }) = &args.command {
+        return match list_generators(&GGLEngine::new(), name.as_deref(), *json) {
+            Ok(listing) => {
+                print!("{}", listing);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let source = match &args.input {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e)),
+        None => {
+            let mut buffer = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buffer)
+                .map(|_| buffer)
+                .map_err(|e| format!("Failed to read stdin: {}", e))
+        }
+    };
+
+    match source.and_then(|code| run(&args, &code)) {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
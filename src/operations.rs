@@ -0,0 +1,576 @@
+//! # Graph Operations
+//!
+//! This module provides binary operations that build a new graph from two
+//! existing ones: the four standard graph products, disjoint union and join.
+//! Operations are invoked in GGL programs with a call on generator results,
+//! graphs bound with `let`, or other operations:
+//!
+//! ```ggl
+//! let ring = generate cycle { nodes: 8; }
+//!
+//! // A torus is the Cartesian product of two cycles
+//! cartesian(ring, generate cycle { nodes: 6; prefix: "m"; });
+//!
+//! // A 3-dimensional hypercube is a repeated product of single edges
+//! let bit = generate path { nodes: 2; prefix: "b"; }
+//! let cube = cartesian(bit, bit, bit);
+//! ```
+//!
+//! A call as a statement adds its result to the graph, while `let` only names
+//! it for later operations. Calls with more than two operands fold from the
+//! left, so `cartesian(a, b, c)` is `cartesian(cartesian(a, b), c)`.
+//!
+//! ## Available Operations
+//!
+//! * [`cartesian_product`] - `cartesian`, also written G □ H
+//! * [`tensor_product`] - `tensor`, the categorical product G × H
+//! * [`strong_product`] - `strong`, G ⊠ H
+//! * [`lexicographic_product`] - `lexicographic`, G\[H\]
+//! * [`disjoint_union`] - `union`, G + H
+//! * [`join`] - `join`, G ∇ H
+//!
+//! ## Identifiers
+//!
+//! Products pair every node `u` of the first operand with every node `v` of the
+//! second into a node `{u}_{v}`, and name each edge `e{source}_{target}` like an
+//! edge declared without an id. A product fails if two nodes or two edges get
+//! the same id, as the pairs of `a_b` and `c` and of `a` and `b_c` do. Union
+//! and join keep the node ids of their operands, which must therefore be
+//! distinct, and rename clashing edges after their endpoints.
+//!
+//! Products treat their operands as undirected simple graphs: edge direction,
+//! self-loops and repeated edges between the same pair of nodes are ignored.
+
+use crate::types::{Edge, Graph, Node};
+use std::collections::{HashMap, HashSet};
+
+/// Function signature for binary graph operations.
+pub type OperationFn = fn(&Graph, &Graph) -> Result<Graph, String>;
+
+/// Returns the operation function for the given name.
+///
+/// # Available Operations
+///
+/// * `"cartesian"` - Cartesian product
+/// * `"tensor"` - Tensor (categorical) product
+/// * `"strong"` - Strong product
+/// * `"lexicographic"` - Lexicographic product
+/// * `"union"` - Disjoint union
+/// * `"join"` - Join
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::operations::get_operation;
+///
+/// assert!(get_operation("cartesian").is_some());
+/// assert!(get_operation("product").is_none());
+/// ```
+pub fn get_operation(name: &str) -> Option<OperationFn> {
+    match name {
+        "cartesian" => Some(cartesian_product),
+        "tensor" => Some(tensor_product),
+        "strong" => Some(strong_product),
+        "lexicographic" => Some(lexicographic_product),
+        "union" => Some(disjoint_union),
+        "join" => Some(join),
+        _ => None,
+    }
+}
+
+/// Names of the operations accepted by [`get_operation`].
+pub const OPERATIONS: [&str; 6] = [
+    "cartesian",
+    "tensor",
+    "strong",
+    "lexicographic",
+    "union",
+    "join",
+];
+
+/// The nodes of a graph in id order.
+fn sorted_nodes(graph: &Graph) -> Vec<&Node> {
+    let mut nodes: Vec<&Node> = graph.nodes.values().collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    nodes
+}
+
+/// The edges of a graph as an undirected simple graph, in id order.
+fn simple_edges(graph: &Graph) -> Vec<&Edge> {
+    let mut edges: Vec<&Edge> = graph.edges.values().collect();
+    edges.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut seen = HashSet::new();
+    edges
+        .into_iter()
+        .filter(|edge| {
+            edge.source != edge.target
+                && graph.nodes.contains_key(&edge.source)
+                && graph.nodes.contains_key(&edge.target)
+                && seen.insert(if edge.source < edge.target {
+                    (&edge.source, &edge.target)
+                } else {
+                    (&edge.target, &edge.source)
+                })
+        })
+        .collect()
+}
+
+fn pair(u: &str, v: &str) -> String {
+    format!("{}_{}", u, v)
+}
+
+/// Pairs two nodes; the second node's type and attributes win on conflicts.
+fn product_node(u: &Node, v: &Node) -> Node {
+    let node_type = if v.r#type.is_empty() {
+        &u.r#type
+    } else {
+        &v.r#type
+    };
+    Node::new(pair(&u.id, &v.id))
+        .with_type(node_type.clone())
+        .with_metadata_map(u.metadata.clone())
+        .with_metadata_map(v.metadata.clone())
+}
+
+/// Adds an edge carrying the type and attributes of the factor edges it comes from.
+fn add_product_edge(
+    graph: &mut Graph,
+    source: String,
+    target: String,
+    factors: &[&Edge],
+) -> Result<(), String> {
+    let id = format!("e{}_{}", source, target);
+    if let Some(other) = graph.edges.get(&id) {
+        return Err(format!(
+            "edges {} -> {} and {} -> {} would both be named {}, give the operands different prefixes",
+            other.source, other.target, source, target, id
+        ));
+    }
+    let mut edge = Edge::new(id, source, target);
+    for factor in factors {
+        if !factor.r#type.is_empty() {
+            edge.r#type = factor.r#type.clone();
+        }
+        edge.metadata.extend(factor.metadata.clone());
+    }
+    graph.add_edge(edge);
+    Ok(())
+}
+
+/// The edges a product takes from each factor.
+struct ProductEdges {
+    /// `(u, v)` ~ `(u', v)` for every edge `u` ~ `u'` of the first factor
+    first: bool,
+    /// `(u, v)` ~ `(u, v')` for every edge `v` ~ `v'` of the second factor
+    second: bool,
+    /// `(u, v)` ~ `(u', v')` for every pair of edges `u` ~ `u'` and `v` ~ `v'`
+    both: bool,
+    /// `(u, v)` ~ `(u', v')` for every edge `u` ~ `u'` and any `v`, `v'`
+    first_any: bool,
+}
+
+fn product(g: &Graph, h: &Graph, kind: ProductEdges) -> Result<Graph, String> {
+    let (g_nodes, h_nodes) = (sorted_nodes(g), sorted_nodes(h));
+    let (g_edges, h_edges) = (simple_edges(g), simple_edges(h));
+
+    let mut graph = Graph::new();
+    let mut pairs: HashMap<String, (&str, &str)> = HashMap::new();
+    for u in &g_nodes {
+        for v in &h_nodes {
+            let node = product_node(u, v);
+            if let Some((u2, v2)) = pairs.insert(node.id.clone(), (&u.id, &v.id)) {
+                return Err(format!(
+                    "nodes ({}, {}) and ({}, {}) would both be named {}, give the operands different prefixes",
+                    u2, v2, u.id, v.id, node.id
+                ));
+            }
+            graph.add_node(node);
+        }
+    }
+
+    if kind.first {
+        for e in &g_edges {
+            for v in &h_nodes {
+                let (source, target) = (pair(&e.source, &v.id), pair(&e.target, &v.id));
+                add_product_edge(&mut graph, source, target, &[e])?;
+            }
+        }
+    }
+    if kind.second {
+        for u in &g_nodes {
+            for f in &h_edges {
+                let (source, target) = (pair(&u.id, &f.source), pair(&u.id, &f.target));
+                add_product_edge(&mut graph, source, target, &[f])?;
+            }
+        }
+    }
+    if kind.both {
+        for e in &g_edges {
+            for f in &h_edges {
+                let (source, target) = (pair(&e.source, &f.source), pair(&e.target, &f.target));
+                add_product_edge(&mut graph, source, target, &[e, f])?;
+                let (source, target) = (pair(&e.source, &f.target), pair(&e.target, &f.source));
+                add_product_edge(&mut graph, source, target, &[e, f])?;
+            }
+        }
+    }
+    if kind.first_any {
+        for e in &g_edges {
+            for v in &h_nodes {
+                for w in &h_nodes {
+                    let (source, target) = (pair(&e.source, &v.id), pair(&e.target, &w.id));
+                    add_product_edge(&mut graph, source, target, &[e])?;
+                }
+            }
+        }
+    }
+    Ok(graph)
+}
+
+/// Computes the Cartesian product G □ H.
+///
+/// Nodes `(u, v)` and `(u', v')` are adjacent when they agree in one
+/// coordinate and are adjacent in the other. Each edge carries the type and
+/// attributes of the factor edge it comes from.
+///
+/// # Properties
+///
+/// * **Nodes**: |G| · |H|
+/// * **Edges**: |E(G)| · |H| + |G| · |E(H)|
+/// * **Examples**: grids are products of paths, tori of cycles, and hypercubes
+///   repeated products of a single edge
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_cycle;
+/// use graph_generation_language::operations::cartesian_product;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(4));
+/// let ring = generate_cycle(&params).unwrap();
+///
+/// let torus = cartesian_product(&ring, &ring).unwrap();
+/// assert_eq!(torus.node_count(), 16);
+/// assert_eq!(torus.edge_count(), 32);
+/// assert!(torus.get_node("n0_n3").is_some());
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// cartesian(generate cycle { nodes: 10; }, generate cycle { nodes: 10; prefix: "m"; });
+/// ```
+pub fn cartesian_product(g: &Graph, h: &Graph) -> Result<Graph, String> {
+    product(
+        g,
+        h,
+        ProductEdges {
+            first: true,
+            second: true,
+            both: false,
+            first_any: false,
+        },
+    )
+}
+
+/// Computes the tensor (categorical, direct or Kronecker) product G × H.
+///
+/// Nodes `(u, v)` and `(u', v')` are adjacent when `u` ~ `u'` in G and
+/// `v` ~ `v'` in H. Each edge carries the type and attributes of both factor
+/// edges, those of H winning on conflicts.
+///
+/// # Properties
+///
+/// * **Nodes**: |G| · |H|
+/// * **Edges**: 2 · |E(G)| · |E(H)|
+/// * **Connectivity**: disconnected when both factors are bipartite
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_complete;
+/// use graph_generation_language::operations::tensor_product;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+/// let triangle = generate_complete(&params).unwrap();
+///
+/// let product = tensor_product(&triangle, &triangle).unwrap();
+/// assert_eq!(product.node_count(), 9);
+/// assert_eq!(product.edge_count(), 18);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// tensor(generate complete { nodes: 3; }, generate path { nodes: 4; prefix: "p"; });
+/// ```
+pub fn tensor_product(g: &Graph, h: &Graph) -> Result<Graph, String> {
+    product(
+        g,
+        h,
+        ProductEdges {
+            first: false,
+            second: false,
+            both: true,
+            first_any: false,
+        },
+    )
+}
+
+/// Computes the strong product G ⊠ H, the union of the Cartesian and tensor products.
+///
+/// Nodes `(u, v)` and `(u', v')` are adjacent when each coordinate is equal or
+/// adjacent, and they differ in at least one.
+///
+/// # Properties
+///
+/// * **Nodes**: |G| · |H|
+/// * **Edges**: |E(G)| · |H| + |G| · |E(H)| + 2 · |E(G)| · |E(H)|
+/// * **Examples**: the strong product of two paths is a king's graph
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_path;
+/// use graph_generation_language::operations::strong_product;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+/// let path = generate_path(&params).unwrap();
+///
+/// // The moves of a king on a 3x3 board
+/// let kings = strong_product(&path, &path).unwrap();
+/// assert_eq!(kings.edge_count(), 20);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// strong(generate path { nodes: 8; }, generate path { nodes: 8; prefix: "m"; });
+/// ```
+pub fn strong_product(g: &Graph, h: &Graph) -> Result<Graph, String> {
+    product(
+        g,
+        h,
+        ProductEdges {
+            first: true,
+            second: true,
+            both: true,
+            first_any: false,
+        },
+    )
+}
+
+/// Computes the lexicographic product G\[H\], substituting a copy of H for every node of G.
+///
+/// Nodes `(u, v)` and `(u', v')` are adjacent when `u` ~ `u'` in G, or when
+/// `u` = `u'` and `v` ~ `v'` in H. Copies of H at adjacent nodes of G are
+/// completely connected.
+///
+/// # Properties
+///
+/// * **Nodes**: |G| · |H|
+/// * **Edges**: |E(G)| · |H|² + |G| · |E(H)|
+/// * **Not commutative**: G\[H\] and H\[G\] differ in general
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::{generate_complete, generate_path};
+/// use graph_generation_language::operations::lexicographic_product;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+/// let path = generate_path(&params).unwrap();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(2));
+/// let edge = generate_complete(&params).unwrap();
+///
+/// let product = lexicographic_product(&path, &edge).unwrap();
+/// assert_eq!(product.edge_count(), 2 * 4 + 3);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// // Racks of 4 fully meshed servers, with every server linked to the neighbouring racks
+/// lexicographic(generate cycle { nodes: 6; prefix: "rack"; }, generate complete { nodes: 4; prefix: "s"; });
+/// ```
+pub fn lexicographic_product(g: &Graph, h: &Graph) -> Result<Graph, String> {
+    product(
+        g,
+        h,
+        ProductEdges {
+            first: false,
+            second: true,
+            both: false,
+            first_any: true,
+        },
+    )
+}
+
+/// Copies every node and edge of `from` into `graph`, rejecting clashing node ids.
+///
+/// Generators number edges without their prefix, so an edge whose id is
+/// already taken is renamed `e{source}_{target}` after its endpoints.
+fn add_disjoint(graph: &mut Graph, from: &Graph) -> Result<(), String> {
+    let mut node_ids: Vec<&String> = from.nodes.keys().collect();
+    node_ids.sort();
+    if let Some(id) = node_ids
+        .into_iter()
+        .find(|id| graph.nodes.contains_key(*id))
+    {
+        return Err(format!(
+            "both operands have a node {}, give them different prefixes",
+            id
+        ));
+    }
+    graph.nodes.extend(from.nodes.clone());
+
+    let mut edges: Vec<&Edge> = from.edges.values().collect();
+    edges.sort_by(|a, b| a.id.cmp(&b.id));
+    for edge in edges {
+        let mut edge = edge.clone();
+        if graph.edges.contains_key(&edge.id) {
+            edge.id = format!("e{}_{}", edge.source, edge.target);
+        }
+        if graph.edges.contains_key(&edge.id) {
+            return Err(format!("both operands have an edge {}", edge.id));
+        }
+        graph.add_edge(edge);
+    }
+    Ok(())
+}
+
+/// Computes the disjoint union G + H, placing both graphs side by side.
+///
+/// Nodes keep their ids, so the operands must not share any; give generated
+/// operands different prefixes. Edges keep their ids too, except that an edge
+/// of H whose id is already used in G is renamed `e{source}_{target}`.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_cycle;
+/// use graph_generation_language::operations::disjoint_union;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+/// let first = generate_cycle(&params).unwrap();
+/// params.insert("prefix".to_string(), MetadataValue::String("m".to_string()));
+/// let second = generate_cycle(&params).unwrap();
+///
+/// let both = disjoint_union(&first, &second).unwrap();
+/// assert_eq!(both.node_count(), 6);
+/// assert!(disjoint_union(&first, &first).is_err());
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// union(generate cycle { nodes: 5; prefix: "a"; }, generate cycle { nodes: 5; prefix: "b"; });
+/// ```
+pub fn disjoint_union(g: &Graph, h: &Graph) -> Result<Graph, String> {
+    let mut graph = g.clone();
+    add_disjoint(&mut graph, h)?;
+    Ok(graph)
+}
+
+/// Computes the join G ∇ H, the disjoint union plus an edge from every node of G to every node of H.
+///
+/// The added edges run from G to H and are named `e{u}_{v}`.
+///
+/// # Properties
+///
+/// * **Nodes**: |G| + |H|
+/// * **Edges**: |E(G)| + |E(H)| + |G| · |H|
+/// * **Examples**: a wheel is the join of a cycle and a single node, and a
+///   complete bipartite graph the join of two edgeless graphs
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_cycle;
+/// use graph_generation_language::operations::join;
+/// use graph_generation_language::types::{Graph, MetadataValue, Node};
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(5));
+/// let rim = generate_cycle(&params).unwrap();
+/// let mut hub = Graph::new();
+/// hub.add_node(Node::new("hub".to_string()));
+///
+/// let wheel = join(&rim, &hub).unwrap();
+/// assert_eq!(wheel.edge_count(), 10);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// join(generate cycle { nodes: 5; }, generate complete { nodes: 1; prefix: "hub"; });
+/// ```
+pub fn join(g: &Graph, h: &Graph) -> Result<Graph, String> {
+    let mut graph = disjoint_union(g, h)?;
+    for u in sorted_nodes(g) {
+        for v in sorted_nodes(h) {
+            let id = format!("e{}_{}", u.id, v.id);
+            if graph.edges.contains_key(&id) {
+                return Err(format!("the join edge {} already exists", id));
+            }
+            graph.add_edge(Edge::new(id, u.id.clone(), v.id.clone()));
+        }
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_edges_skip_loops_and_repeats() {
+        let mut graph = Graph::new();
+        for id in ["a", "b"] {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        for (id, source, target) in [
+            ("ab", "a", "b"),
+            ("ba", "b", "a"),
+            ("aa", "a", "a"),
+            ("ax", "a", "x"),
+        ] {
+            graph.add_edge(Edge::new(
+                id.to_string(),
+                source.to_string(),
+                target.to_string(),
+            ));
+        }
+        let edges: Vec<&str> = simple_edges(&graph).iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(edges, vec!["ab"]);
+    }
+
+    #[test]
+    fn test_product_node_merges_labels() {
+        let u = Node::new("u".to_string())
+            .with_type("rack".to_string())
+            .with_metadata("row".to_string(), crate::types::MetadataValue::Integer(1));
+        let v = Node::new("v".to_string());
+        let node = product_node(&u, &v);
+        assert_eq!(node.id, "u_v");
+        assert_eq!(node.r#type, "rack");
+        assert!(node.metadata.contains_key("row"));
+    }
+}
@@ -1,3 +1,8 @@
+use crate::attributes::{AttributeTemplate, AttributeValue, Distribution, Templates};
+use crate::export::value_text;
+use crate::predicates::{CompareOp, Comparison, ElementKind, Operand, Predicate};
+use crate::relabel::{NodeOrder, Relabeling};
+use crate::selectors::NodeSelector;
 use crate::types::MetadataValue;
 use pest::Parser as PestParser;
 use pest_derive::Parser;
@@ -16,7 +21,8 @@ number = @{ ("+" | "-")? ~ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT*)? }
 boolean = @{ "true" | "false" }
 
 // Values
-value = { string | number | boolean | ident }
+list = { "[" ~ (value ~ ("," ~ value)*)? ~ "]" }
+value = { list | string | number | boolean | ident }
 
 // Attributes
 attribute = { ident ~ "=" ~ value }
@@ -29,12 +35,29 @@ node_decl = { "node" ~ ident ~ node_type? ~ attributes? ~ ";" }
 
 // Edge declarations
 edge_op = { "->" | "--" }
-edge_decl = { "edge" ~ ident? ~ ":" ~ ident ~ edge_op ~ ident ~ attributes? ~ ";" }
+node_ref = @{ ident ~ ("." ~ ident)* }
+node_glob = @{ (ASCII_ALPHANUMERIC | "_" | ".")* ~ "*" ~ (ASCII_ALPHANUMERIC | "_" | "." | "*")* }
+node_selector = { attributes | node_glob | node_ref }
+edge_decl = { "edge" ~ ident? ~ ":" ~ node_selector ~ edge_op ~ node_selector ~ attributes? ~ ";" }
 
 // Generator statements
 param = { ident ~ ":" ~ value }
 param_list = { (param ~ ";")* }
-generate_stmt = { "generate" ~ ident ~ "{" ~ param_list ~ "}" }
+sampler = { ident ~ "(" ~ (value ~ ("," ~ value)*)? ~ ")" }
+attr_template = { ident ~ "=" ~ (sampler | value) }
+attr_templates = { "[" ~ attr_template ~ ("," ~ attr_template)* ~ "]" }
+generate_param = { ident ~ ":" ~ (attr_templates | value) }
+generate_param_list = { (generate_param ~ ";")* }
+generate_stmt = { "generate" ~ ident ~ "{" ~ generate_param_list ~ "}" }
+
+// Transform statements
+transform_stmt = { "transform" ~ ident ~ ("{" ~ param_list ~ "}" | ";") }
+
+// Graph operations
+graph_operand = { generate_stmt | graph_call | ident }
+graph_call = { ident ~ "(" ~ graph_operand ~ ("," ~ graph_operand)+ ~ ")" }
+operation_stmt = { graph_call ~ ";" }
+let_stmt = { "let" ~ ident ~ "=" ~ (generate_stmt ~ ";"? | graph_operand ~ ";") }
 
 // Rule patterns
 node_pattern = { "node" ~ ident ~ node_type? ~ attributes? ~ ";" }
@@ -47,8 +70,32 @@ rule_def = { "rule" ~ ident ~ "{" ~ "lhs" ~ pattern ~ "rhs" ~ pattern ~ "}" }
 // Rule application
 apply_rule = { "apply" ~ ident ~ number ~ "times" ~ ";" }
 
+// Seed statement
+seed_stmt = { "seed" ~ number ~ ";" }
+
+// Bulk edits
+element_kind = { "nodes" | "edges" }
+compare_op = { "==" | "!=" | "<=" | ">=" | "<" | ">" }
+function_call = { ident ~ "(" ~ ")" }
+comparison = { (function_call | ident) ~ compare_op ~ value }
+and_op = @{ "and" ~ !(ASCII_ALPHANUMERIC | "_") }
+predicate = { "where" ~ comparison ~ (and_op ~ comparison)* }
+set_stmt = { "set" ~ element_kind ~ predicate? ~ attributes ~ ";" }
+delete_stmt = { "delete" ~ element_kind ~ predicate? ~ ";" }
+retype_stmt = { "retype" ~ element_kind ~ predicate? ~ node_type ~ ";" }
+
+// Relabeling
+relabel_pair = { node_ref ~ ":" ~ value }
+relabel_map = { "{" ~ (relabel_pair ~ ";")* ~ "}" }
+relabel_order = { "bfs" | "degree" | "id" }
+relabel_stmt = { "relabel" ~ (relabel_map | string? ~ ("by" ~ relabel_order)? ~ ";") }
+
+// Subgraphs
+subgraph_stmt = { node_decl | edge_decl | generate_stmt | subgraph }
+subgraph = { "subgraph" ~ ident ~ "{" ~ subgraph_stmt* ~ "}" }
+
 // Graph statements
-statement = { node_decl | edge_decl | generate_stmt | rule_def | apply_rule }
+statement = { node_decl | edge_decl | generate_stmt | transform_stmt | let_stmt | rule_def | apply_rule | seed_stmt | subgraph | set_stmt | delete_stmt | retype_stmt | relabel_stmt | operation_stmt }
 graph = { "graph" ~ ident? ~ "{" ~ statement* ~ "}" }
 
 // Entry point
@@ -72,10 +119,85 @@ pub struct EdgeDeclaration {
     pub attributes: HashMap<String, MetadataValue>,
 }
 
+/// An edge declaration with a wildcard or attribute selector at either end.
+#[derive(Debug, Clone)]
+pub struct SelectorEdgeDeclaration {
+    /// Explicit id, used as the prefix of the generated edge ids
+    pub id: Option<String>,
+    pub source: NodeSelector,
+    pub target: NodeSelector,
+    pub directed: bool,
+    pub attributes: HashMap<String, MetadataValue>,
+}
+
 #[derive(Debug, Clone)]
 pub struct GenerateStatement {
     pub name: String,
     pub params: HashMap<String, MetadataValue>,
+    /// Types and attributes for the generated nodes and edges
+    pub templates: Templates,
+    /// Line of the statement in the source, starting at 1
+    pub line: usize,
+    /// Column of the statement in the source, starting at 1
+    pub column: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransformStatement {
+    pub name: String,
+    pub params: HashMap<String, MetadataValue>,
+}
+
+/// A graph used by an operation: a generator result, a `let` name, or another call.
+#[derive(Debug, Clone)]
+pub enum GraphOperand {
+    Generate(GenerateStatement),
+    Call(GraphCall),
+    Named(String),
+}
+
+/// An operation such as `cartesian(a, b)`.
+#[derive(Debug, Clone)]
+pub struct GraphCall {
+    pub operation: String,
+    pub operands: Vec<GraphOperand>,
+    /// Line of the call in the source, starting at 1
+    pub line: usize,
+    /// Column of the call in the source, starting at 1
+    pub column: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct LetStatement {
+    pub name: String,
+    pub value: GraphOperand,
+}
+
+/// A `subgraph name { ... }` block scoping its nodes and edges under `name`.
+#[derive(Debug, Clone)]
+pub struct SubgraphBlock {
+    pub name: String,
+    /// Node, edge, generate and nested subgraph statements
+    pub statements: Vec<GGLStatement>,
+}
+
+/// What a bulk edit does to the nodes or edges it selects.
+#[derive(Debug, Clone)]
+pub enum EditAction {
+    /// `set`: add or overwrite attributes
+    Set(HashMap<String, MetadataValue>),
+    /// `delete`: remove them, along with the edges of deleted nodes
+    Delete,
+    /// `retype`: change their type
+    Retype(String),
+}
+
+/// A `set`, `delete` or `retype` statement with an optional `where` clause.
+#[derive(Debug, Clone)]
+pub struct EditStatement {
+    pub kind: ElementKind,
+    pub predicate: Predicate,
+    pub action: EditAction,
 }
 
 #[derive(Debug, Clone)]
@@ -97,18 +219,31 @@ pub struct ApplyRuleStatement {
     pub iterations: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct SeedStatement {
+    pub seed: u64,
+}
+
 #[derive(Debug, Clone)]
 pub enum GGLStatement {
     NodeDecl(NodeDeclaration),
     EdgeDecl(EdgeDeclaration),
+    SelectorEdgeDecl(SelectorEdgeDeclaration),
     GenerateStmt(GenerateStatement),
+    TransformStmt(TransformStatement),
+    LetStmt(LetStatement),
+    OperationStmt(GraphCall),
     RuleDefStmt(RuleDefinition),
     ApplyRuleStmt(ApplyRuleStatement),
+    SeedStmt(SeedStatement),
+    SubgraphStmt(SubgraphBlock),
+    EditStmt(EditStatement),
+    RelabelStmt(Relabeling),
 }
 
 pub fn parse_ggl(input: &str) -> Result<Vec<GGLStatement>, String> {
-    let pairs =
-        <GGLParser as PestParser<Rule>>::parse(Rule::program, input).map_err(|e| format!("Parse error: {}", e))?;
+    let pairs = <GGLParser as PestParser<Rule>>::parse(Rule::program, input)
+        .map_err(|e| format!("Parse error: {}", e))?;
 
     let mut statements = Vec::new();
 
@@ -138,17 +273,32 @@ pub fn parse_ggl(input: &str) -> Result<Vec<GGLStatement>, String> {
 
 fn parse_statement(pair: pest::iterators::Pair<Rule>) -> Result<Option<GGLStatement>, String> {
     match pair.as_rule() {
-        Rule::statement => {
+        Rule::statement | Rule::subgraph_stmt => {
             // Get the actual statement type from within the statement rule
             let inner = pair.into_inner().next().unwrap();
             match inner.as_rule() {
                 Rule::node_decl => Ok(Some(GGLStatement::NodeDecl(parse_node_decl(inner)?))),
-                Rule::edge_decl => Ok(Some(GGLStatement::EdgeDecl(parse_edge_decl(inner)?))),
+                Rule::edge_decl => Ok(Some(parse_edge_stmt(inner)?)),
                 Rule::generate_stmt => Ok(Some(GGLStatement::GenerateStmt(parse_generate_stmt(
                     inner,
                 )?))),
+                Rule::transform_stmt => Ok(Some(GGLStatement::TransformStmt(
+                    parse_transform_stmt(inner)?,
+                ))),
                 Rule::rule_def => Ok(Some(GGLStatement::RuleDefStmt(parse_rule_def(inner)?))),
                 Rule::apply_rule => Ok(Some(GGLStatement::ApplyRuleStmt(parse_apply_rule(inner)?))),
+                Rule::seed_stmt => Ok(Some(GGLStatement::SeedStmt(parse_seed_stmt(inner)?))),
+                Rule::subgraph => Ok(Some(GGLStatement::SubgraphStmt(parse_subgraph(inner)?))),
+                Rule::let_stmt => Ok(Some(GGLStatement::LetStmt(parse_let_stmt(inner)?))),
+                Rule::relabel_stmt => {
+                    Ok(Some(GGLStatement::RelabelStmt(parse_relabel_stmt(inner)?)))
+                }
+                Rule::set_stmt | Rule::delete_stmt | Rule::retype_stmt => {
+                    Ok(Some(GGLStatement::EditStmt(parse_edit_stmt(inner)?)))
+                }
+                Rule::operation_stmt => Ok(Some(GGLStatement::OperationStmt(parse_graph_call(
+                    inner.into_inner().next().unwrap(),
+                )?))),
                 _ => Ok(None),
             }
         }
@@ -232,20 +382,186 @@ fn parse_edge_decl(pair: pest::iterators::Pair<Rule>) -> Result<EdgeDeclaration,
     })
 }
 
+/// Parses an edge declaration, which has selectors as endpoints.
+///
+/// Declarations between two named nodes stay ordinary edge declarations.
+fn parse_edge_stmt(pair: pest::iterators::Pair<Rule>) -> Result<GGLStatement, String> {
+    let mut id = None;
+    let mut endpoints = Vec::new();
+    let mut directed = false;
+    let mut attributes = HashMap::new();
+
+    for inner_pair in pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::ident => id = Some(inner_pair.as_str().to_string()),
+            Rule::node_selector => endpoints.push(parse_node_selector(inner_pair)?),
+            Rule::edge_op => directed = inner_pair.as_str() == "->",
+            Rule::attributes => attributes = parse_attributes(inner_pair)?,
+            _ => (),
+        }
+    }
+
+    let target = endpoints.pop().unwrap();
+    let source = endpoints.pop().unwrap();
+    Ok(match (source, target) {
+        (NodeSelector::Id(source), NodeSelector::Id(target)) => {
+            GGLStatement::EdgeDecl(EdgeDeclaration {
+                id: id.unwrap_or_else(|| format!("e{}_{}", source, target)),
+                source,
+                target,
+                directed,
+                attributes,
+            })
+        }
+        (source, target) => GGLStatement::SelectorEdgeDecl(SelectorEdgeDeclaration {
+            id,
+            source,
+            target,
+            directed,
+            attributes,
+        }),
+    })
+}
+
+fn parse_node_selector(pair: pest::iterators::Pair<Rule>) -> Result<NodeSelector, String> {
+    let inner = pair.into_inner().next().unwrap();
+    match inner.as_rule() {
+        Rule::node_ref => Ok(NodeSelector::Id(inner.as_str().to_string())),
+        Rule::node_glob => Ok(NodeSelector::Glob(inner.as_str().to_string())),
+        Rule::attributes => Ok(NodeSelector::Attributes(parse_attributes(inner)?)),
+        _ => unreachable!(),
+    }
+}
+
+fn parse_edit_stmt(pair: pest::iterators::Pair<Rule>) -> Result<EditStatement, String> {
+    let mut kind = ElementKind::Nodes;
+    let mut predicate = Predicate::default();
+    let mut action = EditAction::Delete;
+
+    for inner_pair in pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::element_kind if inner_pair.as_str() == "edges" => kind = ElementKind::Edges,
+            Rule::predicate => predicate = parse_predicate(inner_pair)?,
+            Rule::attributes => action = EditAction::Set(parse_attributes(inner_pair)?),
+            Rule::node_type => {
+                let new_type = inner_pair.into_inner().next().unwrap().as_str();
+                action = EditAction::Retype(new_type.to_string());
+            }
+            _ => (),
+        }
+    }
+
+    Ok(EditStatement {
+        kind,
+        predicate,
+        action,
+    })
+}
+
+fn parse_predicate(pair: pest::iterators::Pair<Rule>) -> Result<Predicate, String> {
+    let mut comparisons = Vec::new();
+    for comparison in pair.into_inner() {
+        if comparison.as_rule() != Rule::comparison {
+            continue;
+        }
+        let mut inner = comparison.into_inner();
+        let left = inner.next().unwrap();
+        let operand = match left.as_rule() {
+            Rule::function_call => {
+                Operand::Function(left.into_inner().next().unwrap().as_str().to_string())
+            }
+            _ => Operand::Attribute(left.as_str().to_string()),
+        };
+        let op = CompareOp::from_symbol(inner.next().unwrap().as_str()).unwrap();
+        let value = parse_value(inner.next().unwrap())?;
+        comparisons.push(Comparison { operand, op, value });
+    }
+    Ok(Predicate { comparisons })
+}
+
+fn parse_relabel_stmt(pair: pest::iterators::Pair<Rule>) -> Result<Relabeling, String> {
+    let mut format = "{index}".to_string();
+    let mut order = NodeOrder::Id;
+
+    for inner_pair in pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::relabel_map => {
+                let mut mapping = HashMap::new();
+                for relabel_pair in inner_pair.into_inner() {
+                    let mut inner = relabel_pair.into_inner();
+                    let id = inner.next().unwrap().as_str().to_string();
+                    let new_id = value_text(&parse_value(inner.next().unwrap())?);
+                    mapping.insert(id, new_id);
+                }
+                return Ok(Relabeling::Mapping(mapping));
+            }
+            Rule::string => format = inner_pair.as_str().trim_matches('"').to_string(),
+            Rule::relabel_order => order = NodeOrder::from_name(inner_pair.as_str()).unwrap(),
+            _ => (),
+        }
+    }
+
+    Ok(Relabeling::Format { format, order })
+}
+
+fn parse_subgraph(pair: pest::iterators::Pair<Rule>) -> Result<SubgraphBlock, String> {
+    let mut inner = pair.into_inner();
+    let name = inner.next().unwrap().as_str().to_string();
+    let mut statements = Vec::new();
+    for stmt_pair in inner {
+        if let Some(stmt) = parse_statement(stmt_pair)? {
+            statements.push(stmt);
+        }
+    }
+    Ok(SubgraphBlock { name, statements })
+}
+
 fn parse_generate_stmt(pair: pest::iterators::Pair<Rule>) -> Result<GenerateStatement, String> {
+    let (line, column) = pair.as_span().start_pos().line_col();
     let mut name = String::new();
     let mut params = HashMap::new();
+    let mut templates = Templates::default();
 
     for inner_pair in pair.into_inner() {
         match inner_pair.as_rule() {
             Rule::ident => name = inner_pair.as_str().to_string(),
-            Rule::param_list => {
+            Rule::generate_param_list => {
                 for param_pair in inner_pair.into_inner() {
-                    if param_pair.as_rule() == Rule::param {
-                        let mut param_iter = param_pair.into_inner();
-                        let param_name = param_iter.next().unwrap().as_str().to_string();
-                        let param_value = parse_value(param_iter.next().unwrap())?;
-                        params.insert(param_name, param_value);
+                    let mut param_iter = param_pair.into_inner();
+                    let param_name = param_iter.next().unwrap().as_str().to_string();
+                    let value_pair = param_iter.next().unwrap();
+
+                    match (param_name.as_str(), value_pair.as_rule()) {
+                        ("node_attrs", Rule::attr_templates) => {
+                            templates.node_attrs = parse_attr_templates(value_pair)?
+                        }
+                        ("edge_attrs", Rule::attr_templates) => {
+                            templates.edge_attrs = parse_attr_templates(value_pair)?
+                        }
+                        (_, Rule::attr_templates) => {
+                            return Err(format!(
+                                "Attribute templates are only allowed in node_attrs and edge_attrs, not {}",
+                                param_name
+                            ))
+                        }
+                        (key, _) => {
+                            let value = parse_value(value_pair)?;
+                            match (key, value) {
+                                ("node_type", MetadataValue::String(t)) => templates.node_type = Some(t),
+                                ("edge_type", MetadataValue::String(t)) => templates.edge_type = Some(t),
+                                ("node_attrs" | "edge_attrs", MetadataValue::List(items))
+                                    if items.is_empty() => {}
+                                ("node_type" | "edge_type" | "node_attrs" | "edge_attrs", other) => {
+                                    return Err(format!(
+                                        "Invalid {} in generate {}: {:?}",
+                                        key, name, other
+                                    ))
+                                }
+                                (_, value) => {
+                                    params.insert(param_name, value);
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -253,7 +569,115 @@ fn parse_generate_stmt(pair: pest::iterators::Pair<Rule>) -> Result<GenerateStat
         }
     }
 
-    Ok(GenerateStatement { name, params })
+    Ok(GenerateStatement {
+        name,
+        params,
+        templates,
+        line,
+        column,
+    })
+}
+
+fn parse_attr_templates(
+    pair: pest::iterators::Pair<Rule>,
+) -> Result<Vec<AttributeTemplate>, String> {
+    let mut templates = Vec::new();
+
+    for template_pair in pair.into_inner() {
+        let (line, column) = template_pair.as_span().start_pos().line_col();
+        let mut template_iter = template_pair.into_inner();
+        let name = template_iter.next().unwrap().as_str().to_string();
+        let value_pair = template_iter.next().unwrap();
+
+        let value = if value_pair.as_rule() == Rule::sampler {
+            let mut sampler_iter = value_pair.into_inner();
+            let function = sampler_iter.next().unwrap().as_str();
+            let args = sampler_iter
+                .map(parse_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            let distribution = Distribution::from_call(function, &args).map_err(|e| {
+                format!(
+                    "Invalid attribute {} at line {}, column {}: {}",
+                    name, line, column, e
+                )
+            })?;
+            AttributeValue::Sampled(distribution)
+        } else {
+            AttributeValue::Fixed(parse_value(value_pair)?)
+        };
+        templates.push(AttributeTemplate { name, value });
+    }
+
+    Ok(templates)
+}
+
+fn parse_transform_stmt(pair: pest::iterators::Pair<Rule>) -> Result<TransformStatement, String> {
+    let mut name = String::new();
+    let mut params = HashMap::new();
+
+    for inner_pair in pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::ident => name = inner_pair.as_str().to_string(),
+            Rule::param_list => params = parse_param_list(inner_pair)?,
+            _ => (),
+        }
+    }
+
+    Ok(TransformStatement { name, params })
+}
+
+fn parse_param_list(
+    pair: pest::iterators::Pair<Rule>,
+) -> Result<HashMap<String, MetadataValue>, String> {
+    let mut params = HashMap::new();
+
+    for param_pair in pair.into_inner() {
+        if param_pair.as_rule() == Rule::param {
+            let mut param_iter = param_pair.into_inner();
+            let param_name = param_iter.next().unwrap().as_str().to_string();
+            let param_value = parse_value(param_iter.next().unwrap())?;
+            params.insert(param_name, param_value);
+        }
+    }
+
+    Ok(params)
+}
+
+fn parse_let_stmt(pair: pest::iterators::Pair<Rule>) -> Result<LetStatement, String> {
+    let mut inner = pair.into_inner();
+    let name = inner.next().unwrap().as_str().to_string();
+    let value_pair = inner.next().unwrap();
+    let value = match value_pair.as_rule() {
+        Rule::generate_stmt => GraphOperand::Generate(parse_generate_stmt(value_pair)?),
+        _ => parse_graph_operand(value_pair)?,
+    };
+
+    Ok(LetStatement { name, value })
+}
+
+fn parse_graph_operand(pair: pest::iterators::Pair<Rule>) -> Result<GraphOperand, String> {
+    let inner = pair.into_inner().next().unwrap();
+    match inner.as_rule() {
+        Rule::generate_stmt => Ok(GraphOperand::Generate(parse_generate_stmt(inner)?)),
+        Rule::graph_call => Ok(GraphOperand::Call(parse_graph_call(inner)?)),
+        _ => Ok(GraphOperand::Named(inner.as_str().to_string())),
+    }
+}
+
+fn parse_graph_call(pair: pest::iterators::Pair<Rule>) -> Result<GraphCall, String> {
+    let (line, column) = pair.as_span().start_pos().line_col();
+    let mut inner = pair.into_inner();
+    let operation = inner.next().unwrap().as_str().to_string();
+    let operands = inner
+        .map(parse_graph_operand)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(GraphCall {
+        operation,
+        operands,
+        line,
+        column,
+    })
 }
 
 fn parse_rule_def(pair: pest::iterators::Pair<Rule>) -> Result<RuleDefinition, String> {
@@ -296,7 +720,7 @@ fn parse_pattern(pair: pest::iterators::Pair<Rule>) -> Result<Pattern, String> {
                 nodes.push(parse_node_decl(inner_pair)?);
             }
             Rule::edge_pattern => {
-                // Parse edge_pattern the same way as edge_decl
+                // Parse edge_pattern into an ordinary edge declaration
                 edges.push(parse_edge_decl(inner_pair)?);
             }
             _ => (),
@@ -329,6 +753,17 @@ fn parse_apply_rule(pair: pest::iterators::Pair<Rule>) -> Result<ApplyRuleStatem
     })
 }
 
+fn parse_seed_stmt(pair: pest::iterators::Pair<Rule>) -> Result<SeedStatement, String> {
+    let number = pair.into_inner().next().unwrap();
+    let seed = number
+        .as_str()
+        .trim_start_matches('+')
+        .parse::<u64>()
+        .map_err(|e| format!("Invalid seed {}: {}", number.as_str(), e))?;
+
+    Ok(SeedStatement { seed })
+}
+
 fn parse_attributes(
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<HashMap<String, MetadataValue>, String> {
@@ -373,6 +808,11 @@ fn parse_value(pair: pest::iterators::Pair<Rule>) -> Result<MetadataValue, Strin
             }
         }
         Rule::boolean => Ok(MetadataValue::Boolean(value_pair.as_str() == "true")),
+        Rule::list => value_pair
+            .into_inner()
+            .map(parse_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map(MetadataValue::List),
         Rule::ident => Ok(MetadataValue::String(value_pair.as_str().to_string())),
         _ => Err(format!("Unexpected value type: {:?}", value_pair.as_rule())),
     }
@@ -0,0 +1,272 @@
+//! # Predicates
+//!
+//! Predicates pick the nodes or edges a bulk edit applies to, in the `where`
+//! clause of the `set`, `delete` and `retype` statements:
+//!
+//! ```ggl
+//! set nodes where type == "server" and cpu > 8 [tier="big"];
+//! delete edges where weight < 0.1;
+//! retype nodes where degree() == 1 :leaf;
+//! ```
+//!
+//! ## Comparisons
+//!
+//! A predicate is one or more comparisons joined by `and`. The left side of a
+//! comparison is one of:
+//!
+//! * `id` and `type` - The id and type of the node or edge
+//! * `source` and `target` - The endpoints of an edge
+//! * `degree()`, `in_degree()` and `out_degree()` - The number of edges at a node,
+//!   counting every edge from or to it, only those to it, or only those from it
+//! * Any other name - An attribute
+//!
+//! The operators are `==`, `!=`, `<`, `<=`, `>` and `>=`. Integers and floats
+//! compare as numbers and strings in lexicographic order. Other values can
+//! only be compared for equality. Nodes and edges without the attribute do
+//! not match any comparison on it, not even `!=`.
+
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Whether a bulk edit applies to nodes or to edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementKind {
+    Nodes,
+    Edges,
+}
+
+/// A comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// Parses an operator as written in GGL, e.g. `<=`.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "==" => Some(CompareOp::Eq),
+            "!=" => Some(CompareOp::Ne),
+            "<" => Some(CompareOp::Lt),
+            "<=" => Some(CompareOp::Le),
+            ">" => Some(CompareOp::Gt),
+            ">=" => Some(CompareOp::Ge),
+            _ => None,
+        }
+    }
+
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        match (self, ordering) {
+            (CompareOp::Ne, ordering) => ordering != Some(Ordering::Equal),
+            (_, None) => false,
+            (CompareOp::Eq, Some(ordering)) => ordering == Ordering::Equal,
+            (CompareOp::Lt, Some(ordering)) => ordering == Ordering::Less,
+            (CompareOp::Le, Some(ordering)) => ordering != Ordering::Greater,
+            (CompareOp::Gt, Some(ordering)) => ordering == Ordering::Greater,
+            (CompareOp::Ge, Some(ordering)) => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// The left side of a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    /// A field such as `type`, or an attribute
+    Attribute(String),
+    /// A function of the node such as `degree()`
+    Function(String),
+}
+
+/// A single comparison, e.g. `cpu > 8`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub operand: Operand,
+    pub op: CompareOp,
+    pub value: MetadataValue,
+}
+
+/// Comparisons that must all hold. Without comparisons everything matches.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Predicate {
+    pub comparisons: Vec<Comparison>,
+}
+
+/// Orders two values, or returns `None` if they cannot be ordered.
+fn compare_values(left: &MetadataValue, right: &MetadataValue) -> Option<Ordering> {
+    let number = |value: &MetadataValue| match value {
+        MetadataValue::Integer(i) => Some(*i as f64),
+        MetadataValue::Float(f) => Some(*f),
+        _ => None,
+    };
+    match (left, right) {
+        (MetadataValue::String(a), MetadataValue::String(b)) => Some(a.cmp(b)),
+        (MetadataValue::Integer(a), MetadataValue::Integer(b)) => Some(a.cmp(b)),
+        _ => match (number(left), number(right)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            _ if left == right => Some(Ordering::Equal),
+            _ => None,
+        },
+    }
+}
+
+impl Predicate {
+    /// The names of the functions the comparisons call.
+    fn functions(&self) -> impl Iterator<Item = &str> {
+        self.comparisons
+            .iter()
+            .filter_map(|comparison| match &comparison.operand {
+                Operand::Function(name) => Some(name.as_str()),
+                Operand::Attribute(_) => None,
+            })
+    }
+
+    /// Returns whether every comparison holds for the value it looks up.
+    fn holds(&self, lookup: impl Fn(&Operand) -> Option<MetadataValue>) -> bool {
+        self.comparisons.iter().all(|comparison| {
+            lookup(&comparison.operand).is_some_and(|actual| {
+                comparison
+                    .op
+                    .holds(compare_values(&actual, &comparison.value))
+            })
+        })
+    }
+
+    /// Returns the sorted ids of the nodes the predicate holds for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::generate_star;
+    /// use graph_generation_language::predicates::{CompareOp, Comparison, Operand, Predicate};
+    /// use graph_generation_language::types::MetadataValue;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert("nodes".to_string(), MetadataValue::Integer(4));
+    /// let graph = generate_star(&params).unwrap();
+    ///
+    /// let leaves = Predicate {
+    ///     comparisons: vec![Comparison {
+    ///         operand: Operand::Function("degree".to_string()),
+    ///         op: CompareOp::Eq,
+    ///         value: MetadataValue::Integer(1),
+    ///     }],
+    /// };
+    /// assert_eq!(leaves.nodes(&graph).unwrap(), vec!["n1", "n2", "n3"]);
+    /// ```
+    pub fn nodes(&self, graph: &Graph) -> Result<Vec<String>, String> {
+        for name in self.functions() {
+            if !matches!(name, "degree" | "in_degree" | "out_degree") {
+                return Err(format!("Unknown function: {}()", name));
+            }
+        }
+        let mut in_degree: HashMap<&str, i64> = HashMap::new();
+        let mut out_degree: HashMap<&str, i64> = HashMap::new();
+        for edge in graph.edges.values() {
+            *out_degree.entry(&edge.source).or_default() += 1;
+            *in_degree.entry(&edge.target).or_default() += 1;
+        }
+
+        let mut ids = Vec::new();
+        for node in graph.nodes.values() {
+            let count =
+                |degrees: &HashMap<&str, i64>| degrees.get(node.id.as_str()).copied().unwrap_or(0);
+            let holds = self.holds(|operand| match operand {
+                Operand::Function(name) => Some(MetadataValue::Integer(match name.as_str() {
+                    "degree" => count(&in_degree) + count(&out_degree),
+                    "in_degree" => count(&in_degree),
+                    _ => count(&out_degree),
+                })),
+                Operand::Attribute(name) => node_field(node, name),
+            });
+            if holds {
+                ids.push(node.id.clone());
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// Returns the sorted ids of the edges the predicate holds for.
+    pub fn edges(&self, graph: &Graph) -> Result<Vec<String>, String> {
+        if let Some(name) = self.functions().next() {
+            return Err(format!("{}() only applies to nodes", name));
+        }
+
+        let mut ids = Vec::new();
+        for edge in graph.edges.values() {
+            let holds = self.holds(|operand| match operand {
+                Operand::Attribute(name) => edge_field(edge, name),
+                Operand::Function(_) => unreachable!(),
+            });
+            if holds {
+                ids.push(edge.id.clone());
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// Returns the sorted ids of the nodes or edges the predicate holds for.
+    pub fn select(&self, graph: &Graph, kind: ElementKind) -> Result<Vec<String>, String> {
+        match kind {
+            ElementKind::Nodes => self.nodes(graph),
+            ElementKind::Edges => self.edges(graph),
+        }
+    }
+}
+
+fn node_field(node: &Node, name: &str) -> Option<MetadataValue> {
+    match name {
+        "id" => Some(MetadataValue::String(node.id.clone())),
+        "type" => Some(MetadataValue::String(node.r#type.clone())),
+        _ => node.metadata.get(name).cloned(),
+    }
+}
+
+fn edge_field(edge: &Edge, name: &str) -> Option<MetadataValue> {
+    match name {
+        "id" => Some(MetadataValue::String(edge.id.clone())),
+        "type" => Some(MetadataValue::String(edge.r#type.clone())),
+        "source" => Some(MetadataValue::String(edge.source.clone())),
+        "target" => Some(MetadataValue::String(edge.target.clone())),
+        _ => edge.metadata.get(name).cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_values() {
+        use MetadataValue::*;
+        assert_eq!(
+            compare_values(&Integer(2), &Float(2.0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            compare_values(&Float(0.05), &Float(0.1)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare_values(&String("a".to_string()), &String("b".to_string())),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare_values(&Boolean(true), &Boolean(true)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(compare_values(&Boolean(true), &Integer(1)), None);
+
+        assert!(CompareOp::Ne.holds(None));
+        assert!(!CompareOp::Le.holds(None));
+        assert!(CompareOp::Ge.holds(Some(Ordering::Equal)));
+    }
+}
@@ -0,0 +1,310 @@
+//! # Relabeling
+//!
+//! Renames the nodes of a graph, updating the edges that refer to them. This
+//! is mostly useful before handing a graph to tools that expect dense integer
+//! ids, since generators name their nodes after a prefix such as `n0`.
+//!
+//! ```ggl
+//! relabel;                        // 0, 1, 2, ... in id order
+//! relabel by bfs;                 // 0, 1, 2, ... in breadth-first order
+//! relabel "v{index}" by degree;   // v0 for the node of highest degree, ...
+//! relabel "{name}";               // After the name attribute
+//! relabel { n0: hub; n1: "spare"; }  // By an explicit mapping
+//! ```
+//!
+//! ## Format Strings
+//!
+//! A format string names every node, replacing `{index}` with its position in
+//! the chosen order, starting at 0, `{id}` with its current id, `{type}` with
+//! its type and any other `{name}` with the value of that attribute.
+//!
+//! ## Orders
+//!
+//! * `id` - By id, comparing runs of digits as numbers so that `n2` comes
+//!   before `n10` (default)
+//! * `bfs` - Breadth-first from the first node by id, treating edges as
+//!   undirected and visiting each component in turn
+//! * `degree` - By decreasing number of incident edges, ties broken by id
+
+use crate::export::value_text;
+use crate::types::{Graph, Node};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The order in which nodes are numbered by a format string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NodeOrder {
+    /// By id, with runs of digits compared as numbers
+    #[default]
+    Id,
+    /// Breadth-first, treating edges as undirected
+    Bfs,
+    /// By decreasing degree
+    Degree,
+}
+
+impl NodeOrder {
+    /// Parses an order as written in GGL, e.g. `bfs`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "id" => Some(NodeOrder::Id),
+            "bfs" => Some(NodeOrder::Bfs),
+            "degree" => Some(NodeOrder::Degree),
+            _ => None,
+        }
+    }
+}
+
+/// How to rename the nodes of a graph.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Relabeling {
+    /// Renames the given nodes and keeps the ids of the others
+    Mapping(HashMap<String, String>),
+    /// Names every node after a format string, numbering them in the given order
+    Format { format: String, order: NodeOrder },
+}
+
+impl Relabeling {
+    /// Numbers the nodes 0, 1, 2, ... in the given order.
+    pub fn integers(order: NodeOrder) -> Self {
+        Relabeling::Format {
+            format: "{index}".to_string(),
+            order,
+        }
+    }
+}
+
+/// Compares ids with runs of digits compared as numbers, so `n2 < n10`.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    fn chunks(s: &str) -> Vec<(bool, &str)> {
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        for (i, c) in s.char_indices().skip(1) {
+            let prev = s[..i].chars().next_back().unwrap();
+            if c.is_ascii_digit() != prev.is_ascii_digit() {
+                chunks.push(&s[start..i]);
+                start = i;
+            }
+        }
+        if !s.is_empty() {
+            chunks.push(&s[start..]);
+        }
+        chunks
+            .into_iter()
+            .map(|chunk| (chunk.starts_with(|c: char| c.is_ascii_digit()), chunk))
+            .collect()
+    }
+
+    let ordering = chunks(a)
+        .into_iter()
+        .zip(chunks(b))
+        .map(
+            |((a_digits, a), (b_digits, b))| match (a_digits, b_digits) {
+                (true, true) => {
+                    let (a_trimmed, b_trimmed) =
+                        (a.trim_start_matches('0'), b.trim_start_matches('0'));
+                    a_trimmed
+                        .len()
+                        .cmp(&b_trimmed.len())
+                        .then_with(|| a_trimmed.cmp(b_trimmed))
+                }
+                _ => a.cmp(b),
+            },
+        )
+        .find(|ordering| ordering.is_ne());
+    // Fall back to plain comparison for prefixes and leading zeros
+    ordering.unwrap_or_else(|| a.cmp(b))
+}
+
+/// Returns the node ids in the given order.
+fn ordered_ids(graph: &Graph, order: NodeOrder) -> Vec<String> {
+    let mut ids: Vec<String> = graph.nodes.keys().cloned().collect();
+    ids.sort_by(|a, b| natural_cmp(a, b));
+
+    match order {
+        NodeOrder::Id => ids,
+        NodeOrder::Degree => {
+            let mut degree: HashMap<&str, usize> = HashMap::new();
+            for edge in graph.edges.values() {
+                *degree.entry(&edge.source).or_default() += 1;
+                *degree.entry(&edge.target).or_default() += 1;
+            }
+            let degree_of = |id: &String| degree.get(id.as_str()).copied().unwrap_or(0);
+            let mut by_degree = ids.clone();
+            // Stable, so ties stay in id order
+            by_degree.sort_by_key(|id| std::cmp::Reverse(degree_of(id)));
+            by_degree
+        }
+        NodeOrder::Bfs => {
+            let mut neighbors: HashMap<&str, Vec<&str>> = HashMap::new();
+            for edge in graph.edges.values() {
+                neighbors
+                    .entry(&edge.source)
+                    .or_default()
+                    .push(&edge.target);
+                neighbors
+                    .entry(&edge.target)
+                    .or_default()
+                    .push(&edge.source);
+            }
+            for list in neighbors.values_mut() {
+                list.sort_by(|a, b| natural_cmp(a, b));
+            }
+
+            let mut visited: HashSet<&str> = HashSet::new();
+            let mut bfs = Vec::with_capacity(ids.len());
+            for start in &ids {
+                if !visited.insert(start) {
+                    continue;
+                }
+                let mut queue = VecDeque::from([start.as_str()]);
+                while let Some(id) = queue.pop_front() {
+                    bfs.push(id.to_string());
+                    for &next in neighbors.get(id).into_iter().flatten() {
+                        if graph.nodes.contains_key(next) && visited.insert(next) {
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+            bfs
+        }
+    }
+}
+
+/// Fills in the placeholders of a format string for one node.
+fn format_id(format: &str, index: usize, node: &Node) -> Result<String, String> {
+    let mut id = String::new();
+    let mut rest = format;
+    while let Some(open) = rest.find('{') {
+        let close = rest[open..]
+            .find('}')
+            .map(|close| open + close)
+            .ok_or_else(|| format!("Unclosed {{ in relabel format \"{}\"", format))?;
+        id.push_str(&rest[..open]);
+        match &rest[open + 1..close] {
+            "index" => id.push_str(&index.to_string()),
+            "id" => id.push_str(&node.id),
+            "type" => id.push_str(&node.r#type),
+            name => match node.metadata.get(name) {
+                Some(value) => id.push_str(&value_text(value)),
+                None => return Err(format!("Node {} has no attribute {}", node.id, name)),
+            },
+        }
+        rest = &rest[close + 1..];
+    }
+    id.push_str(rest);
+    Ok(id)
+}
+
+impl Graph {
+    /// Renames nodes and updates the sources and targets of their edges.
+    ///
+    /// Edge ids are left as they are. The graph is unchanged if the new ids
+    /// are not unique, or if a format string refers to a missing attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::generators::generate_path;
+    /// use graph_generation_language::relabel::{NodeOrder, Relabeling};
+    /// use graph_generation_language::types::MetadataValue;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert("nodes".to_string(), MetadataValue::Integer(12));
+    /// let mut graph = generate_path(&params).unwrap();
+    ///
+    /// graph.relabel(&Relabeling::integers(NodeOrder::Id)).unwrap();
+    /// assert_eq!(graph.edges["e10_11"].source, "10");
+    ///
+    /// let format = Relabeling::Format {
+    ///     format: "v{index}".to_string(),
+    ///     order: NodeOrder::Degree,
+    /// };
+    /// graph.relabel(&format).unwrap();
+    /// assert_eq!(graph.edges["e0_1"].source, "v10");
+    /// assert_eq!(graph.edges["e0_1"].target, "v0");
+    /// ```
+    pub fn relabel(&mut self, relabeling: &Relabeling) -> Result<(), String> {
+        let renames: HashMap<String, String> = match relabeling {
+            Relabeling::Mapping(mapping) => {
+                if let Some(id) = mapping.keys().find(|id| !self.nodes.contains_key(*id)) {
+                    return Err(format!("Cannot relabel {}, there is no such node", id));
+                }
+                mapping.clone()
+            }
+            Relabeling::Format { format, order } => ordered_ids(self, *order)
+                .into_iter()
+                .enumerate()
+                .map(|(index, id)| {
+                    let new_id = format_id(format, index, &self.nodes[&id])?;
+                    Ok((id, new_id))
+                })
+                .collect::<Result<_, String>>()?,
+        };
+        let new_id = |id: &String| renames.get(id).unwrap_or(id).clone();
+
+        let mut ids: Vec<&String> = self.nodes.keys().collect();
+        ids.sort();
+        let mut taken: HashMap<String, &String> = HashMap::new();
+        for id in ids {
+            if let Some(other) = taken.insert(new_id(id), id) {
+                return Err(format!(
+                    "Cannot relabel both {} and {} to {}",
+                    other,
+                    id,
+                    new_id(id)
+                ));
+            }
+        }
+
+        self.nodes = std::mem::take(&mut self.nodes)
+            .into_values()
+            .map(|node| {
+                let id = new_id(&node.id);
+                (id.clone(), Node { id, ..node })
+            })
+            .collect();
+        for edge in self.edges.values_mut() {
+            edge.source = new_id(&edge.source);
+            edge.target = new_id(&edge.target);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Edge;
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut ids = vec!["n10", "n2", "n1", "b", "a10b2", "a10b10", "a9", "n02", ""];
+        ids.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            ids,
+            vec!["", "a9", "a10b2", "a10b10", "b", "n1", "n02", "n2", "n10"]
+        );
+    }
+
+    #[test]
+    fn test_bfs_order_visits_every_component() {
+        let mut graph = Graph::new();
+        for id in ["a", "b", "c", "d", "x", "y"] {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        for (u, v) in [("d", "a"), ("a", "c"), ("c", "b"), ("y", "x")] {
+            graph.add_edge(Edge::new(
+                format!("{}{}", u, v),
+                u.to_string(),
+                v.to_string(),
+            ));
+        }
+        assert_eq!(
+            ordered_ids(&graph, NodeOrder::Bfs),
+            vec!["a", "c", "d", "b", "x", "y"]
+        );
+    }
+}
@@ -153,11 +153,16 @@ impl Rule {
                 .get(&pattern_edge.target)
                 .ok_or_else(|| "Invalid target node in pattern".to_string())?;
 
+            // Sort edge IDs so that parallel edges are always matched in the same order
+            let mut edge_ids: Vec<_> = graph.edges.keys().collect();
+            edge_ids.sort();
+
             // Look for a matching edge in the graph
-            for (graph_edge_id, graph_edge) in &graph.edges {
+            for graph_edge_id in edge_ids {
                 if edge_mapping.values().any(|v| v == graph_edge_id) {
                     continue;
                 }
+                let graph_edge = &graph.edges[graph_edge_id];
 
                 // Since graph edges don't store directedness, we need to handle matching differently
                 // For undirected pattern edges, allow matching in either direction
@@ -243,12 +248,8 @@ impl Rule {
             };
 
             if let Some(mapped_id) = m.node_mapping.get(&node.id) {
-                // This node exists in LHS, update it
-                let existing_node = graph.get_node(mapped_id).unwrap().clone();
-                let mut updated_node = Node::new(mapped_id.clone())
-                    .with_type(existing_node.r#type.clone())
-                    .with_metadata_map(existing_node.metadata.clone())
-                    .with_position(existing_node.x, existing_node.y);
+                // This node exists in LHS, update it, keeping its subgraph and position
+                let mut updated_node = graph.get_node(mapped_id).unwrap().clone();
 
                 // Update type if specified in RHS
                 if let Some(ref node_type) = node.node_type {
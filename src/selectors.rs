@@ -0,0 +1,237 @@
+//! # Node Selectors
+//!
+//! Selectors stand for a set of nodes wherever a statement would otherwise name
+//! a single node. An edge declaration with a selector at either end connects
+//! every matching source to every matching target:
+//!
+//! ```ggl
+//! generate star { nodes: 8; prefix: "host"; }
+//! node core :switch;
+//!
+//! edge: host* -> core;                           // Glob on node ids
+//! edge: [type=server] -- [type=switch, zone=a];  // Types and attributes
+//! edge uplink: racks.* -> core [speed=100];       // Everything in a subgraph
+//! ```
+//!
+//! Nodes are never connected to themselves, and `--` connects each pair of
+//! nodes once even when both selectors match both of them, so
+//! `edge: h* -- h*;` builds a complete graph on the `h` nodes.
+//!
+//! ## Selector Kinds
+//!
+//! * `name` - A single node, named as in an ordinary edge declaration
+//! * `pattern*` - Nodes whose id matches the pattern, where `*` stands for
+//!   any run of characters, dots included
+//! * `[key=value, ...]` - Nodes with all the given attributes, where the
+//!   `type` key matches the node type
+//!
+//! Inside a `subgraph` block, globs and attribute selectors look at the
+//! innermost enclosing subgraph with a match first, like plain names do, and
+//! fall back to the whole graph.
+
+use crate::types::{Graph, MetadataValue, Node};
+use std::collections::HashMap;
+use std::fmt;
+
+/// The nodes at one end of an edge declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeSelector {
+    /// A single node, e.g. `core` or `cluster_a.gw`
+    Id(String),
+    /// Nodes whose id matches a glob, e.g. `hosts.*`
+    Glob(String),
+    /// Nodes with the given type and attributes, e.g. `[type=server, zone="a"]`
+    Attributes(HashMap<String, MetadataValue>),
+}
+
+impl fmt::Display for NodeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NodeSelector::Id(id) => write!(f, "{}", id),
+            NodeSelector::Glob(pattern) => write!(f, "{}", pattern),
+            NodeSelector::Attributes(attributes) => {
+                let mut entries: Vec<String> = attributes
+                    .iter()
+                    .map(|(key, value)| format!("{}={:?}", key, value))
+                    .collect();
+                entries.sort();
+                write!(f, "[{}]", entries.join(", "))
+            }
+        }
+    }
+}
+
+/// Returns whether `text` matches `pattern`, where `*` matches any run of characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::selectors::glob_match;
+///
+/// assert!(glob_match("hosts.*", "hosts.h0"));
+/// assert!(glob_match("n*_b*", "n1_b2"));
+/// assert!(!glob_match("n*", "hub"));
+/// ```
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    // Greedy matching that backtracks to the most recent star
+    let (mut p, mut t) = (0, 0);
+    let mut star = None;
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] != '*' && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Finds the node a name refers to from inside a subgraph.
+///
+/// The innermost enclosing subgraph with a matching node wins, so `gw`
+/// means the local gateway and `b.gw` that of a sibling `b`. Names that
+/// match nothing yet refer to the current subgraph.
+pub(crate) fn resolve(graph: &Graph, scope: &str, name: &str) -> String {
+    for path in enclosing(scope) {
+        let candidate = format!("{}.{}", path, name);
+        if graph.nodes.contains_key(&candidate) {
+            return candidate;
+        }
+    }
+    if graph.nodes.contains_key(name) {
+        return name.to_string();
+    }
+    format!("{}.{}", scope, name)
+}
+
+/// The subgraph path and those of its ancestors, innermost first.
+fn enclosing(scope: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(scope), |path| {
+        path.rsplit_once('.').map(|(outer, _)| outer)
+    })
+}
+
+impl NodeSelector {
+    /// Returns whether the node has every attribute of an attribute selector.
+    fn has_attributes(node: &Node, attributes: &HashMap<String, MetadataValue>) -> bool {
+        attributes.iter().all(|(key, value)| match key.as_str() {
+            "type" => matches!(value, MetadataValue::String(t) if *t == node.r#type),
+            _ => node.metadata.get(key) == Some(value),
+        })
+    }
+
+    /// Returns the sorted ids of the matching nodes among those whose id passes `within`.
+    fn matching(&self, graph: &Graph, within: impl Fn(&str) -> bool) -> Vec<String> {
+        let mut ids: Vec<String> = graph
+            .nodes
+            .values()
+            .filter(|node| within(&node.id))
+            .filter(|node| match self {
+                NodeSelector::Id(id) => node.id == *id,
+                NodeSelector::Glob(pattern) => glob_match(pattern, &node.id),
+                NodeSelector::Attributes(attributes) => Self::has_attributes(node, attributes),
+            })
+            .map(|node| node.id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Returns the sorted ids of the nodes the selector stands for.
+    ///
+    /// A single name always gives one id, even for a node that does not exist
+    /// yet, just like an ordinary edge declaration. Inside a subgraph, given as
+    /// `scope`, names and globs are relative to the innermost enclosing
+    /// subgraph with a match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::selectors::NodeSelector;
+    /// use graph_generation_language::types::{Graph, MetadataValue, Node};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut graph = Graph::new();
+    /// for id in ["n0", "n1", "hub"] {
+    ///     graph.add_node(Node::new(id.to_string()));
+    /// }
+    /// graph.nodes.get_mut("hub").unwrap().r#type = "switch".to_string();
+    ///
+    /// let hosts = NodeSelector::Glob("n*".to_string());
+    /// assert_eq!(hosts.select(&graph, None), vec!["n0", "n1"]);
+    ///
+    /// let mut attributes = HashMap::new();
+    /// attributes.insert("type".to_string(), MetadataValue::String("switch".to_string()));
+    /// let switches = NodeSelector::Attributes(attributes);
+    /// assert_eq!(switches.select(&graph, None), vec!["hub"]);
+    /// ```
+    pub fn select(&self, graph: &Graph, scope: Option<&str>) -> Vec<String> {
+        let Some(scope) = scope else {
+            return match self {
+                NodeSelector::Id(id) => vec![id.clone()],
+                _ => self.matching(graph, |_| true),
+            };
+        };
+
+        match self {
+            NodeSelector::Id(name) => vec![resolve(graph, scope, name)],
+            NodeSelector::Glob(pattern) => enclosing(scope)
+                .map(|path| NodeSelector::Glob(format!("{}.{}", path, pattern)))
+                .map(|relative| relative.matching(graph, |_| true))
+                .find(|ids| !ids.is_empty())
+                .unwrap_or_else(|| self.matching(graph, |_| true)),
+            NodeSelector::Attributes(_) => enclosing(scope)
+                .map(|path| {
+                    let prefix = format!("{}.", path);
+                    self.matching(graph, |id| id.starts_with(&prefix))
+                })
+                .find(|ids| !ids.is_empty())
+                .unwrap_or_else(|| self.matching(graph, |_| true)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*b*c", "aXbYc"));
+        assert!(glob_match("a*c", "abcbc"));
+        assert!(!glob_match("a*c", "abcb"));
+        assert!(!glob_match("a.*", "a"));
+        assert!(glob_match("**", "anything"));
+    }
+
+    #[test]
+    fn test_scoped_selection_prefers_innermost_match() {
+        let mut graph = Graph::new();
+        for id in ["dc.h0", "dc.rack.h0", "dc.rack.h1", "h9"] {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        let glob = NodeSelector::Glob("h*".to_string());
+        assert_eq!(
+            glob.select(&graph, Some("dc.rack")),
+            vec!["dc.rack.h0", "dc.rack.h1"]
+        );
+        assert_eq!(glob.select(&graph, Some("dc.other")), vec!["dc.h0"]);
+        assert_eq!(glob.select(&graph, None), vec!["h9"]);
+        assert_eq!(
+            NodeSelector::Id("h0".to_string()).select(&graph, Some("dc.other")),
+            vec!["dc.h0"]
+        );
+    }
+}
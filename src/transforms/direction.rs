@@ -0,0 +1,182 @@
+//! # Direction Transforms
+//!
+//! Transforms that change how edges are oriented: reversing arcs, and
+//! converting between the directed and undirected representations.
+
+use super::{add_new_edge, selected_edges, Selection};
+use crate::types::{Graph, MetadataValue};
+use std::collections::{HashMap, HashSet};
+
+/// Flips the direction of the selected edges.
+///
+/// Edges keep their ids, types and attributes; only the source and target
+/// are swapped.
+///
+/// # Parameters
+///
+/// * `edge_type`, `attribute`, `value` (optional) - The edges to reverse
+///   (default: all edges)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::transforms::reverse;
+/// use graph_generation_language::types::{Edge, Graph};
+/// use std::collections::HashMap;
+///
+/// let mut graph = Graph::new();
+/// graph.add_edge(Edge::new("e".to_string(), "a".to_string(), "b".to_string()));
+///
+/// reverse(&mut graph, &HashMap::new()).unwrap();
+/// assert_eq!(graph.edges["e"].source, "b");
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform reverse;
+/// transform reverse { edge_type: "depends_on"; }
+/// ```
+pub fn reverse(graph: &mut Graph, params: &HashMap<String, MetadataValue>) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    for id in selected_edges(graph, &selection) {
+        let edge = graph.edges.get_mut(&id).unwrap();
+        std::mem::swap(&mut edge.source, &mut edge.target);
+    }
+    Ok(())
+}
+
+/// Keeps a single edge for every pair of nodes joined by selected edges.
+///
+/// Arcs in both directions and parallel edges between the same two nodes are
+/// collapsed into the one with the smallest id, which keeps its direction,
+/// type and attributes. Unselected edges are left alone.
+///
+/// # Parameters
+///
+/// * `edge_type`, `attribute`, `value` (optional) - The edges to collapse
+///   (default: all edges)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_complete;
+/// use graph_generation_language::transforms::to_undirected;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(4));
+/// params.insert("directed".to_string(), MetadataValue::Boolean(true));
+/// let mut graph = generate_complete(&params).unwrap();
+/// assert_eq!(graph.edge_count(), 12);
+///
+/// to_undirected(&mut graph, &HashMap::new()).unwrap();
+/// assert_eq!(graph.edge_count(), 6);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform to_undirected;
+/// ```
+pub fn to_undirected(
+    graph: &mut Graph,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    let mut seen = HashSet::new();
+    for id in selected_edges(graph, &selection) {
+        let edge = &graph.edges[&id];
+        let pair = if edge.source <= edge.target {
+            (edge.source.clone(), edge.target.clone())
+        } else {
+            (edge.target.clone(), edge.source.clone())
+        };
+        if !seen.insert(pair) {
+            graph.edges.remove(&id);
+        }
+    }
+    Ok(())
+}
+
+/// Adds the opposite arc of every selected edge that lacks one.
+///
+/// An undirected edge `u -- v` thus becomes the pair of arcs `u -> v` and
+/// `v -> u`. Each new arc copies the type and attributes of its edge and is
+/// named `{id}_rev`. Self-loops are left as they are.
+///
+/// # Parameters
+///
+/// * `edge_type`, `attribute`, `value` (optional) - The edges to mirror
+///   (default: all edges)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_path;
+/// use graph_generation_language::transforms::to_directed;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+/// let mut graph = generate_path(&params).unwrap();
+///
+/// to_directed(&mut graph, &HashMap::new()).unwrap();
+/// assert_eq!(graph.edge_count(), 4);
+/// assert_eq!(graph.edges["e0_1_rev"].source, "n1");
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform to_directed { edge_type: "duplex"; }
+/// ```
+pub fn to_directed(
+    graph: &mut Graph,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    let arcs: HashSet<(String, String)> = graph
+        .edges
+        .values()
+        .map(|edge| (edge.source.clone(), edge.target.clone()))
+        .collect();
+
+    for id in selected_edges(graph, &selection) {
+        let edge = &graph.edges[&id];
+        if edge.source == edge.target || arcs.contains(&(edge.target.clone(), edge.source.clone()))
+        {
+            continue;
+        }
+        let mut opposite = edge.clone();
+        opposite.id = format!("{}_rev", id);
+        std::mem::swap(&mut opposite.source, &mut opposite.target);
+        add_new_edge(graph, opposite)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Edge;
+
+    #[test]
+    fn test_round_trip_through_directed() {
+        let mut graph = Graph::new();
+        for (id, u, v) in [("ab", "a", "b"), ("bc", "b", "c"), ("cb", "c", "b")] {
+            graph.add_edge(Edge::new(id.to_string(), u.to_string(), v.to_string()));
+        }
+
+        to_directed(&mut graph, &HashMap::new()).unwrap();
+        assert_eq!(graph.edge_count(), 4);
+        assert!(graph.edges.contains_key("ab_rev"));
+
+        to_undirected(&mut graph, &HashMap::new()).unwrap();
+        let mut ids: Vec<&String> = graph.edges.keys().collect();
+        ids.sort();
+        assert_eq!(ids, vec!["ab", "bc"]);
+    }
+}
@@ -0,0 +1,306 @@
+//! # Graph Transforms
+//!
+//! This module provides built-in operations that rewrite the current graph as a
+//! whole. Where rules match local patterns, transforms see the entire graph, so
+//! they can express global structure such as spanning trees. Transforms are
+//! invoked using the `transform` statement in GGL programs.
+//!
+//! ## Available Transforms
+//!
+//! * [`spanning_tree`] - Replaces the edges with a random or minimum spanning tree
+//! * [`complement`] - Connects exactly the pairs of nodes that were not adjacent
+//! * [`line_graph`] - Turns every edge into a node, adjacent when the edges meet
+//! * [`graph_power`] - Connects nodes within `k` hops of each other
+//! * [`reverse`] - Flips the direction of edges
+//! * [`to_undirected`] - Keeps one edge per connected pair of nodes
+//! * [`to_directed`] - Adds the missing opposite of every edge
+//! * [`subdivide`] - Replaces edges with paths through new nodes
+//! * [`contract`] - Merges the endpoints of edges into one node
+//!
+//! ## Usage in GGL
+//!
+//! Transforms apply to everything declared or generated before them:
+//! ```ggl
+//! generate grid {
+//!     rows: 20;
+//!     cols: 20;
+//! }
+//!
+//! transform spanning_tree {
+//!     algorithm: "wilson";
+//! }
+//! ```
+//!
+//! A transform without parameters may be written `transform name;`. Like
+//! generators, random transforms accept a `seed` parameter and otherwise draw
+//! their seed from the engine's random number generator. Parameters are
+//! checked like those of generators, so a misspelt or unknown name is an error;
+//! [`transform_params`] lists the ones each transform accepts.
+//!
+//! ## Selections
+//!
+//! Most transforms can be limited to part of the graph. Edge transforms such as
+//! `reverse` apply to the edges matching `edge_type`, while node transforms
+//! such as `complement` apply to the nodes matching `node_type`. An `attribute`
+//! parameter further requires the element to carry that attribute, equal to
+//! `value` when one is given:
+//! ```ggl
+//! transform contract { edge_type: "bond"; }
+//! transform reverse { attribute: "direction"; value: "down"; }
+//! transform complement { node_type: "server"; }
+//! ```
+//!
+//! ## Identifiers
+//!
+//! New edges are named after their endpoints, e.g. `e{u}_{v}`. A transform
+//! fails rather than replace an existing edge that has the same id, which can
+//! happen when node ids contain `_`.
+//!
+//! ## Direction
+//!
+//! Edges have no direction flag of their own. A directed graph stores every
+//! arc as a separate edge from its source to its target, while an undirected
+//! graph stores one edge per connected pair. Transforms treat edges as
+//! undirected unless they take a `directed` parameter or, like `reverse`, only
+//! make sense for arcs.
+
+use crate::generators::{ParamSpec, ParamType};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::HashMap;
+
+mod direction;
+mod spanning_tree;
+mod structural;
+mod subdivision;
+
+pub use direction::{reverse, to_directed, to_undirected};
+pub use spanning_tree::spanning_tree;
+pub use structural::{complement, graph_power, line_graph};
+pub use subdivision::{contract, subdivide};
+
+/// Function signature for graph transform functions.
+///
+/// All transforms take the graph to modify in place and a parameter map, and
+/// return an error message if the parameters are invalid.
+pub type TransformFn = fn(&mut Graph, &HashMap<String, MetadataValue>) -> Result<(), String>;
+
+/// Returns the transform function for the given name.
+///
+/// # Available Transforms
+///
+/// * `"spanning_tree"` - Spanning tree (maze) transform
+/// * `"complement"` - Complement graph
+/// * `"line_graph"` - Line graph
+/// * `"graph_power"` - k-th power of the graph
+/// * `"reverse"` - Reversed edges
+/// * `"to_undirected"` - Undirected version of the graph
+/// * `"to_directed"` - Directed version of the graph
+/// * `"subdivide"` - Edge subdivision
+/// * `"contract"` - Edge contraction
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::transforms::get_transform;
+///
+/// assert!(get_transform("spanning_tree").is_some());
+/// assert!(get_transform("unknown").is_none());
+/// ```
+pub fn get_transform(name: &str) -> Option<TransformFn> {
+    match name {
+        "spanning_tree" => Some(spanning_tree),
+        "complement" => Some(complement),
+        "line_graph" => Some(line_graph),
+        "graph_power" => Some(graph_power),
+        "reverse" => Some(reverse),
+        "to_undirected" => Some(to_undirected),
+        "to_directed" => Some(to_directed),
+        "subdivide" => Some(subdivide),
+        "contract" => Some(contract),
+        _ => None,
+    }
+}
+
+/// Returns the parameters the given transform accepts.
+///
+/// The engine checks the parameters of a `transform` statement against these
+/// specs before running it, like those of a `generate` statement.
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::transforms::transform_params;
+///
+/// let specs = transform_params("graph_power").unwrap();
+/// assert!(specs.iter().any(|spec| spec.name == "k"));
+/// assert!(transform_params("unknown").is_none());
+/// ```
+pub fn transform_params(name: &str) -> Option<Vec<ParamSpec>> {
+    let specs = match name {
+        "spanning_tree" => vec![
+            ParamSpec::new("algorithm", ParamType::String, "wilson, dfs or minimum")
+                .with_default(MetadataValue::String("wilson".to_string())),
+            ParamSpec::new("root", ParamType::String, "Node to grow the tree from"),
+            ParamSpec::new(
+                "weight",
+                ParamType::String,
+                "Edge attribute holding the weight",
+            )
+            .with_default(MetadataValue::String("weight".to_string())),
+            ParamSpec::new("seed", ParamType::Integer, "Random seed"),
+        ],
+        "complement" => with_selection(
+            "node_type",
+            vec![directed_param("Complement the arcs instead")],
+        ),
+        "line_graph" => with_selection(
+            "edge_type",
+            vec![directed_param("Connect edges only head to tail")],
+        ),
+        "graph_power" => with_selection(
+            "node_type",
+            vec![ParamSpec::new("k", ParamType::Integer, "The power")
+                .with_default(MetadataValue::Integer(2))
+                .with_min(1.0)],
+        ),
+        "subdivide" => with_selection(
+            "edge_type",
+            vec![
+                ParamSpec::new(
+                    "nodes",
+                    ParamType::Integer,
+                    "Nodes to insert into each edge",
+                )
+                .with_default(MetadataValue::Integer(1))
+                .with_min(1.0),
+                ParamSpec::new("node_type", ParamType::String, "Type of the inserted nodes"),
+            ],
+        ),
+        "reverse" | "to_undirected" | "to_directed" | "contract" => {
+            with_selection("edge_type", Vec::new())
+        }
+        _ => return None,
+    };
+    Some(specs)
+}
+
+/// Adds the parameters read by [`Selection::from_params`], selecting by `type_param`.
+fn with_selection(type_param: &str, mut specs: Vec<ParamSpec>) -> Vec<ParamSpec> {
+    specs.extend([
+        ParamSpec::new(
+            type_param,
+            ParamType::String,
+            "Type of the elements to apply to",
+        ),
+        ParamSpec::new(
+            "attribute",
+            ParamType::String,
+            "Attribute the elements must carry",
+        ),
+        ParamSpec::new("value", ParamType::Any, "Value the attribute must have"),
+    ]);
+    specs
+}
+
+fn directed_param(description: &str) -> ParamSpec {
+    ParamSpec::new("directed", ParamType::Boolean, description)
+        .with_default(MetadataValue::Boolean(false))
+}
+
+/// The nodes or edges a transform applies to, read from its parameters.
+///
+/// An empty selection matches everything.
+#[derive(Debug, Default)]
+pub(crate) struct Selection {
+    node_type: Option<String>,
+    edge_type: Option<String>,
+    attribute: Option<String>,
+    value: Option<MetadataValue>,
+}
+
+impl Selection {
+    /// Reads the `node_type`, `edge_type`, `attribute` and `value` parameters.
+    pub(crate) fn from_params(params: &HashMap<String, MetadataValue>) -> Result<Self, String> {
+        let string = |key: &str| match params.get(key) {
+            Some(MetadataValue::String(s)) => Ok(Some(s.clone())),
+            Some(other) => Err(format!("{} must be a string, got {:?}", key, other)),
+            None => Ok(None),
+        };
+        let selection = Selection {
+            node_type: string("node_type")?,
+            edge_type: string("edge_type")?,
+            attribute: string("attribute")?,
+            value: params.get("value").cloned(),
+        };
+        if selection.value.is_some() && selection.attribute.is_none() {
+            return Err("value needs an attribute to compare with".to_string());
+        }
+        Ok(selection)
+    }
+
+    fn has_attribute(&self, metadata: &HashMap<String, MetadataValue>) -> bool {
+        match &self.attribute {
+            Some(attribute) => match (metadata.get(attribute), &self.value) {
+                (Some(found), Some(value)) => found == value,
+                (found, None) => found.is_some(),
+                (None, Some(_)) => false,
+            },
+            None => true,
+        }
+    }
+
+    /// Returns whether a node transform applies to the node.
+    pub(crate) fn node(&self, node: &Node) -> bool {
+        self.node_type
+            .iter()
+            .all(|node_type| &node.r#type == node_type)
+            && self.has_attribute(&node.metadata)
+    }
+
+    /// Returns whether an edge transform applies to the edge.
+    pub(crate) fn edge(&self, edge: &Edge) -> bool {
+        self.edge_type
+            .iter()
+            .all(|edge_type| &edge.r#type == edge_type)
+            && self.has_attribute(&edge.metadata)
+    }
+}
+
+/// Adds an edge, failing instead of replacing an edge with the same id.
+///
+/// Edges named after their endpoints can clash when node ids contain `_`, as
+/// `ea_b_c` joins both `a_b` to `c` and `a` to `b_c`.
+pub(crate) fn add_new_edge(graph: &mut Graph, edge: Edge) -> Result<(), String> {
+    if graph.edges.contains_key(&edge.id) {
+        return Err(format!(
+            "cannot add {}, an edge with that id exists",
+            edge.id
+        ));
+    }
+    graph.add_edge(edge);
+    Ok(())
+}
+
+/// The ids of the selected nodes, sorted.
+pub(crate) fn selected_nodes<'a>(graph: &'a Graph, selection: &Selection) -> Vec<&'a str> {
+    let mut ids: Vec<&str> = graph
+        .nodes
+        .values()
+        .filter(|node| selection.node(node))
+        .map(|node| node.id.as_str())
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// The ids of the selected edges, sorted.
+pub(crate) fn selected_edges(graph: &Graph, selection: &Selection) -> Vec<String> {
+    let mut ids: Vec<String> = graph
+        .edges
+        .values()
+        .filter(|edge| selection.edge(edge))
+        .map(|edge| edge.id.clone())
+        .collect();
+    ids.sort();
+    ids
+}
@@ -0,0 +1,331 @@
+//! # Spanning Trees
+//!
+//! Reduces a graph to a spanning tree of itself, which turns a grid into a
+//! maze and a dense network into a backbone.
+
+use crate::generators::{get_param_string, get_rng};
+use crate::types::{Graph, MetadataValue};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet};
+
+/// The graph as undirected adjacency lists over node indices.
+///
+/// Nodes and edges are indexed in sorted id order so that a seeded transform
+/// gives the same tree on every run, whatever the map iteration order.
+struct Adjacency<'a> {
+    /// Edge ids with their endpoint indices; self-loops and dangling edges are left out
+    edges: Vec<(&'a str, usize, usize)>,
+    /// `(neighbour, edge index)` pairs for every node
+    neighbours: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a> Adjacency<'a> {
+    fn new(graph: &'a Graph, ids: &[&'a str]) -> Self {
+        let index: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut edge_ids: Vec<&String> = graph.edges.keys().collect();
+        edge_ids.sort();
+
+        let mut edges = Vec::new();
+        let mut neighbours = vec![Vec::new(); ids.len()];
+        for id in edge_ids {
+            let edge = &graph.edges[id];
+            if let (Some(&u), Some(&v)) = (
+                index.get(edge.source.as_str()),
+                index.get(edge.target.as_str()),
+            ) {
+                if u != v {
+                    neighbours[u].push((v, edges.len()));
+                    neighbours[v].push((u, edges.len()));
+                    edges.push((id.as_str(), u, v));
+                }
+            }
+        }
+        Adjacency { edges, neighbours }
+    }
+
+    /// Labels each node with the index of the first node of its component.
+    fn components(&self, roots: &[usize]) -> Vec<usize> {
+        let mut component = vec![usize::MAX; self.neighbours.len()];
+        for &root in roots {
+            if component[root] != usize::MAX {
+                continue;
+            }
+            component[root] = root;
+            let mut stack = vec![root];
+            while let Some(u) = stack.pop() {
+                for &(v, _) in &self.neighbours[u] {
+                    if component[v] == usize::MAX {
+                        component[v] = root;
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+        component
+    }
+}
+
+/// Wilson's algorithm: loop-erased random walks from every node to the growing tree.
+fn wilson(adjacency: &Adjacency, roots: &[usize], rng: &mut SmallRng) -> Vec<usize> {
+    let n = adjacency.neighbours.len();
+    let component = adjacency.components(roots);
+    let mut in_tree: Vec<bool> = (0..n).map(|u| component[u] == u).collect();
+    let mut next = vec![(0, 0); n];
+    let mut tree = Vec::with_capacity(n);
+
+    for start in 0..n {
+        // Overwriting the exit of revisited nodes erases the loops of the walk
+        let mut u = start;
+        while !in_tree[u] {
+            next[u] = *adjacency.neighbours[u].choose(rng).unwrap();
+            u = next[u].0;
+        }
+        u = start;
+        while !in_tree[u] {
+            in_tree[u] = true;
+            tree.push(next[u].1);
+            u = next[u].0;
+        }
+    }
+    tree
+}
+
+/// Randomised depth-first search, the "recursive backtracker" maze algorithm.
+fn random_dfs(adjacency: &Adjacency, roots: &[usize], rng: &mut SmallRng) -> Vec<usize> {
+    let mut visited = vec![false; adjacency.neighbours.len()];
+    let mut tree = Vec::new();
+
+    for &root in roots {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![root];
+        while let Some(&u) = stack.last() {
+            let unvisited: Vec<(usize, usize)> = adjacency.neighbours[u]
+                .iter()
+                .filter(|&&(v, _)| !visited[v])
+                .copied()
+                .collect();
+            match unvisited.choose(rng) {
+                Some(&(v, edge)) => {
+                    visited[v] = true;
+                    tree.push(edge);
+                    stack.push(v);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+    tree
+}
+
+/// Kruskal's algorithm over edges ordered by weight, ties broken by edge id.
+fn minimum(adjacency: &Adjacency, weights: &[f64]) -> Vec<usize> {
+    fn find(parent: &mut [usize], mut u: usize) -> usize {
+        while parent[u] != u {
+            parent[u] = parent[parent[u]];
+            u = parent[u];
+        }
+        u
+    }
+
+    let mut order: Vec<usize> = (0..adjacency.edges.len()).collect();
+    order.sort_by(|&a, &b| weights[a].total_cmp(&weights[b]));
+
+    let mut parent: Vec<usize> = (0..adjacency.neighbours.len()).collect();
+    let mut tree = Vec::new();
+    for edge in order {
+        let (_, u, v) = adjacency.edges[edge];
+        let (ru, rv) = (find(&mut parent, u), find(&mut parent, v));
+        if ru != rv {
+            parent[ru] = rv;
+            tree.push(edge);
+        }
+    }
+    tree
+}
+
+/// Replaces the edges of the graph with a spanning tree.
+///
+/// Edge direction is ignored while building the tree, and the kept edges retain
+/// their ids, direction, types and attributes. A disconnected graph becomes a
+/// spanning forest with one tree per component. Self-loops, parallel edges that
+/// are not chosen, and edges to undeclared nodes are removed.
+///
+/// # Algorithms
+///
+/// * `"wilson"` - A uniformly random spanning tree, sampled with Wilson's
+///   loop-erased random walks. Mazes built this way have many short dead ends.
+/// * `"dfs"` - A random depth-first search tree. Mazes built this way have long,
+///   winding corridors.
+/// * `"minimum"` - A minimum spanning tree by the numeric `weight` attribute.
+///   Every edge must carry the attribute; ties are broken by edge id.
+///
+/// # Parameters
+///
+/// * `algorithm` (optional) - "wilson", "dfs" or "minimum" (default: "wilson")
+/// * `root` (optional) - Node to grow the tree from (default: the smallest node id)
+/// * `weight` (optional) - Edge attribute holding the weight (default: "weight")
+/// * `seed` (optional) - Seed for reproducible output
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_grid;
+/// use graph_generation_language::transforms::spanning_tree;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("rows".to_string(), MetadataValue::Integer(5));
+/// params.insert("cols".to_string(), MetadataValue::Integer(5));
+/// let mut maze = generate_grid(&params).unwrap();
+///
+/// let mut params = HashMap::new();
+/// params.insert("seed".to_string(), MetadataValue::Integer(7));
+/// spanning_tree(&mut maze, &params).unwrap();
+/// assert_eq!(maze.edge_count(), 24);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// generate grid {
+///     rows: 30;
+///     cols: 30;
+/// }
+///
+/// transform spanning_tree {
+///     algorithm: "dfs";
+///     seed: 5;
+/// }
+/// ```
+///
+/// # Use Cases
+///
+/// * Maze generation on grids and lattices
+/// * Backbone and broadcast trees of existing networks
+/// * Uniform spanning tree sampling for statistical physics
+pub fn spanning_tree(
+    graph: &mut Graph,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<(), String> {
+    let algorithm = get_param_string(params, "algorithm", "wilson");
+    let weight_key = get_param_string(params, "weight", "weight");
+    let mut rng = get_rng(params)?;
+
+    let mut ids: Vec<&str> = graph.nodes.keys().map(String::as_str).collect();
+    ids.sort();
+
+    // The root comes first, then every other node starts its own tree if unreached
+    let mut roots: Vec<usize> = (0..ids.len()).collect();
+    match params.get("root") {
+        Some(MetadataValue::String(root)) => {
+            let index = ids
+                .binary_search(&root.as_str())
+                .map_err(|_| format!("root {} is not a node of the graph", root))?;
+            roots.swap(0, index);
+        }
+        Some(other) => return Err(format!("root must be a node id, got {:?}", other)),
+        None => {}
+    }
+
+    let adjacency = Adjacency::new(graph, &ids);
+    let tree = match algorithm.as_str() {
+        "wilson" => wilson(&adjacency, &roots, &mut rng),
+        "dfs" => random_dfs(&adjacency, &roots, &mut rng),
+        "minimum" => {
+            let weights = adjacency
+                .edges
+                .iter()
+                .map(
+                    |&(id, _, _)| match graph.edges[id].metadata.get(&weight_key) {
+                        Some(MetadataValue::Float(w)) => Ok(*w),
+                        Some(MetadataValue::Integer(w)) => Ok(*w as f64),
+                        _ => Err(format!(
+                            "edge {} has no numeric {} attribute",
+                            id, weight_key
+                        )),
+                    },
+                )
+                .collect::<Result<Vec<_>, _>>()?;
+            minimum(&adjacency, &weights)
+        }
+        other => {
+            return Err(format!(
+                "Unknown algorithm {}, expected one of wilson, dfs, minimum",
+                other
+            ))
+        }
+    };
+
+    let kept: HashSet<String> = tree
+        .into_iter()
+        .map(|edge| adjacency.edges[edge].0.to_string())
+        .collect();
+    graph.edges.retain(|id, _| kept.contains(id));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Edge, Node};
+
+    fn square_with_diagonal() -> Graph {
+        let mut graph = Graph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        for (id, u, v, w) in [
+            ("ab", "a", "b", 1),
+            ("bc", "b", "c", 4),
+            ("cd", "c", "d", 2),
+            ("da", "d", "a", 5),
+            ("ac", "a", "c", 3),
+            ("loop", "a", "a", 0),
+        ] {
+            graph.add_edge(
+                Edge::new(id.to_string(), u.to_string(), v.to_string())
+                    .with_metadata("weight".to_string(), MetadataValue::Integer(w)),
+            );
+        }
+        graph
+    }
+
+    #[test]
+    fn test_components_label_by_first_root() {
+        let mut graph = square_with_diagonal();
+        graph.add_node(Node::new("e".to_string()));
+        let ids: Vec<&str> = ["a", "b", "c", "d", "e"].to_vec();
+        let adjacency = Adjacency::new(&graph, &ids);
+        assert_eq!(adjacency.edges.len(), 5);
+        assert_eq!(adjacency.components(&[2, 0, 1, 3, 4]), vec![2, 2, 2, 2, 4]);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let graph = square_with_diagonal();
+        let ids: Vec<&str> = ["a", "b", "c", "d"].to_vec();
+        let adjacency = Adjacency::new(&graph, &ids);
+        let weights: Vec<f64> = adjacency
+            .edges
+            .iter()
+            .map(|&(id, _, _)| match graph.edges[id].metadata["weight"] {
+                MetadataValue::Integer(w) => w as f64,
+                _ => unreachable!(),
+            })
+            .collect();
+        let mut tree: Vec<&str> = minimum(&adjacency, &weights)
+            .into_iter()
+            .map(|edge| adjacency.edges[edge].0)
+            .collect();
+        tree.sort();
+        assert_eq!(tree, vec!["ab", "ac", "cd"]);
+    }
+}
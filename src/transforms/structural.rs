@@ -0,0 +1,374 @@
+//! # Structural Transforms
+//!
+//! Transforms that derive a new adjacency from the existing one: the
+//! complement, the line graph and graph powers.
+
+use super::{add_new_edge, selected_edges, selected_nodes, Selection};
+use crate::generators::{get_param_bool, get_param_int_or};
+use crate::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// The pair of node ids in sorted order.
+fn unordered<'a>(u: &'a str, v: &'a str) -> (&'a str, &'a str) {
+    if u <= v {
+        (u, v)
+    } else {
+        (v, u)
+    }
+}
+
+/// Replaces the edges among the selected nodes with those of the complement graph.
+///
+/// Two selected nodes are adjacent afterwards exactly when they were not
+/// adjacent before. Edges with an unselected endpoint are kept, and
+/// self-loops among the selected nodes are removed. The new edges are named
+/// `e{u}_{v}` and have no type or attributes.
+///
+/// # Parameters
+///
+/// * `directed` (optional) - Complement the arcs instead, adding `u -> v`
+///   for every ordered pair without one (default: false)
+/// * `node_type`, `attribute`, `value` (optional) - The nodes to complement
+///   (default: all nodes)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_cycle;
+/// use graph_generation_language::transforms::complement;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(5));
+/// let mut graph = generate_cycle(&params).unwrap();
+///
+/// // The complement of a 5-cycle is another 5-cycle
+/// complement(&mut graph, &HashMap::new()).unwrap();
+/// assert_eq!(graph.edge_count(), 5);
+/// assert!(graph.edges.contains_key("en0_n2"));
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform complement;
+/// transform complement { node_type: "server"; directed: true; }
+/// ```
+pub fn complement(
+    graph: &mut Graph,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    let directed = get_param_bool(params, "directed", false);
+
+    let nodes: Vec<String> = selected_nodes(graph, &selection)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let selected: HashSet<&str> = nodes.iter().map(String::as_str).collect();
+
+    let mut adjacent = HashSet::new();
+    let mut removed = Vec::new();
+    for edge in graph.edges.values() {
+        if selected.contains(edge.source.as_str()) && selected.contains(edge.target.as_str()) {
+            removed.push(edge.id.clone());
+            let pair = if directed {
+                (edge.source.clone(), edge.target.clone())
+            } else {
+                let (u, v) = unordered(&edge.source, &edge.target);
+                (u.to_string(), v.to_string())
+            };
+            adjacent.insert(pair);
+        }
+    }
+    for id in removed {
+        graph.edges.remove(&id);
+    }
+
+    for (i, u) in nodes.iter().enumerate() {
+        for (j, v) in nodes.iter().enumerate() {
+            if i == j || (!directed && j < i) {
+                continue;
+            }
+            if !adjacent.contains(&(u.clone(), v.clone())) {
+                add_new_edge(
+                    graph,
+                    Edge::new(format!("e{}_{}", u, v), u.clone(), v.clone()),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the graph with its line graph.
+///
+/// Every selected edge becomes a node with the same id, type and attributes,
+/// plus `source` and `target` attributes naming its endpoints, placed midway
+/// between them. Two such nodes are adjacent when their edges share an
+/// endpoint. The original nodes and unselected edges are removed.
+///
+/// # Parameters
+///
+/// * `directed` (optional) - Connect `a -> b` only when edge `a` ends where
+///   edge `b` starts (default: false)
+/// * `edge_type`, `attribute`, `value` (optional) - The edges that become
+///   nodes (default: all edges)
+///
+/// # Properties
+///
+/// * **Nodes**: |E|
+/// * **Edges**: Σ deg(v)(deg(v) - 1) / 2 for simple undirected graphs
+/// * **Examples**: the line graph of a star is a complete graph, and that of
+///   a cycle is a cycle of the same length
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_star;
+/// use graph_generation_language::transforms::line_graph;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(5));
+/// let mut graph = generate_star(&params).unwrap();
+///
+/// line_graph(&mut graph, &HashMap::new()).unwrap();
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 6);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform line_graph;
+/// transform line_graph { edge_type: "road"; directed: true; }
+/// ```
+pub fn line_graph(
+    graph: &mut Graph,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    let directed = get_param_bool(params, "directed", false);
+
+    let edges: Vec<&Edge> = selected_edges(graph, &selection)
+        .iter()
+        .map(|id| &graph.edges[id])
+        .filter(|edge| {
+            graph.nodes.contains_key(&edge.source) && graph.nodes.contains_key(&edge.target)
+        })
+        .collect();
+
+    let mut line = Graph::new();
+    // Edges leaving and entering each node, by index into `edges`
+    let mut outgoing: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut incoming: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, edge) in edges.iter().enumerate() {
+        let (source, target) = (&graph.nodes[&edge.source], &graph.nodes[&edge.target]);
+        line.add_node(
+            Node::new(edge.id.clone())
+                .with_type(edge.r#type.clone())
+                .with_metadata_map(edge.metadata.clone())
+                .with_metadata(
+                    "source".to_string(),
+                    MetadataValue::String(edge.source.clone()),
+                )
+                .with_metadata(
+                    "target".to_string(),
+                    MetadataValue::String(edge.target.clone()),
+                )
+                .with_position((source.x + target.x) / 2.0, (source.y + target.y) / 2.0),
+        );
+        outgoing.entry(&edge.source).or_default().push(i);
+        incoming.entry(&edge.target).or_default().push(i);
+    }
+
+    let mut adjacent = BTreeSet::new();
+    for id in graph.nodes.keys() {
+        let leaving = outgoing.get(id.as_str()).map_or(&[][..], Vec::as_slice);
+        let entering = incoming.get(id.as_str()).map_or(&[][..], Vec::as_slice);
+        if directed {
+            for &a in entering {
+                for &b in leaving {
+                    if a != b {
+                        adjacent.insert((a, b));
+                    }
+                }
+            }
+        } else {
+            let mut incident: Vec<usize> = leaving.iter().chain(entering).copied().collect();
+            incident.sort();
+            incident.dedup();
+            for (k, &a) in incident.iter().enumerate() {
+                for &b in &incident[k + 1..] {
+                    adjacent.insert((a, b));
+                }
+            }
+        }
+    }
+    for (a, b) in adjacent {
+        let (a, b) = (&edges[a].id, &edges[b].id);
+        add_new_edge(
+            &mut line,
+            Edge::new(format!("e{}_{}", a, b), a.clone(), b.clone()),
+        )?;
+    }
+
+    *graph = line;
+    Ok(())
+}
+
+/// Connects every pair of selected nodes within `k` hops of each other.
+///
+/// Distances are measured over the whole graph with edges taken as
+/// undirected. Existing edges are kept, and each new edge is named
+/// `e{u}_{v}` and carries the `distance` between its endpoints.
+///
+/// # Parameters
+///
+/// * `k` (optional) - The power, at least 1 (default: 2)
+/// * `node_type`, `attribute`, `value` (optional) - The nodes to connect
+///   (default: all nodes)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_path;
+/// use graph_generation_language::transforms::graph_power;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(5));
+/// let mut graph = generate_path(&params).unwrap();
+///
+/// let mut params = HashMap::new();
+/// params.insert("k".to_string(), MetadataValue::Integer(2));
+/// graph_power(&mut graph, &params).unwrap();
+/// assert_eq!(graph.edge_count(), 4 + 3);
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform graph_power { k: 3; }
+/// ```
+pub fn graph_power(
+    graph: &mut Graph,
+    params: &HashMap<String, MetadataValue>,
+) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    let k = get_param_int_or(params, "k", 2)?;
+    if k == 0 {
+        return Err("k must be at least 1".to_string());
+    }
+
+    let mut neighbours: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in graph.edges.values() {
+        if edge.source != edge.target
+            && graph.nodes.contains_key(&edge.source)
+            && graph.nodes.contains_key(&edge.target)
+        {
+            neighbours
+                .entry(&edge.source)
+                .or_default()
+                .push(&edge.target);
+            neighbours
+                .entry(&edge.target)
+                .or_default()
+                .push(&edge.source);
+        }
+    }
+
+    let nodes = selected_nodes(graph, &selection);
+    let selected: HashSet<&str> = nodes.iter().copied().collect();
+    let mut added = Vec::new();
+    for &start in &nodes {
+        let mut distance = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(u) = queue.pop_front() {
+            let d = distance[u];
+            if d == k {
+                continue;
+            }
+            for &v in neighbours.get(u).into_iter().flatten() {
+                if !distance.contains_key(v) {
+                    distance.insert(v, d + 1);
+                    queue.push_back(v);
+                }
+            }
+        }
+        for (v, d) in distance {
+            if d >= 2 && start < v && selected.contains(v) {
+                added.push((start.to_string(), v.to_string(), d));
+            }
+        }
+    }
+
+    for (u, v, d) in added {
+        add_new_edge(
+            graph,
+            Edge::new(format!("e{}_{}", u, v), u, v)
+                .with_metadata("distance".to_string(), MetadataValue::Integer(d as i64)),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(ids: &[&str]) -> Graph {
+        let mut graph = Graph::new();
+        for id in ids {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        for pair in ids.windows(2) {
+            graph.add_edge(Edge::new(
+                format!("{}{}", pair[0], pair[1]),
+                pair[0].to_string(),
+                pair[1].to_string(),
+            ));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_directed_line_graph_follows_arcs() {
+        let mut graph = path(&["a", "b", "c"]);
+        let mut params = HashMap::new();
+        params.insert("directed".to_string(), MetadataValue::Boolean(true));
+        line_graph(&mut graph, &params).unwrap();
+
+        assert_eq!(graph.edge_count(), 1);
+        let edge = graph.edges.get("eab_bc").unwrap();
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("ab", "bc"));
+    }
+
+    #[test]
+    fn test_graph_power_distances() {
+        let mut graph = path(&["a", "b", "c", "d"]);
+        let mut params = HashMap::new();
+        params.insert("k".to_string(), MetadataValue::Integer(3));
+        graph_power(&mut graph, &params).unwrap();
+
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(
+            graph.edges["ea_d"].metadata.get("distance"),
+            Some(&MetadataValue::Integer(3))
+        );
+    }
+
+    #[test]
+    fn test_complement_rejects_clashing_edge_ids() {
+        let mut graph = Graph::new();
+        for id in ["a_b", "c", "a", "b_c"] {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        let error = complement(&mut graph, &HashMap::new()).unwrap_err();
+        assert!(error.contains("ea_b_c"), "{}", error);
+    }
+}
@@ -0,0 +1,237 @@
+//! # Subdivision and Contraction
+//!
+//! Transforms that refine or coarsen a graph edge by edge: subdividing edges
+//! into paths, and contracting edges into single nodes.
+
+use super::{add_new_edge, selected_edges, Selection};
+use crate::generators::{get_param_int_or, get_param_string};
+use crate::types::{Graph, MetadataValue, Node};
+use std::collections::{HashMap, HashSet};
+
+/// Replaces each selected edge with a path through new nodes.
+///
+/// An edge `id` from `u` to `v` becomes the path `u -> id_1 -> … -> id_k -> v`,
+/// with the new nodes spaced evenly between the positions of `u` and `v`.
+/// The new edges follow the direction of the original, copy its type and
+/// attributes, and are named `e{source}_{target}` after their endpoints.
+/// Self-loops and edges to undeclared nodes are left as they are.
+///
+/// # Parameters
+///
+/// * `nodes` (optional) - Nodes to insert into each edge (default: 1)
+/// * `node_type` (optional) - Type of the inserted nodes (default: none)
+/// * `edge_type`, `attribute`, `value` (optional) - The edges to subdivide
+///   (default: all edges)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_cycle;
+/// use graph_generation_language::transforms::subdivide;
+/// use graph_generation_language::types::MetadataValue;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(3));
+/// let mut graph = generate_cycle(&params).unwrap();
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(2));
+/// subdivide(&mut graph, &params).unwrap();
+/// assert_eq!(graph.node_count(), 9);
+/// assert_eq!(graph.edge_count(), 9);
+/// assert!(graph.get_node("e0_1_2").is_some());
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// // Put a repeater in the middle of every long link
+/// transform subdivide { edge_type: "long"; node_type: "repeater"; }
+/// ```
+pub fn subdivide(graph: &mut Graph, params: &HashMap<String, MetadataValue>) -> Result<(), String> {
+    let selection = Selection::from_params(params)?;
+    let count = get_param_int_or(params, "nodes", 1)?;
+    let node_type = get_param_string(params, "node_type", "");
+    if count == 0 {
+        return Err("nodes must be at least 1".to_string());
+    }
+
+    for id in selected_edges(graph, &selection) {
+        let edge = graph.edges[&id].clone();
+        let (Some(source), Some(target)) = (
+            graph.get_node(&edge.source).cloned(),
+            graph.get_node(&edge.target).cloned(),
+        ) else {
+            continue;
+        };
+        if edge.source == edge.target {
+            continue;
+        }
+
+        let mut path = vec![edge.source.clone()];
+        for i in 1..=count {
+            let node_id = format!("{}_{}", id, i);
+            if graph.nodes.contains_key(&node_id) {
+                return Err(format!(
+                    "cannot add {}, a node with that id exists",
+                    node_id
+                ));
+            }
+            let t = i as f64 / (count + 1) as f64;
+            graph.add_node(
+                Node::new(node_id.clone())
+                    .with_type(node_type.clone())
+                    .with_position(
+                        source.x + t * (target.x - source.x),
+                        source.y + t * (target.y - source.y),
+                    ),
+            );
+            path.push(node_id);
+        }
+        path.push(edge.target.clone());
+
+        graph.edges.remove(&id);
+        for pair in path.windows(2) {
+            let mut segment = edge.clone();
+            segment.id = format!("e{}_{}", pair[0], pair[1]);
+            segment.source = pair[0].clone();
+            segment.target = pair[1].clone();
+            add_new_edge(graph, segment)?;
+        }
+    }
+    Ok(())
+}
+
+/// Contracts each selected edge, merging its endpoints into one node.
+///
+/// Nodes joined by a chain of selected edges merge into the one with the
+/// smallest id, which keeps its type, attributes and position. Other edges of
+/// the merged nodes are moved onto it, except those that would become
+/// self-loops. Parallel edges are kept.
+///
+/// # Parameters
+///
+/// * `edge_type`, `attribute`, `value` (optional) - The edges to contract
+///   (default: all edges)
+///
+/// # Examples
+///
+/// ```rust
+/// use graph_generation_language::generators::generate_path;
+/// use graph_generation_language::transforms::contract;
+/// use graph_generation_language::types::{Edge, MetadataValue};
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("nodes".to_string(), MetadataValue::Integer(4));
+/// let mut graph = generate_path(&params).unwrap();
+/// graph.edges.get_mut("e1_2").unwrap().r#type = "bond".to_string();
+///
+/// let mut params = HashMap::new();
+/// params.insert("edge_type".to_string(), MetadataValue::String("bond".to_string()));
+/// contract(&mut graph, &params).unwrap();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edges["e2_3"].source, "n1");
+/// ```
+///
+/// # GGL Usage
+///
+/// ```ggl
+/// transform contract { attribute: "weight"; value: 0; }
+/// ```
+pub fn contract(graph: &mut Graph, params: &HashMap<String, MetadataValue>) -> Result<(), String> {
+    fn find(parent: &HashMap<String, String>, u: &str) -> String {
+        let mut root = u.to_string();
+        while let Some(next) = parent.get(&root).filter(|&next| next != &root) {
+            root = next.clone();
+        }
+        root
+    }
+
+    let selection = Selection::from_params(params)?;
+    let contracted: Vec<String> = selected_edges(graph, &selection)
+        .into_iter()
+        .filter(|id| {
+            let edge = &graph.edges[id];
+            graph.nodes.contains_key(&edge.source) && graph.nodes.contains_key(&edge.target)
+        })
+        .collect();
+
+    // Union the endpoints, keeping the smaller id as the root
+    let mut parent: HashMap<String, String> = HashMap::new();
+    for id in &contracted {
+        let edge = graph.edges.remove(id).unwrap();
+        let (ru, rv) = (find(&parent, &edge.source), find(&parent, &edge.target));
+        if ru < rv {
+            parent.insert(rv, ru);
+        } else if rv < ru {
+            parent.insert(ru, rv);
+        }
+    }
+
+    let merged: HashSet<String> = parent.keys().cloned().collect();
+    let roots: HashMap<String, String> = merged
+        .iter()
+        .map(|id| (id.clone(), find(&parent, id)))
+        .collect();
+    for id in &merged {
+        graph.nodes.remove(id);
+    }
+
+    let mut loops = Vec::new();
+    for edge in graph.edges.values_mut() {
+        let was_loop = edge.source == edge.target;
+        if let Some(root) = roots.get(&edge.source) {
+            edge.source = root.clone();
+        }
+        if let Some(root) = roots.get(&edge.target) {
+            edge.target = root.clone();
+        }
+        if !was_loop && edge.source == edge.target {
+            loops.push(edge.id.clone());
+        }
+    }
+    for id in loops {
+        graph.edges.remove(&id);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Edge;
+
+    #[test]
+    fn test_contract_triangle_drops_new_loops() {
+        let mut graph = Graph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(Node::new(id.to_string()));
+        }
+        for (id, u, v, edge_type) in [
+            ("ab", "a", "b", "bond"),
+            ("bc", "b", "c", "bond"),
+            ("ca", "c", "a", ""),
+            ("cd", "c", "d", ""),
+        ] {
+            graph.add_edge(
+                Edge::new(id.to_string(), u.to_string(), v.to_string())
+                    .with_type(edge_type.to_string()),
+            );
+        }
+
+        let mut params = HashMap::new();
+        params.insert(
+            "edge_type".to_string(),
+            MetadataValue::String("bond".to_string()),
+        );
+        contract(&mut graph, &params).unwrap();
+
+        let mut nodes: Vec<&String> = graph.nodes.keys().collect();
+        nodes.sort();
+        assert_eq!(nodes, vec!["a", "d"]);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.edges["cd"].source, "a");
+    }
+}
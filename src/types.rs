@@ -13,7 +13,9 @@
 //!
 //! ## JSON Serialization
 //!
-//! All types implement Serde serialization for JSON export:
+//! All types implement Serde serialization for JSON export. Maps are written
+//! with their keys in sorted order so that the same graph always produces
+//! byte-identical JSON:
 //!
 //! ```json
 //! {
@@ -43,14 +45,27 @@
 //! }
 //! ```
 
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+
+/// Serializes a map with its keys in sorted order.
+///
+/// `HashMap` iteration order differs between runs, so without this the JSON
+/// output of identical graphs would not be byte-for-byte reproducible.
+fn serialize_sorted<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    let sorted: BTreeMap<&String, &V> = map.iter().collect();
+    sorted.serialize(serializer)
+}
 
 /// Flexible value type for node and edge metadata attributes.
 ///
 /// `MetadataValue` supports the common data types used in graph attributes:
-/// strings, integers, floating-point numbers, and booleans. The enum uses
-/// Serde's untagged serialization for clean JSON output.
+/// strings, integers, floating-point numbers, booleans, and lists of values.
+/// The enum uses Serde's untagged serialization for clean JSON output.
 ///
 /// # Examples
 ///
@@ -61,6 +76,7 @@ use std::collections::HashMap;
 /// let age = MetadataValue::Integer(30);
 /// let score = MetadataValue::Float(98.5);
 /// let active = MetadataValue::Boolean(true);
+/// let sizes = MetadataValue::List(vec![MetadataValue::Integer(10), MetadataValue::Integer(20)]);
 /// ```
 ///
 /// # JSON Representation
@@ -71,7 +87,8 @@ use std::collections::HashMap;
 ///   "name": "Alice",
 ///   "age": 30,
 ///   "score": 98.5,
-///   "active": true
+///   "active": true,
+///   "sizes": [10, 20]
 /// }
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -85,6 +102,8 @@ pub enum MetadataValue {
     Float(f64),
     /// Boolean value for true/false flags
     Boolean(bool),
+    /// Ordered list of values, e.g. block sizes or rows of a matrix
+    List(Vec<MetadataValue>),
 }
 
 /// Represents a graph node (vertex) with optional type and metadata.
@@ -119,18 +138,26 @@ pub enum MetadataValue {
 /// node bob :person;                        // Typed node
 /// node server :machine [cpu=8, ram=16];   // Node with attributes
 /// ```
+///
+/// Fields may be added in minor releases, so nodes are built with
+/// [`Node::new`] and the `with_*` methods rather than struct literals.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Node {
     /// Unique identifier for the node
     pub id: String,
     /// Optional type for categorizing nodes (e.g., "person", "server")
     pub r#type: String,
     /// Key-value metadata attributes
+    #[serde(serialize_with = "serialize_sorted")]
     pub metadata: HashMap<String, MetadataValue>,
     /// X coordinate for visualization
     pub x: f64,
     /// Y coordinate for visualization
     pub y: f64,
+    /// Path of the innermost subgraph holding the node, e.g. `"dc.rack1"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subgraph: Option<String>,
 }
 
 impl Node {
@@ -159,6 +186,7 @@ impl Node {
             metadata: HashMap::new(),
             x: 0.0,
             y: 0.0,
+            subgraph: None,
         }
     }
 
@@ -259,6 +287,24 @@ impl Node {
         self.y = y;
         self
     }
+
+    /// Places the node in a subgraph using the builder pattern.
+    ///
+    /// Nested subgraphs are written as a dotted path from the outermost one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graph_generation_language::types::Node;
+    ///
+    /// let node = Node::new("dc.rack1.gw".to_string())
+    ///     .with_subgraph("dc.rack1".to_string());
+    /// assert_eq!(node.subgraph.as_deref(), Some("dc.rack1"));
+    /// ```
+    pub fn with_subgraph(mut self, subgraph: String) -> Self {
+        self.subgraph = Some(subgraph);
+        self
+    }
 }
 
 /// Represents a graph edge connecting two nodes.
@@ -308,6 +354,7 @@ pub struct Edge {
     /// Optional type for categorizing edges (e.g., "friend", "connection")
     pub r#type: String,
     /// Key-value metadata attributes
+    #[serde(serialize_with = "serialize_sorted")]
     pub metadata: HashMap<String, MetadataValue>,
 }
 
@@ -469,8 +516,10 @@ impl Edge {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Graph {
     /// Map of node ID to Node objects
+    #[serde(serialize_with = "serialize_sorted")]
     pub nodes: HashMap<String, Node>,
     /// Map of edge ID to Edge objects
+    #[serde(serialize_with = "serialize_sorted")]
     pub edges: HashMap<String, Edge>,
 }
 
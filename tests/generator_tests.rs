@@ -1,6 +1,68 @@
 use graph_generation_language::generators::*;
-use graph_generation_language::types::MetadataValue;
+use graph_generation_language::types::{Graph, MetadataValue};
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+
+/// Generator parameters built up in a chain, e.g.
+/// `int_params(&[("nodes", 50)]).float("p", 0.1).seed(7)`.
+///
+/// Dereferences to the parameter map, so it can be passed to generators and
+/// edited with `insert` and `remove` like one.
+#[derive(Debug, Clone, Default)]
+struct Params(HashMap<String, MetadataValue>);
+
+impl Params {
+    fn value(mut self, key: &str, value: MetadataValue) -> Self {
+        self.0.insert(key.to_string(), value);
+        self
+    }
+
+    fn float(self, key: &str, value: f64) -> Self {
+        self.value(key, MetadataValue::Float(value))
+    }
+
+    fn boolean(self, key: &str, value: bool) -> Self {
+        self.value(key, MetadataValue::Boolean(value))
+    }
+
+    fn seed(self, seed: i64) -> Self {
+        self.value("seed", MetadataValue::Integer(seed))
+    }
+}
+
+impl Deref for Params {
+    type Target = HashMap<String, MetadataValue>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Params {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Integer parameters, the most common kind.
+fn int_params(pairs: &[(&str, i64)]) -> Params {
+    pairs
+        .iter()
+        .fold(Params::default(), |params, (key, value)| {
+            params.value(key, MetadataValue::Integer(*value))
+        })
+}
+
+/// Counts the edges at every node, in both directions.
+fn degrees(graph: &Graph) -> HashMap<String, usize> {
+    let mut degrees: HashMap<String, usize> =
+        graph.nodes.keys().map(|id| (id.clone(), 0)).collect();
+    for edge in graph.edges.values() {
+        *degrees.get_mut(&edge.source).unwrap() += 1;
+        *degrees.get_mut(&edge.target).unwrap() += 1;
+    }
+    degrees
+}
 
 #[cfg(test)]
 mod complete_graph_tests {
@@ -127,6 +189,12 @@ mod path_graph_tests {
         assert_eq!(graph.edge_count(), 0);
     }
 
+    #[test]
+    fn test_path_and_grid_reject_empty_sizes() {
+        assert!(generate_path(&int_params(&[("nodes", 0)])).is_err());
+        assert!(generate_grid(&int_params(&[("rows", 0), ("cols", 3)])).is_err());
+    }
+
     #[test]
     fn test_path_graph_two_nodes() {
         let mut params = HashMap::new();
@@ -412,6 +480,26 @@ mod tree_graph_tests {
         assert_eq!(graph.edge_count(), 3);
     }
 
+    #[test]
+    fn test_tree_graph_depth_and_parent_attributes() {
+        let mut params = HashMap::new();
+        params.insert("branching".to_string(), MetadataValue::Integer(2));
+        params.insert("depth".to_string(), MetadataValue::Integer(3));
+
+        let graph = generate_tree(&params).unwrap();
+
+        let root = graph.get_node("n0").unwrap();
+        assert_eq!(root.metadata.get("depth"), Some(&MetadataValue::Integer(0)));
+        assert!(!root.metadata.contains_key("parent"));
+
+        let leaf = graph.get_node("n4").unwrap();
+        assert_eq!(leaf.metadata.get("depth"), Some(&MetadataValue::Integer(2)));
+        assert_eq!(
+            leaf.metadata.get("parent"),
+            Some(&MetadataValue::String("n1".to_string()))
+        );
+    }
+
     #[test]
     fn test_tree_graph_depth_zero() {
         let mut params = HashMap::new();
@@ -521,6 +609,128 @@ mod barabasi_albert_tests {
         assert!(graph.get_node("ba4").is_some());
     }
 
+    #[test]
+    fn test_barabasi_albert_exact_distinct_targets() {
+        let (n, m) = (500, 4);
+        let graph =
+            generate_barabasi_albert(&int_params(&[("nodes", n), ("edges_per_node", m)]).seed(17))
+                .unwrap();
+        assert_eq!(graph.edge_count(), 10 + (n as usize - 5) * m as usize);
+
+        let mut targets: HashMap<String, Vec<String>> = HashMap::new();
+        for edge in graph.edges.values() {
+            targets
+                .entry(edge.source.clone())
+                .or_default()
+                .push(edge.target.clone());
+        }
+        for i in 5..n {
+            let mut node_targets = targets[&format!("n{}", i)].clone();
+            assert_eq!(node_targets.len(), m as usize);
+            node_targets.sort();
+            node_targets.dedup();
+            assert_eq!(
+                node_targets.len(),
+                m as usize,
+                "targets of n{} must be distinct",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_barabasi_albert_initial_graphs() {
+        let cases = [("complete", 6), ("star", 3), ("path", 3), ("cycle", 4)];
+        for (initial_graph, initial_edges) in cases {
+            let mut params = int_params(&[("nodes", 50), ("edges_per_node", 3)]).seed(17);
+            params.insert(
+                "initial_graph".to_string(),
+                MetadataValue::String(initial_graph.to_string()),
+            );
+
+            let graph = generate_barabasi_albert(&params).unwrap();
+            assert_eq!(graph.node_count(), 50);
+            assert_eq!(
+                graph.edge_count(),
+                initial_edges + 46 * 3,
+                "wrong edge count for initial_graph {}",
+                initial_graph
+            );
+        }
+    }
+
+    #[test]
+    fn test_barabasi_albert_initial_nodes() {
+        let mut params = int_params(&[("nodes", 30), ("edges_per_node", 2)]).seed(17);
+        params.insert("initial_nodes".to_string(), MetadataValue::Integer(6));
+        params.insert(
+            "initial_graph".to_string(),
+            MetadataValue::String("cycle".to_string()),
+        );
+
+        let graph = generate_barabasi_albert(&params).unwrap();
+        assert_eq!(graph.edge_count(), 6 + 24 * 2);
+
+        // Seed graph must have more than edges_per_node nodes
+        params.insert("initial_nodes".to_string(), MetadataValue::Integer(2));
+        assert!(generate_barabasi_albert(&params).is_err());
+    }
+
+    #[test]
+    fn test_barabasi_albert_invalid_initial_graph() {
+        let mut params = int_params(&[("nodes", 30), ("edges_per_node", 2)]).seed(17);
+        params.insert(
+            "initial_graph".to_string(),
+            MetadataValue::String("wheel".to_string()),
+        );
+        assert!(generate_barabasi_albert(&params).is_err());
+    }
+
+    #[test]
+    fn test_barabasi_albert_directed() {
+        let mut params = int_params(&[("nodes", 200), ("edges_per_node", 2)]).seed(17);
+        params.insert("directed".to_string(), MetadataValue::Boolean(true));
+
+        let graph = generate_barabasi_albert(&params).unwrap();
+        assert_eq!(graph.edge_count(), 3 + 197 * 2);
+        for edge in graph.edges.values() {
+            let source: usize = edge.source[1..].parse().unwrap();
+            let target: usize = edge.target[1..].parse().unwrap();
+            if source >= 3 {
+                assert!(source > target, "edges point from newer to older nodes");
+            }
+        }
+    }
+
+    #[test]
+    fn test_barabasi_albert_seed_is_reproducible() {
+        let a = generate_barabasi_albert(
+            &int_params(&[("nodes", 300), ("edges_per_node", 3)]).seed(17),
+        )
+        .unwrap();
+        let b = generate_barabasi_albert(
+            &int_params(&[("nodes", 300), ("edges_per_node", 3)]).seed(17),
+        )
+        .unwrap();
+        let mut a_edges: Vec<_> = a.edges.keys().collect();
+        let mut b_edges: Vec<_> = b.edges.keys().collect();
+        a_edges.sort();
+        b_edges.sort();
+        assert_eq!(a_edges, b_edges);
+    }
+
+    #[test]
+    fn test_barabasi_albert_large() {
+        let start = std::time::Instant::now();
+        let graph = generate_barabasi_albert(
+            &int_params(&[("nodes", 50_000), ("edges_per_node", 3)]).seed(17),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 50_000);
+        assert_eq!(graph.edge_count(), 6 + 49_996 * 3);
+        assert!(start.elapsed().as_secs() < 30);
+    }
+
     #[test]
     fn test_barabasi_albert_missing_params() {
         let mut params = HashMap::new();
@@ -533,72 +743,2033 @@ mod barabasi_albert_tests {
 }
 
 #[cfg(test)]
-mod generator_registry_tests {
+mod growth_model_tests {
     use super::*;
+    use std::collections::HashSet;
+
+    fn average_clustering(graph: &Graph) -> f64 {
+        let mut adjacency: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for edge in graph.edges.values() {
+            adjacency
+                .entry(&edge.source)
+                .or_default()
+                .insert(&edge.target);
+            adjacency
+                .entry(&edge.target)
+                .or_default()
+                .insert(&edge.source);
+        }
+        let total: f64 = adjacency
+            .values()
+            .map(|neighbours| {
+                let k = neighbours.len();
+                if k < 2 {
+                    return 0.0;
+                }
+                let links = neighbours
+                    .iter()
+                    .flat_map(|a| neighbours.iter().map(move |b| (a, b)))
+                    .filter(|(a, b)| a < b && adjacency[**a].contains(**b))
+                    .count();
+                2.0 * links as f64 / (k * (k - 1)) as f64
+            })
+            .sum();
+        total / graph.node_count() as f64
+    }
 
     #[test]
-    fn test_get_generator_valid() {
-        assert!(get_generator("complete").is_some());
-        assert!(get_generator("path").is_some());
-        assert!(get_generator("cycle").is_some());
-        assert!(get_generator("grid").is_some());
-        assert!(get_generator("star").is_some());
-        assert!(get_generator("tree").is_some());
-        assert!(get_generator("barabasi_albert").is_some());
+    fn test_powerlaw_cluster_edge_count() {
+        let mut params = int_params(&[("nodes", 500)])
+            .seed(77)
+            .float("triad_probability", 0.7);
+        params.insert("edges_per_node".to_string(), MetadataValue::Integer(3));
+        let graph = generate_powerlaw_cluster(&params).unwrap();
+        assert_eq!(graph.node_count(), 500);
+        assert_eq!(graph.edge_count(), 6 + 496 * 3);
+
+        let pairs: HashSet<(String, String)> = graph
+            .edges
+            .values()
+            .map(|e| (e.source.clone(), e.target.clone()))
+            .collect();
+        assert_eq!(pairs.len(), graph.edge_count(), "no parallel edges");
     }
 
     #[test]
-    fn test_get_generator_invalid() {
-        assert!(get_generator("nonexistent").is_none());
-        assert!(get_generator("").is_none());
-        assert!(get_generator("COMPLETE").is_none()); // Case sensitive
+    fn test_powerlaw_cluster_raises_clustering() {
+        let mut params = int_params(&[("nodes", 1000)])
+            .seed(77)
+            .float("triad_probability", 0.9);
+        params.insert("edges_per_node".to_string(), MetadataValue::Integer(3));
+        let clustered = generate_powerlaw_cluster(&params).unwrap();
+
+        let mut ba_params = int_params(&[("nodes", 1000)]).seed(77);
+        ba_params.insert("edges_per_node".to_string(), MetadataValue::Integer(3));
+        let ba = generate_barabasi_albert(&ba_params).unwrap();
+
+        let (c_hk, c_ba) = (average_clustering(&clustered), average_clustering(&ba));
+        assert!(c_hk > 3.0 * c_ba, "Holme-Kim {} vs BA {}", c_hk, c_ba);
     }
 
     #[test]
-    fn test_all_generators_callable() {
-        let generators = [
-            "complete",
-            "path",
-            "cycle",
-            "grid",
-            "star",
-            "tree",
-            "barabasi_albert",
-        ];
+    fn test_powerlaw_cluster_invalid() {
+        let mut params = int_params(&[("nodes", 10)])
+            .seed(77)
+            .float("triad_probability", 1.5);
+        params.insert("edges_per_node".to_string(), MetadataValue::Integer(2));
+        assert!(generate_powerlaw_cluster(&params).is_err());
 
-        for gen_name in generators {
-            let generator = get_generator(gen_name).unwrap();
+        let mut params = int_params(&[("nodes", 3)]).seed(77);
+        params.insert("edges_per_node".to_string(), MetadataValue::Integer(3));
+        assert!(generate_powerlaw_cluster(&params).is_err());
+    }
 
-            // Test with minimal valid parameters
-            let mut params = HashMap::new();
-            match gen_name {
-                "complete" | "path" | "cycle" | "star" => {
-                    params.insert("nodes".to_string(), MetadataValue::Integer(3));
-                }
-                "grid" => {
-                    params.insert("rows".to_string(), MetadataValue::Integer(2));
-                    params.insert("cols".to_string(), MetadataValue::Integer(2));
-                }
-                "tree" => {
-                    params.insert("branching".to_string(), MetadataValue::Integer(2));
-                    params.insert("depth".to_string(), MetadataValue::Integer(2));
-                }
-                "barabasi_albert" => {
-                    params.insert("nodes".to_string(), MetadataValue::Integer(5));
-                    params.insert("edges_per_node".to_string(), MetadataValue::Integer(2));
-                }
-                _ => unreachable!(),
-            }
+    #[test]
+    fn test_forest_fire_without_burning_is_a_tree() {
+        let params = int_params(&[("nodes", 200)])
+            .seed(77)
+            .float("forward_probability", 0.0)
+            .float("backward_probability", 0.0);
+        let graph = generate_forest_fire(&params).unwrap();
+        assert_eq!(graph.node_count(), 200);
+        assert_eq!(graph.edge_count(), 199);
+    }
 
-            let result = generator(&params);
-            assert!(
-                result.is_ok(),
-                "Generator {} failed with minimal params: {:?}",
-                gen_name,
-                result.err()
+    #[test]
+    fn test_forest_fire_links_new_to_old() {
+        let graph = generate_forest_fire(&int_params(&[("nodes", 500)]).seed(77)).unwrap();
+        for edge in graph.edges.values() {
+            let source: usize = edge.source[1..].parse().unwrap();
+            let target: usize = edge.target[1..].parse().unwrap();
+            assert!(source > target, "{} should point to an older node", edge.id);
+        }
+        // Burning adds links beyond the ambassador
+        assert!(graph.edge_count() > 600);
+    }
+
+    #[test]
+    fn test_forest_fire_densifies_with_forward_probability() {
+        let sparse = generate_forest_fire(
+            &int_params(&[("nodes", 400)])
+                .seed(77)
+                .float("forward_probability", 0.2),
+        )
+        .unwrap()
+        .edge_count();
+        let dense = generate_forest_fire(
+            &int_params(&[("nodes", 400)])
+                .seed(77)
+                .float("forward_probability", 0.45),
+        )
+        .unwrap()
+        .edge_count();
+        assert!(dense > sparse, "{} vs {}", dense, sparse);
+
+        assert!(generate_forest_fire(
+            &int_params(&[("nodes", 10)])
+                .seed(77)
+                .float("forward_probability", 1.0)
+        )
+        .is_err());
+    }
+}
+
+#[cfg(test)]
+mod small_world_tests {
+    use super::*;
+
+    fn sorted_edges(graph: &graph_generation_language::types::Graph) -> Vec<String> {
+        let mut edges: Vec<String> = graph.edges.keys().cloned().collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_watts_strogatz_preserves_edge_count() {
+        for p in [0.0, 0.3, 1.0] {
+            let graph = generate_watts_strogatz(
+                &int_params(&[("nodes", 50), ("k", 6)]).float("p", p).seed(3),
+            )
+            .unwrap();
+            assert_eq!(graph.node_count(), 50);
+            assert_eq!(graph.edge_count(), 150, "n*k/2 edges for p={}", p);
+        }
+    }
+
+    #[test]
+    fn test_watts_strogatz_no_self_loops() {
+        let graph = generate_watts_strogatz(
+            &int_params(&[("nodes", 40), ("k", 4)])
+                .float("p", 0.8)
+                .seed(11),
+        )
+        .unwrap();
+        for edge in graph.edges.values() {
+            assert_ne!(edge.source, edge.target);
+        }
+    }
+
+    #[test]
+    fn test_watts_strogatz_seed_is_reproducible() {
+        let a = generate_watts_strogatz(
+            &int_params(&[("nodes", 60), ("k", 4)])
+                .float("p", 0.5)
+                .seed(99),
+        )
+        .unwrap();
+        let b = generate_watts_strogatz(
+            &int_params(&[("nodes", 60), ("k", 4)])
+                .float("p", 0.5)
+                .seed(99),
+        )
+        .unwrap();
+        let c = generate_watts_strogatz(
+            &int_params(&[("nodes", 60), ("k", 4)])
+                .float("p", 0.5)
+                .seed(100),
+        )
+        .unwrap();
+        assert_eq!(sorted_edges(&a), sorted_edges(&b));
+        assert_ne!(sorted_edges(&a), sorted_edges(&c));
+    }
+
+    #[test]
+    fn test_watts_strogatz_custom_prefix() {
+        let mut params = int_params(&[("nodes", 10), ("k", 2)])
+            .float("p", 0.5)
+            .seed(1);
+        params.insert(
+            "prefix".to_string(),
+            MetadataValue::String("ws".to_string()),
+        );
+
+        let graph = generate_watts_strogatz(&params).unwrap();
+        assert!(graph.get_node("ws0").is_some());
+        assert!(graph.get_node("ws9").is_some());
+    }
+
+    #[test]
+    fn test_watts_strogatz_invalid_params() {
+        // Odd k
+        assert!(generate_watts_strogatz(
+            &int_params(&[("nodes", 10), ("k", 3)])
+                .float("p", 0.1)
+                .seed(1)
+        )
+        .is_err());
+        // k not smaller than nodes
+        assert!(generate_watts_strogatz(
+            &int_params(&[("nodes", 4), ("k", 4)])
+                .float("p", 0.1)
+                .seed(1)
+        )
+        .is_err());
+        // Probability out of range
+        assert!(generate_watts_strogatz(
+            &int_params(&[("nodes", 10), ("k", 2)])
+                .float("p", 1.5)
+                .seed(1)
+        )
+        .is_err());
+        // Missing k
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(10));
+        assert!(generate_watts_strogatz(&params).is_err());
+    }
+
+    #[test]
+    fn test_newman_watts_adds_shortcuts() {
+        let lattice = generate_newman_watts(
+            &int_params(&[("nodes", 50), ("k", 4)])
+                .float("p", 0.0)
+                .seed(5),
+        )
+        .unwrap();
+        assert_eq!(lattice.edge_count(), 100);
+
+        let graph = generate_newman_watts(
+            &int_params(&[("nodes", 50), ("k", 4)])
+                .float("p", 0.5)
+                .seed(5),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 50);
+        assert!(graph.edge_count() > 100);
+        for edge in graph.edges.values() {
+            assert_ne!(edge.source, edge.target);
+        }
+    }
+
+    #[test]
+    fn test_newman_watts_seed_is_reproducible() {
+        let a = generate_newman_watts(
+            &int_params(&[("nodes", 60), ("k", 4)])
+                .float("p", 0.3)
+                .seed(8),
+        )
+        .unwrap();
+        let b = generate_newman_watts(
+            &int_params(&[("nodes", 60), ("k", 4)])
+                .float("p", 0.3)
+                .seed(8),
+        )
+        .unwrap();
+        assert_eq!(sorted_edges(&a), sorted_edges(&b));
+    }
+
+    fn long_range_lengths(graph: &graph_generation_language::types::Graph) -> Vec<f64> {
+        graph
+            .edges
+            .values()
+            .filter(|edge| edge.r#type == "long_range")
+            .map(|edge| {
+                let (u, v) = (&graph.nodes[&edge.source], &graph.nodes[&edge.target]);
+                (u.x - v.x).abs() + (u.y - v.y).abs()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_kleinberg_structure() {
+        let graph = generate_kleinberg(
+            &int_params(&[("rows", 20), ("cols", 30), ("q", 2)])
+                .float("r", 2.0)
+                .seed(6),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 600);
+        let local = graph
+            .edges
+            .values()
+            .filter(|edge| edge.r#type == "local")
+            .count();
+        assert_eq!(local, 20 * 29 + 19 * 30);
+        assert_eq!(graph.edge_count(), local + 2 * 600);
+
+        let node = graph.get_node("n4_7").unwrap();
+        assert_eq!((node.x, node.y), (7.0, 4.0));
+        assert_eq!(node.metadata.get("row"), Some(&MetadataValue::Integer(4)));
+        assert_eq!(graph.get_edge("e4_7_5_7").unwrap().r#type, "local");
+
+        let link = graph.get_edge("l4_7_1").unwrap();
+        assert_eq!(link.source, "n4_7");
+        assert_ne!(link.target, "n4_7");
+        assert!(graph.get_node(&link.target).is_some());
+    }
+
+    #[test]
+    fn test_kleinberg_exponent_controls_link_length() {
+        let mean = |r: f64| {
+            let lengths = long_range_lengths(
+                &generate_kleinberg(
+                    &int_params(&[("rows", 30), ("cols", 30), ("q", 1)])
+                        .float("r", r)
+                        .seed(6),
+                )
+                .unwrap(),
+            );
+            lengths.iter().sum::<f64>() / lengths.len() as f64
+        };
+        let (uniform, harmonic, short) = (mean(0.0), mean(2.0), mean(4.0));
+        assert!(uniform > 15.0, "mean length {} for r = 0", uniform);
+        assert!(uniform > harmonic && harmonic > short);
+        assert!(short < 2.0, "mean length {} for r = 4", short);
+    }
+
+    #[test]
+    fn test_kleinberg_link_distribution() {
+        // In a 2x2 grid each node has two neighbours at distance 1 and one at
+        // distance 2, so with r = 2 the far node is chosen with probability 1/9
+        let graph = generate_kleinberg(
+            &int_params(&[("rows", 2), ("cols", 2), ("q", 9000)])
+                .float("r", 2.0)
+                .seed(6),
+        )
+        .unwrap();
+        let lengths = long_range_lengths(&graph);
+        assert_eq!(lengths.len(), 36000);
+        let far = lengths.iter().filter(|&&d| d == 2.0).count();
+        assert!((3700..4300).contains(&far), "{} far links", far);
+    }
+
+    #[test]
+    fn test_kleinberg_invalid_params() {
+        assert!(generate_kleinberg(
+            &int_params(&[("rows", 10), ("cols", 10), ("q", 1)])
+                .float("r", -1.0)
+                .seed(6)
+        )
+        .is_err());
+        assert!(generate_kleinberg(
+            &int_params(&[("rows", 1), ("cols", 1), ("q", 1)])
+                .float("r", 2.0)
+                .seed(6)
+        )
+        .is_err());
+        assert_eq!(
+            generate_kleinberg(
+                &int_params(&[("rows", 1), ("cols", 1), ("q", 0)])
+                    .float("r", 2.0)
+                    .seed(6)
+            )
+            .unwrap()
+            .node_count(),
+            1
+        );
+
+        let mut params = HashMap::new();
+        params.insert("rows".to_string(), MetadataValue::Integer(5));
+        assert!(generate_kleinberg(&params).is_err());
+    }
+}
+
+#[cfg(test)]
+mod stochastic_block_model_tests {
+    use super::*;
+
+    fn int_list(values: &[i64]) -> MetadataValue {
+        MetadataValue::List(values.iter().map(|v| MetadataValue::Integer(*v)).collect())
+    }
+
+    fn matrix(rows: &[&[f64]]) -> MetadataValue {
+        MetadataValue::List(
+            rows.iter()
+                .map(|row| {
+                    MetadataValue::List(row.iter().map(|p| MetadataValue::Float(*p)).collect())
+                })
+                .collect(),
+        )
+    }
+
+    fn block_of(graph: &graph_generation_language::types::Graph, id: &str) -> i64 {
+        match graph.get_node(id).unwrap().metadata.get("block") {
+            Some(MetadataValue::Integer(b)) => *b,
+            other => panic!("missing block attribute: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sbm_planted_cliques() {
+        let graph = generate_stochastic_block_model(
+            &Params::default()
+                .value("sizes", int_list(&[4, 3]))
+                .value("probabilities", matrix(&[&[1.0, 0.0], &[0.0, 1.0]]))
+                .seed(5),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 7);
+        assert_eq!(graph.edge_count(), 6 + 3);
+
+        for edge in graph.edges.values() {
+            assert_eq!(
+                block_of(&graph, &edge.source),
+                block_of(&graph, &edge.target)
+            );
+        }
+        assert_eq!(block_of(&graph, "n3"), 0);
+        assert_eq!(block_of(&graph, "n4"), 1);
+    }
+
+    #[test]
+    fn test_sbm_complete_bipartite() {
+        let graph = generate_stochastic_block_model(
+            &Params::default()
+                .value("sizes", int_list(&[3, 5]))
+                .value("probabilities", matrix(&[&[0.0, 1.0], &[1.0, 0.0]]))
+                .seed(5),
+        )
+        .unwrap();
+        assert_eq!(graph.edge_count(), 15);
+        for edge in graph.edges.values() {
+            assert_ne!(
+                block_of(&graph, &edge.source),
+                block_of(&graph, &edge.target)
             );
         }
     }
+
+    #[test]
+    fn test_sbm_edge_density() {
+        let graph = generate_stochastic_block_model(
+            &Params::default()
+                .value("sizes", int_list(&[200, 200]))
+                .value("probabilities", matrix(&[&[0.2, 0.01], &[0.01, 0.2]]))
+                .seed(5),
+        )
+        .unwrap();
+
+        let (mut within, mut between) = (0, 0);
+        for edge in graph.edges.values() {
+            if block_of(&graph, &edge.source) == block_of(&graph, &edge.target) {
+                within += 1;
+            } else {
+                between += 1;
+            }
+        }
+        // Expected: 2 * 0.2 * 19900 = 7960 within, 0.01 * 40000 = 400 between
+        assert!((7400..8500).contains(&within), "within = {}", within);
+        assert!((250..550).contains(&between), "between = {}", between);
+    }
+
+    #[test]
+    fn test_sbm_block_types() {
+        let mut params = Params::default()
+            .value("sizes", int_list(&[2, 2]))
+            .value("probabilities", matrix(&[&[1.0, 0.0], &[0.0, 1.0]]))
+            .seed(5);
+        params.insert(
+            "block_types".to_string(),
+            MetadataValue::List(vec![
+                MetadataValue::String("server".to_string()),
+                MetadataValue::String("client".to_string()),
+            ]),
+        );
+
+        let graph = generate_stochastic_block_model(&params).unwrap();
+        assert_eq!(graph.get_node("n0").unwrap().r#type, "server");
+        assert_eq!(graph.get_node("n3").unwrap().r#type, "client");
+    }
+
+    #[test]
+    fn test_sbm_directed_asymmetric() {
+        let mut params = Params::default()
+            .value("sizes", int_list(&[3, 3]))
+            .value("probabilities", matrix(&[&[0.0, 1.0], &[0.0, 0.0]]))
+            .seed(5);
+        assert!(generate_stochastic_block_model(&params).is_err());
+
+        params.insert("directed".to_string(), MetadataValue::Boolean(true));
+        let graph = generate_stochastic_block_model(&params).unwrap();
+        assert_eq!(graph.edge_count(), 9);
+        for edge in graph.edges.values() {
+            assert_eq!(block_of(&graph, &edge.source), 0);
+            assert_eq!(block_of(&graph, &edge.target), 1);
+        }
+    }
+
+    #[test]
+    fn test_sbm_invalid_params() {
+        // Matrix does not match block count
+        assert!(generate_stochastic_block_model(
+            &Params::default()
+                .value("sizes", int_list(&[3, 3]))
+                .value("probabilities", matrix(&[&[0.5]]))
+                .seed(5)
+        )
+        .is_err());
+        // Probability out of range
+        assert!(generate_stochastic_block_model(
+            &Params::default()
+                .value("sizes", int_list(&[3]))
+                .value("probabilities", matrix(&[&[1.5]]))
+                .seed(5)
+        )
+        .is_err());
+        // Sizes must be a list
+        let mut params = Params::default()
+            .value("sizes", int_list(&[3]))
+            .value("probabilities", matrix(&[&[0.5]]))
+            .seed(5);
+        params.insert("sizes".to_string(), MetadataValue::Integer(3));
+        assert!(generate_stochastic_block_model(&params).is_err());
+    }
+}
+
+#[cfg(test)]
+mod geometric_tests {
+    use super::*;
+
+    fn weight(graph: &Graph, edge_id: &str) -> f64 {
+        match graph.get_edge(edge_id).unwrap().metadata.get("weight") {
+            Some(MetadataValue::Float(w)) => *w,
+            other => panic!("missing weight: {:?}", other),
+        }
+    }
+
+    fn planar_distance(graph: &Graph, a: &str, b: &str) -> f64 {
+        let (a, b) = (graph.get_node(a).unwrap(), graph.get_node(b).unwrap());
+        ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_random_geometric_matches_brute_force() {
+        let graph = generate_random_geometric(
+            &int_params(&[("nodes", 150)]).float("radius", 0.15).seed(21),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 150);
+
+        let mut expected = 0;
+        for u in 0..150 {
+            for v in u + 1..150 {
+                let d = planar_distance(&graph, &format!("n{}", u), &format!("n{}", v));
+                let edge_id = format!("e{}_{}", u, v);
+                if d <= 0.15 {
+                    expected += 1;
+                    assert!((weight(&graph, &edge_id) - d).abs() < 1e-12);
+                } else {
+                    assert!(graph.get_edge(&edge_id).is_none());
+                }
+            }
+        }
+        assert_eq!(graph.edge_count(), expected);
+    }
+
+    #[test]
+    fn test_random_geometric_positions_in_unit_square() {
+        let graph =
+            generate_random_geometric(&int_params(&[("nodes", 100)]).float("radius", 0.1).seed(21))
+                .unwrap();
+        let mut distinct = std::collections::HashSet::new();
+        for node in graph.nodes.values() {
+            assert!((0.0..1.0).contains(&node.x));
+            assert!((0.0..1.0).contains(&node.y));
+            distinct.insert((node.x.to_bits(), node.y.to_bits()));
+        }
+        assert_eq!(
+            distinct.len(),
+            100,
+            "positions should be sampled, not left at 0"
+        );
+    }
+
+    #[test]
+    fn test_random_geometric_three_dimensions() {
+        let mut params = int_params(&[("nodes", 80)]).float("radius", 0.3).seed(21);
+        params.insert("dim".to_string(), MetadataValue::Integer(3));
+
+        let graph = generate_random_geometric(&params).unwrap();
+        for node in graph.nodes.values() {
+            assert!(
+                matches!(node.metadata.get("z"), Some(MetadataValue::Float(z)) if (0.0..1.0).contains(z))
+            );
+        }
+        for edge in graph.edges.values() {
+            assert!(weight(&graph, &edge.id) <= 0.3);
+            assert!(planar_distance(&graph, &edge.source, &edge.target) <= 0.3);
+        }
+    }
+
+    #[test]
+    fn test_random_geometric_large_radius_is_complete() {
+        let graph =
+            generate_random_geometric(&int_params(&[("nodes", 12)]).float("radius", 2.0).seed(21))
+                .unwrap();
+        assert_eq!(graph.edge_count(), 66);
+    }
+
+    #[test]
+    fn test_random_geometric_invalid_params() {
+        assert!(generate_random_geometric(
+            &int_params(&[("nodes", 10)]).float("radius", 0.0).seed(21)
+        )
+        .is_err());
+
+        let mut params = int_params(&[("nodes", 10)]).float("radius", 0.1).seed(21);
+        params.insert("dim".to_string(), MetadataValue::Integer(4));
+        assert!(generate_random_geometric(&params).is_err());
+
+        params.remove("dim");
+        params.remove("radius");
+        assert!(generate_random_geometric(&params).is_err());
+    }
+
+    #[test]
+    fn test_waxman_weights_and_positions() {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(60));
+        params.insert("beta".to_string(), MetadataValue::Float(0.8));
+        params.insert("alpha".to_string(), MetadataValue::Float(0.3));
+        params.insert("seed".to_string(), MetadataValue::Integer(4));
+
+        let graph = generate_waxman(&params).unwrap();
+        assert_eq!(graph.node_count(), 60);
+        assert!(graph.edge_count() > 0);
+        for edge in graph.edges.values() {
+            let d = planar_distance(&graph, &edge.source, &edge.target);
+            assert!((weight(&graph, &edge.id) - d).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_waxman_beta_bounds() {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(30));
+        params.insert("beta".to_string(), MetadataValue::Float(0.0));
+        params.insert("seed".to_string(), MetadataValue::Integer(4));
+        assert_eq!(generate_waxman(&params).unwrap().edge_count(), 0);
+
+        params.insert("beta".to_string(), MetadataValue::Float(1.2));
+        assert!(generate_waxman(&params).is_err());
+
+        params.insert("beta".to_string(), MetadataValue::Float(0.5));
+        params.insert("alpha".to_string(), MetadataValue::Float(0.0));
+        assert!(generate_waxman(&params).is_err());
+    }
+
+    fn float_attribute(graph: &Graph, node: &str, key: &str) -> f64 {
+        match graph.get_node(node).unwrap().metadata.get(key) {
+            Some(MetadataValue::Float(value)) => *value,
+            other => panic!("missing {}: {:?}", key, other),
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_average_degree_and_hubs() {
+        let graph = generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 1500)])
+                .float("average_degree", 10.0)
+                .float("exponent", 2.5)
+                .float("temperature", 0.0)
+                .seed(13),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 1500);
+        let average = 2.0 * graph.edge_count() as f64 / 1500.0;
+        assert!((8.5..11.5).contains(&average), "average degree {}", average);
+
+        let mut degrees: HashMap<&str, usize> = HashMap::new();
+        for edge in graph.edges.values() {
+            *degrees.entry(edge.source.as_str()).or_default() += 1;
+            *degrees.entry(edge.target.as_str()).or_default() += 1;
+        }
+        let max = *degrees.values().max().unwrap();
+        assert!(
+            max as f64 > 8.0 * average,
+            "expected hubs, max degree {}",
+            max
+        );
+    }
+
+    #[test]
+    fn test_hyperbolic_coordinates_and_distances() {
+        let graph = generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 300)])
+                .float("average_degree", 6.0)
+                .float("exponent", 3.0)
+                .float("temperature", 0.0)
+                .seed(13),
+        )
+        .unwrap();
+        for node in graph.nodes.values() {
+            let r = float_attribute(&graph, &node.id, "radius");
+            let angle = float_attribute(&graph, &node.id, "angle");
+            assert!(r >= 0.0 && (0.0..std::f64::consts::TAU).contains(&angle));
+            assert!((node.x - r * angle.cos()).abs() < 1e-12);
+            assert!((node.y - r * angle.sin()).abs() < 1e-12);
+        }
+
+        let distance = |u: &str, v: &str| {
+            let (r1, a1) = (
+                float_attribute(&graph, u, "radius"),
+                float_attribute(&graph, u, "angle"),
+            );
+            let (r2, a2) = (
+                float_attribute(&graph, v, "radius"),
+                float_attribute(&graph, v, "angle"),
+            );
+            (r1.cosh() * r2.cosh() - r1.sinh() * r2.sinh() * (a1 - a2).cos())
+                .max(1.0)
+                .acosh()
+        };
+
+        // At temperature zero every linked pair is closer than every unlinked pair
+        let (mut longest_edge, mut shortest_gap) = (0.0f64, f64::INFINITY);
+        for u in 0..300 {
+            for v in u + 1..300 {
+                let d = distance(&format!("n{}", u), &format!("n{}", v));
+                match graph.get_edge(&format!("e{}_{}", u, v)) {
+                    Some(edge) => {
+                        assert!((weight(&graph, &edge.id) - d).abs() < 1e-9);
+                        longest_edge = longest_edge.max(d);
+                    }
+                    None => shortest_gap = shortest_gap.min(d),
+                }
+            }
+        }
+        assert!(longest_edge < shortest_gap);
+    }
+
+    #[test]
+    fn test_hyperbolic_temperature() {
+        let graph = generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 400)])
+                .float("average_degree", 8.0)
+                .float("exponent", 2.7)
+                .float("temperature", 0.6)
+                .seed(13),
+        )
+        .unwrap();
+        let average = 2.0 * graph.edge_count() as f64 / 400.0;
+        assert!((6.0..10.0).contains(&average), "average degree {}", average);
+
+        let again = generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 400)])
+                .float("average_degree", 8.0)
+                .float("exponent", 2.7)
+                .float("temperature", 0.6)
+                .seed(13),
+        )
+        .unwrap();
+        let mut a: Vec<_> = graph.edges.keys().collect();
+        let mut b: Vec<_> = again.edges.keys().collect();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_hyperbolic_invalid_params() {
+        assert!(generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 100)])
+                .float("average_degree", 6.0)
+                .float("exponent", 2.0)
+                .float("temperature", 0.0)
+                .seed(13)
+        )
+        .is_err());
+        assert!(generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 100)])
+                .float("average_degree", 6.0)
+                .float("exponent", 3.0)
+                .float("temperature", 1.0)
+                .seed(13)
+        )
+        .is_err());
+        assert!(generate_hyperbolic_random_graph(
+            &int_params(&[("nodes", 100)])
+                .float("average_degree", 120.0)
+                .float("exponent", 3.0)
+                .float("temperature", 0.0)
+                .seed(13)
+        )
+        .is_err());
+        assert!(generate_hyperbolic_random_graph(&HashMap::new()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod interconnect_tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_hypercube_structure() {
+        let graph = generate_hypercube(&int_params(&[("dimension", 4)])).unwrap();
+        assert_eq!(graph.node_count(), 16);
+        assert_eq!(graph.edge_count(), 32);
+        assert!(degrees(&graph).values().all(|&d| d == 4));
+
+        let node = graph.get_node("n0110").unwrap();
+        let expected: Vec<MetadataValue> = [0, 1, 1, 0]
+            .iter()
+            .map(|&b| MetadataValue::Integer(b))
+            .collect();
+        assert_eq!(
+            node.metadata.get("coords"),
+            Some(&MetadataValue::List(expected))
+        );
+
+        for edge in graph.edges.values() {
+            let differing = edge.source[1..]
+                .chars()
+                .zip(edge.target[1..].chars())
+                .filter(|(a, b)| a != b)
+                .count();
+            assert_eq!(differing, 1);
+        }
+    }
+
+    #[test]
+    fn test_torus_three_dimensions() {
+        let mut params = HashMap::new();
+        params.insert(
+            "dims".to_string(),
+            MetadataValue::List(vec![
+                MetadataValue::Integer(3),
+                MetadataValue::Integer(4),
+                MetadataValue::Integer(5),
+            ]),
+        );
+
+        let graph = generate_torus(&params).unwrap();
+        assert_eq!(graph.node_count(), 60);
+        assert_eq!(graph.edge_count(), 180);
+        assert!(degrees(&graph).values().all(|&d| d == 6));
+        assert!(graph.get_node("n2_3_4").is_some());
+
+        params.insert("periodic".to_string(), MetadataValue::Boolean(false));
+        let mesh = generate_torus(&params).unwrap();
+        // (k-1) edges per line along each axis
+        assert_eq!(mesh.edge_count(), 2 * 20 + 3 * 15 + 4 * 12);
+    }
+
+    #[test]
+    fn test_torus_matches_periodic_grid() {
+        let mut params = HashMap::new();
+        params.insert(
+            "dims".to_string(),
+            MetadataValue::List(vec![MetadataValue::Integer(4), MetadataValue::Integer(5)]),
+        );
+        let torus = generate_torus(&params).unwrap();
+
+        let mut grid_params = int_params(&[("rows", 4), ("cols", 5)]);
+        grid_params.insert("periodic".to_string(), MetadataValue::Boolean(true));
+        let grid = generate_grid(&grid_params).unwrap();
+
+        assert_eq!(torus.node_count(), grid.node_count());
+        assert_eq!(torus.edge_count(), grid.edge_count());
+    }
+
+    #[test]
+    fn test_torus_side_of_two_has_no_duplicate_edges() {
+        let mut params = HashMap::new();
+        params.insert(
+            "dims".to_string(),
+            MetadataValue::List(vec![MetadataValue::Integer(2), MetadataValue::Integer(2)]),
+        );
+        let graph = generate_torus(&params).unwrap();
+        assert_eq!(graph.edge_count(), 4);
+
+        params.insert("dims".to_string(), MetadataValue::List(vec![]));
+        assert!(generate_torus(&params).is_err());
+    }
+
+    #[test]
+    fn test_interconnects_reject_huge_sizes() {
+        let mut params = HashMap::new();
+        params.insert(
+            "dims".to_string(),
+            MetadataValue::List(vec![
+                MetadataValue::Integer(1_000_000),
+                MetadataValue::Integer(1_000_000),
+            ]),
+        );
+        let error = generate_torus(&params).unwrap_err();
+        assert!(error.contains("more than 16777216 nodes"), "{}", error);
+
+        assert!(generate_hypercube(&int_params(&[("dimension", 25)])).is_err());
+    }
+
+    #[test]
+    fn test_butterfly_structure() {
+        let graph = generate_butterfly(&int_params(&[("dimension", 3)])).unwrap();
+        assert_eq!(graph.node_count(), 32);
+        assert_eq!(graph.edge_count(), 48);
+
+        let node_degrees = degrees(&graph);
+        for node in graph.nodes.values() {
+            let level = match node.metadata.get("level") {
+                Some(MetadataValue::Integer(level)) => *level,
+                other => panic!("missing level: {:?}", other),
+            };
+            let expected = if level == 0 || level == 3 { 2 } else { 4 };
+            assert_eq!(node_degrees[&node.id], expected);
+        }
+        assert!(graph
+            .edges
+            .values()
+            .any(|e| e.source == "n1_0" && e.target == "n2_2"));
+
+        let mut params = int_params(&[("dimension", 3)]);
+        params.insert("wrapped".to_string(), MetadataValue::Boolean(true));
+        let wrapped = generate_butterfly(&params).unwrap();
+        assert_eq!(wrapped.node_count(), 24);
+        assert!(degrees(&wrapped).values().all(|&d| d == 4));
+    }
+
+    #[test]
+    fn test_de_bruijn_structure() {
+        let graph = generate_de_bruijn(&int_params(&[("symbols", 2), ("length", 3)])).unwrap();
+        assert_eq!(graph.node_count(), 8);
+        assert_eq!(graph.edge_count(), 16);
+        assert!(graph.get_edge("e000_000").is_some(), "constant words loop");
+
+        let edge = graph.get_edge("e011_110").unwrap();
+        assert_eq!(
+            (edge.source.as_str(), edge.target.as_str()),
+            ("n011", "n110")
+        );
+
+        let large = generate_de_bruijn(&int_params(&[("symbols", 12), ("length", 2)])).unwrap();
+        assert!(large.get_node("n11_3").is_some());
+    }
+
+    #[test]
+    fn test_kautz_structure() {
+        let graph = generate_kautz(&int_params(&[("symbols", 3), ("length", 3)])).unwrap();
+        assert_eq!(graph.node_count(), 12);
+        assert_eq!(graph.edge_count(), 24);
+        assert!(graph.get_node("n010").is_some());
+        assert!(graph.get_node("n011").is_none());
+        assert!(graph.edges.values().all(|e| e.source != e.target));
+
+        let mut out_degree: HashMap<&str, usize> = HashMap::new();
+        for edge in graph.edges.values() {
+            *out_degree.entry(edge.source.as_str()).or_default() += 1;
+        }
+        assert!(out_degree.values().all(|&d| d == 2));
+
+        assert!(generate_kautz(&int_params(&[("symbols", 1), ("length", 3)])).is_err());
+    }
+
+    #[test]
+    fn test_fat_tree_layers() {
+        let graph = generate_fat_tree(&int_params(&[("k", 4)])).unwrap();
+        let count = |role: &str| graph.nodes.values().filter(|n| n.r#type == role).count();
+        assert_eq!(count("core"), 4);
+        assert_eq!(count("aggregation"), 8);
+        assert_eq!(count("edge"), 8);
+        assert_eq!(count("host"), 16);
+        assert_eq!(graph.edge_count(), 48);
+
+        let node_degrees = degrees(&graph);
+        for node in graph.nodes.values() {
+            let expected = if node.r#type == "host" { 1 } else { 4 };
+            assert_eq!(node_degrees[&node.id], expected, "{}", node.id);
+        }
+
+        let cores: HashSet<&str> = graph
+            .edges
+            .values()
+            .filter(|e| e.target == "agg2_1")
+            .map(|e| e.source.as_str())
+            .collect();
+        assert_eq!(cores, HashSet::from(["core2", "core3"]));
+
+        assert!(generate_fat_tree(&int_params(&[("k", 3)])).is_err());
+    }
+
+    #[test]
+    fn test_fat_tree_without_hosts() {
+        let mut params = int_params(&[("k", 6)]);
+        params.insert("hosts".to_string(), MetadataValue::Boolean(false));
+        let graph = generate_fat_tree(&params).unwrap();
+        assert_eq!(graph.node_count(), 45);
+        assert_eq!(graph.edge_count(), 108);
+    }
+}
+
+#[cfg(test)]
+mod lattice_tests {
+    use super::*;
+
+    fn assert_unit_bonds(graph: &Graph) {
+        for edge in graph.edges.values() {
+            let a = graph.get_node(&edge.source).unwrap();
+            let b = graph.get_node(&edge.target).unwrap();
+            let length = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+            assert!(
+                (length - 1.0).abs() < 1e-9,
+                "{} has length {}",
+                edge.id,
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn test_triangular_lattice() {
+        let graph = generate_triangular_lattice(
+            &int_params(&[("rows", 4), ("cols", 5)]).boolean("periodic", false),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 20);
+        // Horizontal, vertical and diagonal bonds
+        assert_eq!(graph.edge_count(), 4 * 4 + 3 * 5 + 3 * 4);
+        assert_unit_bonds(&graph);
+        assert_eq!(degrees(&graph)["n1_1"], 6);
+
+        let periodic = generate_triangular_lattice(
+            &int_params(&[("rows", 4), ("cols", 5)]).boolean("periodic", true),
+        )
+        .unwrap();
+        assert_eq!(periodic.edge_count(), 60);
+        assert!(degrees(&periodic).values().all(|&d| d == 6));
+    }
+
+    #[test]
+    fn test_hexagonal_lattice() {
+        let graph = generate_hexagonal_lattice(
+            &int_params(&[("rows", 3), ("cols", 3)]).boolean("periodic", false),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 18);
+        assert_unit_bonds(&graph);
+        assert!(degrees(&graph).values().all(|&d| (1..=3).contains(&d)));
+        assert_eq!(degrees(&graph)["n1_1_0"], 3);
+
+        let periodic = generate_hexagonal_lattice(
+            &int_params(&[("rows", 4), ("cols", 3)]).boolean("periodic", true),
+        )
+        .unwrap();
+        assert_eq!(periodic.node_count(), 24);
+        assert_eq!(periodic.edge_count(), 36);
+        assert!(degrees(&periodic).values().all(|&d| d == 3));
+    }
+
+    #[test]
+    fn test_kagome_lattice() {
+        let graph =
+            generate_kagome(&int_params(&[("rows", 3), ("cols", 4)]).boolean("periodic", false))
+                .unwrap();
+        assert_eq!(graph.node_count(), 36);
+        assert_unit_bonds(&graph);
+        assert_eq!(degrees(&graph)["n1_1_0"], 4);
+        let node = graph.get_node("n1_1_2").unwrap();
+        assert_eq!(node.metadata.get("site"), Some(&MetadataValue::Integer(2)));
+
+        let periodic =
+            generate_kagome(&int_params(&[("rows", 3), ("cols", 4)]).boolean("periodic", true))
+                .unwrap();
+        assert_eq!(periodic.edge_count(), 72);
+        assert!(degrees(&periodic).values().all(|&d| d == 4));
+    }
+
+    #[test]
+    fn test_periodic_lattice_too_small() {
+        assert!(generate_triangular_lattice(
+            &int_params(&[("rows", 2), ("cols", 5)]).boolean("periodic", true)
+        )
+        .is_err());
+        assert!(generate_kagome(
+            &int_params(&[("rows", 3), ("cols", 1)]).boolean("periodic", true)
+        )
+        .is_err());
+        assert!(generate_kagome(
+            &int_params(&[("rows", 2), ("cols", 2)]).boolean("periodic", false)
+        )
+        .is_ok());
+    }
+}
+
+#[cfg(test)]
+mod classic_tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    fn named(name: &str) -> Graph {
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), MetadataValue::String(name.to_string()));
+        generate_named(&params).unwrap()
+    }
+
+    fn adjacency(graph: &Graph) -> HashMap<&str, Vec<&str>> {
+        let mut adjacency: HashMap<&str, Vec<&str>> = graph
+            .nodes
+            .keys()
+            .map(|id| (id.as_str(), Vec::new()))
+            .collect();
+        for edge in graph.edges.values() {
+            adjacency
+                .get_mut(edge.source.as_str())
+                .unwrap()
+                .push(&edge.target);
+            adjacency
+                .get_mut(edge.target.as_str())
+                .unwrap()
+                .push(&edge.source);
+        }
+        adjacency
+    }
+
+    /// Length of the shortest cycle, found by a BFS from every node.
+    fn girth(graph: &Graph) -> usize {
+        let adjacency = adjacency(graph);
+        let mut best = usize::MAX;
+        for &start in adjacency.keys() {
+            let mut dist: HashMap<&str, usize> = HashMap::from([(start, 0)]);
+            let mut parent: HashMap<&str, &str> = HashMap::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(u) = queue.pop_front() {
+                for &v in &adjacency[u] {
+                    if !dist.contains_key(v) {
+                        dist.insert(v, dist[u] + 1);
+                        parent.insert(v, u);
+                        queue.push_back(v);
+                    } else if parent.get(u) != Some(&v) {
+                        best = best.min(dist[u] + dist[v] + 1);
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_small_families_match_networkx_sizes() {
+        let cases = [
+            (generate_wheel(&int_params(&[("nodes", 6)])), 6, 10),
+            (generate_ladder(&int_params(&[("length", 4)])), 8, 10),
+            (
+                generate_circular_ladder(&int_params(&[("length", 6)])),
+                12,
+                18,
+            ),
+            (
+                generate_lollipop(&int_params(&[("clique", 5), ("path", 3)])),
+                8,
+                13,
+            ),
+            (
+                generate_barbell(&int_params(&[("clique", 4), ("path", 2)])),
+                10,
+                15,
+            ),
+            (
+                generate_complete_bipartite(&int_params(&[("left", 3), ("right", 4)])),
+                7,
+                12,
+            ),
+            (
+                generate_turan(&int_params(&[("nodes", 10), ("parts", 3)])),
+                10,
+                33,
+            ),
+            (generate_friendship(&int_params(&[("triangles", 4)])), 9, 12),
+        ];
+        for (i, (graph, nodes, edges)) in cases.into_iter().enumerate() {
+            let graph = graph.unwrap();
+            assert_eq!(graph.node_count(), nodes, "case {}", i);
+            assert_eq!(graph.edge_count(), edges, "case {}", i);
+        }
+    }
+
+    #[test]
+    fn test_barbell_bridge_path() {
+        let graph = generate_barbell(&int_params(&[("clique", 3), ("path", 0)])).unwrap();
+        assert_eq!(graph.node_count(), 6);
+        assert!(graph.get_edge("e2_3").is_some());
+        assert_eq!(girth(&graph), 3);
+    }
+
+    #[test]
+    fn test_complete_multipartite_parts() {
+        let mut params = HashMap::new();
+        params.insert(
+            "sizes".to_string(),
+            MetadataValue::List(vec![
+                MetadataValue::Integer(2),
+                MetadataValue::Integer(3),
+                MetadataValue::Integer(4),
+            ]),
+        );
+        let graph = generate_complete_multipartite(&params).unwrap();
+        assert_eq!(graph.edge_count(), 2 * 3 + 2 * 4 + 3 * 4);
+        assert_eq!(
+            graph.get_node("n4").unwrap().metadata.get("part"),
+            Some(&MetadataValue::Integer(1))
+        );
+    }
+
+    #[test]
+    fn test_family_parameter_validation() {
+        assert!(generate_wheel(&int_params(&[("nodes", 3)])).is_err());
+        assert!(generate_circular_ladder(&int_params(&[("length", 2)])).is_err());
+        assert!(generate_lollipop(&int_params(&[("clique", 1), ("path", 3)])).is_err());
+        assert!(generate_turan(&int_params(&[("nodes", 3), ("parts", 4)])).is_err());
+        assert!(generate_friendship(&int_params(&[("triangles", 0)])).is_err());
+    }
+
+    #[test]
+    fn test_named_cages() {
+        for (name, nodes, edges, expected_girth) in [
+            ("petersen", 10, 15, 5),
+            ("heawood", 14, 21, 6),
+            ("mobius_kantor", 16, 24, 6),
+            ("dodecahedral", 20, 30, 5),
+        ] {
+            let graph = named(name);
+            assert_eq!(graph.node_count(), nodes, "{}", name);
+            assert_eq!(graph.edge_count(), edges, "{}", name);
+            assert!(adjacency(&graph).values().all(|n| n.len() == 3), "{}", name);
+            assert_eq!(girth(&graph), expected_girth, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_named_karate_club() {
+        let graph = named("karate_club");
+        assert_eq!(graph.node_count(), 34);
+        assert_eq!(graph.edge_count(), 78);
+        let adjacency = adjacency(&graph);
+        assert_eq!(adjacency["n0"].len(), 16);
+        assert_eq!(adjacency["n33"].len(), 17);
+        assert_eq!(
+            graph.get_node("n33").unwrap().metadata.get("club"),
+            Some(&MetadataValue::String("Officer".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_named_les_miserables() {
+        let graph = named("les_miserables");
+        assert_eq!(graph.node_count(), 77);
+        assert_eq!(graph.edge_count(), 254);
+
+        let valjean = graph.get_node("n10").unwrap();
+        assert_eq!(
+            valjean.metadata.get("name"),
+            Some(&MetadataValue::String("Valjean".to_string()))
+        );
+        let weighted_degree: i64 = graph
+            .edges
+            .values()
+            .filter(|e| e.source == "n10" || e.target == "n10")
+            .map(|e| match e.metadata.get("weight") {
+                Some(MetadataValue::Integer(w)) => *w,
+                other => panic!("missing weight: {:?}", other),
+            })
+            .sum();
+        assert_eq!(weighted_degree, 158);
+    }
+
+    #[test]
+    fn test_named_unknown() {
+        let mut params = HashMap::new();
+        params.insert(
+            "name".to_string(),
+            MetadataValue::String("tutte".to_string()),
+        );
+        let err = generate_named(&params).unwrap_err();
+        assert!(err.contains("petersen"), "{}", err);
+        assert!(generate_named(&HashMap::new()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod random_tree_tests {
+    use super::*;
+
+    fn depth(graph: &Graph, id: &str) -> i64 {
+        match graph.get_node(id).unwrap().metadata.get("depth") {
+            Some(MetadataValue::Integer(d)) => *d,
+            other => panic!("missing depth on {}: {:?}", id, other),
+        }
+    }
+
+    /// Checks every edge runs from the recorded parent to a child one level deeper.
+    fn assert_rooted_forest(graph: &Graph, components: usize) {
+        assert_eq!(graph.edge_count(), graph.node_count() - components);
+        let roots = graph
+            .nodes
+            .values()
+            .filter(|n| !n.metadata.contains_key("parent"))
+            .count();
+        assert_eq!(roots, components);
+        for edge in graph.edges.values() {
+            let child = graph.get_node(&edge.target).unwrap();
+            assert_eq!(
+                child.metadata.get("parent"),
+                Some(&MetadataValue::String(edge.source.clone()))
+            );
+            assert_eq!(depth(graph, &edge.target), depth(graph, &edge.source) + 1);
+        }
+    }
+
+    #[test]
+    fn test_random_trees_are_rooted_trees() {
+        let generators: [GeneratorFn; 3] = [
+            generate_random_tree,
+            generate_random_recursive_tree,
+            generate_preferential_attachment_tree,
+        ];
+        for generator in generators {
+            let graph = generator(&int_params(&[("nodes", 300)]).seed(17)).unwrap();
+            assert_eq!(graph.node_count(), 300);
+            assert_rooted_forest(&graph, 1);
+            assert_eq!(depth(&graph, "n0"), 0);
+        }
+    }
+
+    #[test]
+    fn test_random_trees_are_reproducible() {
+        let a = generate_random_tree(&int_params(&[("nodes", 50)]).seed(17)).unwrap();
+        let b = generate_random_tree(&int_params(&[("nodes", 50)]).seed(17)).unwrap();
+        let mut ids_a: Vec<_> = a.edges.keys().collect();
+        let mut ids_b: Vec<_> = b.edges.keys().collect();
+        ids_a.sort();
+        ids_b.sort();
+        assert_eq!(ids_a, ids_b);
+    }
+
+    #[test]
+    fn test_preferential_attachment_tree_has_hubs() {
+        let pa = generate_preferential_attachment_tree(&int_params(&[("nodes", 2000)]).seed(17))
+            .unwrap();
+        let mut degree: HashMap<&str, usize> = HashMap::new();
+        for edge in pa.edges.values() {
+            *degree.entry(edge.source.as_str()).or_default() += 1;
+            *degree.entry(edge.target.as_str()).or_default() += 1;
+        }
+        // A uniform recursive tree of this size rarely exceeds degree ~15
+        assert!(*degree.values().max().unwrap() > 25);
+    }
+
+    #[test]
+    fn test_caterpillar_spine() {
+        let graph =
+            generate_caterpillar(&int_params(&[("spine", 5), ("leaves", 12)]).seed(17)).unwrap();
+        assert_eq!(graph.node_count(), 17);
+        assert_rooted_forest(&graph, 1);
+        assert_eq!(depth(&graph, "n4"), 4);
+        for i in 5..17 {
+            let parent = match graph
+                .get_node(&format!("n{}", i))
+                .unwrap()
+                .metadata
+                .get("parent")
+            {
+                Some(MetadataValue::String(parent)) => parent.clone(),
+                other => panic!("missing parent: {:?}", other),
+            };
+            let index: usize = parent[1..].parse().unwrap();
+            assert!(index < 5, "leaves attach to the spine");
+        }
+        assert!(
+            generate_caterpillar(&int_params(&[("spine", 0), ("leaves", 3)]).seed(17)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_random_forest_components() {
+        let graph =
+            generate_random_forest(&int_params(&[("nodes", 100), ("trees", 7)]).seed(17)).unwrap();
+        assert_rooted_forest(&graph, 7);
+
+        let mut trees = std::collections::HashSet::new();
+        for edge in graph.edges.values() {
+            let tree = |id: &str| graph.get_node(id).unwrap().metadata.get("tree").cloned();
+            assert_eq!(tree(&edge.source), tree(&edge.target));
+        }
+        for node in graph.nodes.values() {
+            trees.insert(format!("{:?}", node.metadata.get("tree")));
+        }
+        assert_eq!(trees.len(), 7);
+
+        assert!(
+            generate_random_forest(&int_params(&[("nodes", 3), ("trees", 4)]).seed(17)).is_err()
+        );
+        assert!(
+            generate_random_forest(&int_params(&[("nodes", 3), ("trees", 0)]).seed(17)).is_err()
+        );
+    }
+}
+
+#[cfg(test)]
+mod degree_sequence_tests {
+    use super::*;
+
+    fn degree_list(degrees: &[i64]) -> MetadataValue {
+        MetadataValue::List(degrees.iter().map(|&d| MetadataValue::Integer(d)).collect())
+    }
+
+    fn realised_degrees(graph: &Graph) -> Vec<usize> {
+        let mut degrees = vec![0; graph.node_count()];
+        for edge in graph.edges.values() {
+            degrees[edge.source[1..].parse::<usize>().unwrap()] += 1;
+            degrees[edge.target[1..].parse::<usize>().unwrap()] += 1;
+        }
+        degrees
+    }
+
+    #[test]
+    fn test_configuration_model_preserves_degrees() {
+        let sequence = [5, 4, 4, 3, 3, 3, 2, 2, 1, 1];
+        let mut params = HashMap::new();
+        params.insert("degrees".to_string(), degree_list(&sequence));
+        params.insert("seed".to_string(), MetadataValue::Integer(3));
+
+        let graph = generate_configuration_model(&params).unwrap();
+        assert_eq!(graph.edge_count(), 14);
+        let expected: Vec<usize> = sequence.iter().map(|&d| d as usize).collect();
+        assert_eq!(realised_degrees(&graph), expected);
+    }
+
+    #[test]
+    fn test_configuration_model_simple_option() {
+        let mut params = HashMap::new();
+        params.insert("degrees".to_string(), degree_list(&[6; 8]));
+        params.insert(
+            "remove_self_loops".to_string(),
+            MetadataValue::Boolean(true),
+        );
+        params.insert(
+            "remove_multi_edges".to_string(),
+            MetadataValue::Boolean(true),
+        );
+
+        for seed in 0..10 {
+            params.insert("seed".to_string(), MetadataValue::Integer(seed));
+            let graph = generate_configuration_model(&params).unwrap();
+            let mut pairs = std::collections::HashSet::new();
+            for edge in graph.edges.values() {
+                assert_ne!(edge.source, edge.target);
+                assert!(pairs.insert((edge.source.clone(), edge.target.clone())));
+            }
+            assert!(realised_degrees(&graph).iter().all(|&d| d <= 6));
+        }
+    }
+
+    #[test]
+    fn test_configuration_model_distributions() {
+        for (distribution, extra) in [
+            ("regular", ("degree", MetadataValue::Integer(3))),
+            ("uniform", ("max_degree", MetadataValue::Integer(5))),
+            ("poisson", ("mean", MetadataValue::Float(3.0))),
+            ("powerlaw", ("exponent", MetadataValue::Float(2.3))),
+        ] {
+            let mut params = HashMap::new();
+            params.insert(
+                "distribution".to_string(),
+                MetadataValue::String(distribution.to_string()),
+            );
+            params.insert("nodes".to_string(), MetadataValue::Integer(200));
+            params.insert(extra.0.to_string(), extra.1);
+            params.insert("seed".to_string(), MetadataValue::Integer(12));
+
+            let graph = generate_configuration_model(&params).unwrap();
+            assert_eq!(graph.node_count(), 200, "{}", distribution);
+            let target: usize = graph
+                .nodes
+                .values()
+                .map(|n| match n.metadata.get("degree") {
+                    Some(MetadataValue::Integer(d)) => *d as usize,
+                    other => panic!("missing degree: {:?}", other),
+                })
+                .sum();
+            assert_eq!(graph.edge_count() * 2, target, "{}", distribution);
+        }
+    }
+
+    #[test]
+    fn test_configuration_model_invalid() {
+        let mut params = HashMap::new();
+        assert!(generate_configuration_model(&params).is_err());
+
+        params.insert("degrees".to_string(), degree_list(&[1, 1, 1]));
+        assert!(generate_configuration_model(&params).is_err());
+
+        params.remove("degrees");
+        params.insert(
+            "distribution".to_string(),
+            MetadataValue::String("zipf".to_string()),
+        );
+        params.insert("nodes".to_string(), MetadataValue::Integer(10));
+        assert!(generate_configuration_model(&params).is_err());
+
+        params.insert(
+            "distribution".to_string(),
+            MetadataValue::String("regular".to_string()),
+        );
+        params.insert("nodes".to_string(), MetadataValue::Integer(5));
+        params.insert("degree".to_string(), MetadataValue::Integer(3));
+        assert!(generate_configuration_model(&params).is_err());
+    }
+
+    #[test]
+    fn test_havel_hakimi_realises_sequence() {
+        let sequence = [4, 3, 3, 3, 2, 2, 2, 1];
+        let mut params = HashMap::new();
+        params.insert("degrees".to_string(), degree_list(&sequence));
+
+        let graph = generate_havel_hakimi(&params).unwrap();
+        let expected: Vec<usize> = sequence.iter().map(|&d| d as usize).collect();
+        assert_eq!(realised_degrees(&graph), expected);
+
+        // Deterministic: the same sequence always gives the same edges
+        let again = generate_havel_hakimi(&params).unwrap();
+        let mut a: Vec<_> = graph.edges.keys().collect();
+        let mut b: Vec<_> = again.edges.keys().collect();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_havel_hakimi_rejects_non_graphical() {
+        for (sequence, reason) in [
+            (&[3, 3, 3, 1][..], "not graphical"),
+            (&[4, 4, 2, 1, 1][..], "not graphical"),
+            (&[2, 2, 1][..], "odd"),
+            (&[5, 1, 1, 1][..], "only 3 other nodes"),
+        ] {
+            let mut params = HashMap::new();
+            params.insert("degrees".to_string(), degree_list(sequence));
+            let err = generate_havel_hakimi(&params).unwrap_err();
+            assert!(err.contains(reason), "{:?}: {}", sequence, err);
+        }
+
+        // A star plus an isolated node is graphical
+        let mut params = HashMap::new();
+        params.insert("degrees".to_string(), degree_list(&[4, 1, 1, 1, 1, 0]));
+        assert_eq!(generate_havel_hakimi(&params).unwrap().edge_count(), 4);
+    }
+}
+
+#[cfg(test)]
+mod kronecker_tests {
+    use super::*;
+
+    fn matrix(rows: &[&[f64]]) -> MetadataValue {
+        MetadataValue::List(
+            rows.iter()
+                .map(|row| {
+                    MetadataValue::List(row.iter().map(|&p| MetadataValue::Float(p)).collect())
+                })
+                .collect(),
+        )
+    }
+
+    fn out_degrees(graph: &Graph) -> HashMap<&str, usize> {
+        let mut degrees = HashMap::new();
+        for edge in graph.edges.values() {
+            *degrees.entry(edge.source.as_str()).or_default() += 1;
+        }
+        degrees
+    }
+
+    #[test]
+    fn test_rmat_sizes_and_skew() {
+        let mut params = int_params(&[("scale", 10), ("edge_factor", 8)]).seed(500);
+        params.insert("permute".to_string(), MetadataValue::Boolean(false));
+        let graph = generate_rmat(&params).unwrap();
+        assert_eq!(graph.node_count(), 1024);
+        assert_eq!(graph.edge_count(), 8192);
+
+        // Without permutation the heaviest quadrant keeps node 0 the top source
+        let degrees = out_degrees(&graph);
+        let max = *degrees.values().max().unwrap();
+        assert_eq!(degrees["n0"], max);
+        assert!(
+            max > 8 * 10,
+            "expected a heavy-tailed out-degree, max {}",
+            max
+        );
+    }
+
+    #[test]
+    fn test_rmat_simple_graph_options() {
+        let mut params = int_params(&[("scale", 8), ("edge_factor", 16)]).seed(500);
+        params.insert(
+            "remove_self_loops".to_string(),
+            MetadataValue::Boolean(true),
+        );
+        params.insert(
+            "remove_multi_edges".to_string(),
+            MetadataValue::Boolean(true),
+        );
+        let graph = generate_rmat(&params).unwrap();
+
+        let mut pairs = std::collections::HashSet::new();
+        for edge in graph.edges.values() {
+            assert_ne!(edge.source, edge.target);
+            assert!(pairs.insert((&edge.source, &edge.target)));
+        }
+        assert!(graph.edge_count() < 4096);
+    }
+
+    #[test]
+    fn test_rmat_reproducible_and_validated() {
+        let a = generate_rmat(&int_params(&[("scale", 6), ("edge_factor", 4)]).seed(500)).unwrap();
+        let b = generate_rmat(&int_params(&[("scale", 6), ("edge_factor", 4)]).seed(500)).unwrap();
+        let mut ids_a: Vec<_> = a.edges.keys().collect();
+        let mut ids_b: Vec<_> = b.edges.keys().collect();
+        ids_a.sort();
+        ids_b.sort();
+        assert_eq!(ids_a, ids_b);
+
+        let mut params = int_params(&[("scale", 6), ("edge_factor", 4)]).seed(500);
+        params.insert("a".to_string(), MetadataValue::Float(0.9));
+        assert!(generate_rmat(&params).is_err());
+    }
+
+    #[test]
+    fn test_rmat_rejects_out_of_range_scale() {
+        for scale in [27, 32, 64] {
+            let err = generate_rmat(&int_params(&[("scale", scale)])).unwrap_err();
+            assert_eq!(err, format!("scale must be at most 26, got {}", scale));
+        }
+        // The largest scale still fits the edge limit only with fewer edges per node
+        let err = generate_rmat(&int_params(&[("scale", 26), ("edge_factor", 16)])).unwrap_err();
+        assert!(err.contains("more than 1000000000 edges"), "{}", err);
+    }
+
+    #[test]
+    fn test_rmat_large() {
+        let start = std::time::Instant::now();
+        let graph =
+            generate_rmat(&int_params(&[("scale", 14), ("edge_factor", 16)]).seed(500)).unwrap();
+        assert_eq!(graph.node_count(), 16_384);
+        assert_eq!(graph.edge_count(), 262_144);
+        assert!(start.elapsed().as_secs() < 30);
+    }
+
+    #[test]
+    fn test_stochastic_kronecker() {
+        let mut params = HashMap::new();
+        params.insert("initiator".to_string(), matrix(&[&[0.9, 0.6], &[0.6, 0.2]]));
+        params.insert("power".to_string(), MetadataValue::Integer(10));
+        params.insert("seed".to_string(), MetadataValue::Integer(3));
+
+        let graph = generate_stochastic_kronecker(&params).unwrap();
+        assert_eq!(graph.node_count(), 1024);
+        // (0.9 + 0.6 + 0.6 + 0.2)^10 ≈ 4142.65
+        assert_eq!(graph.edge_count(), 4143);
+        assert!(graph.edges.keys().all(|id| id.matches('_').count() == 1));
+    }
+
+    #[test]
+    fn test_stochastic_kronecker_three_by_three() {
+        let mut params = HashMap::new();
+        params.insert(
+            "initiator".to_string(),
+            matrix(&[&[0.9, 0.5, 0.1], &[0.5, 0.5, 0.1], &[0.1, 0.1, 0.9]]),
+        );
+        params.insert("power".to_string(), MetadataValue::Integer(4));
+        params.insert("seed".to_string(), MetadataValue::Integer(3));
+        let graph = generate_stochastic_kronecker(&params).unwrap();
+        assert_eq!(graph.node_count(), 81);
+
+        params.insert("initiator".to_string(), matrix(&[&[0.9, 0.5]]));
+        assert!(generate_stochastic_kronecker(&params).is_err());
+    }
+}
+
+#[cfg(test)]
+mod delaunay_tests {
+    use super::*;
+    use std::collections::{HashSet, VecDeque};
+
+    fn delaunay(nodes: i64, prune: &str) -> Graph {
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(nodes));
+        params.insert(
+            "prune".to_string(),
+            MetadataValue::String(prune.to_string()),
+        );
+        params.insert("seed".to_string(), MetadataValue::Integer(8));
+        generate_delaunay(&params).unwrap()
+    }
+
+    fn edge_set(graph: &Graph) -> HashSet<String> {
+        graph.edges.keys().cloned().collect()
+    }
+
+    fn is_connected(graph: &Graph) -> bool {
+        let mut adjacency: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in graph.edges.values() {
+            adjacency
+                .entry(&edge.source)
+                .or_default()
+                .push(&edge.target);
+            adjacency
+                .entry(&edge.target)
+                .or_default()
+                .push(&edge.source);
+        }
+        let mut seen = HashSet::from(["n0"]);
+        let mut queue = VecDeque::from(["n0"]);
+        while let Some(node) = queue.pop_front() {
+            for &next in adjacency.get(node).into_iter().flatten() {
+                if seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        seen.len() == graph.node_count()
+    }
+
+    fn point(x: f64, y: f64) -> MetadataValue {
+        MetadataValue::List(vec![MetadataValue::Float(x), MetadataValue::Float(y)])
+    }
+
+    #[test]
+    fn test_delaunay_is_planar_triangulation() {
+        let graph = delaunay(200, "none");
+        assert_eq!(graph.node_count(), 200);
+        assert!(graph.edge_count() <= 3 * 200 - 6);
+        // A triangulation with h hull points has 3n - 3 - h edges
+        assert!(graph.edge_count() > 3 * 200 - 3 - 40);
+        assert!(is_connected(&graph));
+
+        for edge in graph.edges.values() {
+            let (u, v) = (&graph.nodes[&edge.source], &graph.nodes[&edge.target]);
+            let (ux, uy) = (u.x, u.y);
+            let (vx, vy) = (v.x, v.y);
+            let expected = ((ux - vx).powi(2) + (uy - vy).powi(2)).sqrt();
+            match edge.metadata.get("length") {
+                Some(MetadataValue::Float(length)) => assert!((length - expected).abs() < 1e-12),
+                other => panic!("expected a float length, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_pruned_graphs_are_nested_and_connected() {
+        let full = edge_set(&delaunay(300, "none"));
+        let gabriel_graph = delaunay(300, "gabriel");
+        let rng_graph = delaunay(300, "relative_neighborhood");
+        let gabriel = edge_set(&gabriel_graph);
+        let relative = edge_set(&rng_graph);
+
+        assert!(gabriel.is_subset(&full) && gabriel.len() < full.len());
+        assert!(relative.is_subset(&gabriel) && relative.len() < gabriel.len());
+        assert!(is_connected(&gabriel_graph));
+        assert!(is_connected(&rng_graph));
+    }
+
+    #[test]
+    fn test_delaunay_given_points() {
+        let mut params = HashMap::new();
+        params.insert(
+            "points".to_string(),
+            MetadataValue::List(vec![
+                point(0.0, 0.0),
+                point(4.0, 0.0),
+                point(0.0, 3.0),
+                point(4.0, 3.0),
+                point(2.0, 1.5),
+            ]),
+        );
+        let graph = generate_delaunay(&params).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 8);
+        assert_eq!(graph.nodes["n4"].x, 2.0);
+        assert_eq!(
+            graph.edges["e0_4"].metadata.get("length"),
+            Some(&MetadataValue::Float(2.5))
+        );
+    }
+
+    #[test]
+    fn test_delaunay_invalid_params() {
+        let mut params = HashMap::new();
+        params.insert(
+            "points".to_string(),
+            MetadataValue::List(vec![point(0.0, 0.0), point(1.0, 1.0), point(0.0, 0.0)]),
+        );
+        assert!(generate_delaunay(&params).unwrap_err().contains("distinct"));
+
+        params.insert(
+            "points".to_string(),
+            MetadataValue::List(vec![MetadataValue::List(vec![MetadataValue::Float(1.0)])]),
+        );
+        assert!(generate_delaunay(&params).is_err());
+
+        let mut params = HashMap::new();
+        params.insert("nodes".to_string(), MetadataValue::Integer(10));
+        params.insert(
+            "prune".to_string(),
+            MetadataValue::String("mst".to_string()),
+        );
+        assert!(generate_delaunay(&params).is_err());
+    }
+}
+
+#[cfg(test)]
+mod generator_registry_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_generator_valid() {
+        assert!(get_generator("complete").is_some());
+        assert!(get_generator("path").is_some());
+        assert!(get_generator("cycle").is_some());
+        assert!(get_generator("grid").is_some());
+        assert!(get_generator("star").is_some());
+        assert!(get_generator("tree").is_some());
+        assert!(get_generator("barabasi_albert").is_some());
+    }
+
+    #[test]
+    fn test_get_generator_invalid() {
+        assert!(get_generator("nonexistent").is_none());
+        assert!(get_generator("").is_none());
+        assert!(get_generator("COMPLETE").is_none()); // Case sensitive
+    }
+
+    #[test]
+    fn test_all_generators_callable() {
+        let generators = [
+            "complete",
+            "path",
+            "cycle",
+            "grid",
+            "star",
+            "tree",
+            "barabasi_albert",
+            "watts_strogatz",
+            "newman_watts",
+        ];
+
+        for gen_name in generators {
+            let generator = get_generator(gen_name).unwrap();
+
+            // Test with minimal valid parameters
+            let mut params = HashMap::new();
+            match gen_name {
+                "complete" | "path" | "cycle" | "star" => {
+                    params.insert("nodes".to_string(), MetadataValue::Integer(3));
+                }
+                "grid" => {
+                    params.insert("rows".to_string(), MetadataValue::Integer(2));
+                    params.insert("cols".to_string(), MetadataValue::Integer(2));
+                }
+                "tree" => {
+                    params.insert("branching".to_string(), MetadataValue::Integer(2));
+                    params.insert("depth".to_string(), MetadataValue::Integer(2));
+                }
+                "barabasi_albert" => {
+                    params.insert("nodes".to_string(), MetadataValue::Integer(5));
+                    params.insert("edges_per_node".to_string(), MetadataValue::Integer(2));
+                }
+                "watts_strogatz" | "newman_watts" => {
+                    params.insert("nodes".to_string(), MetadataValue::Integer(6));
+                    params.insert("k".to_string(), MetadataValue::Integer(2));
+                }
+                _ => unreachable!(),
+            }
+
+            let result = generator(&params);
+            assert!(
+                result.is_ok(),
+                "Generator {} failed with minimal params: {:?}",
+                gen_name,
+                result.err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_lists_builtins() {
+        let registry = GeneratorRegistry::default();
+        for name in ["complete", "grid", "barabasi_albert", "delaunay", "rmat"] {
+            let generator = registry.get(name).unwrap();
+            assert_eq!(generator.name(), name);
+            assert!(!generator.description().is_empty());
+            assert!(generator
+                .params()
+                .iter()
+                .any(|param| param.name == "prefix"));
+        }
+        assert!(registry.names().all(|name| get_generator(name).is_some()));
+
+        let spec = registry
+            .get("watts_strogatz")
+            .unwrap()
+            .params()
+            .into_iter()
+            .find(|param| param.name == "p")
+            .unwrap();
+        assert_eq!(spec.param_type, ParamType::Float);
+        assert_eq!(spec.default, Some(MetadataValue::Float(0.1)));
+    }
+
+    #[test]
+    fn test_registry_usage_and_json_schema() {
+        let registry = GeneratorRegistry::default();
+
+        let usage = registry.usage();
+        let schema = registry.json_schema();
+        let definitions = schema["definitions"].as_object().unwrap();
+        assert_eq!(definitions.len(), registry.len());
+
+        for generator in registry.iter() {
+            assert!(usage.contains(&format!(
+                "{} - {}",
+                generator.name(),
+                generator.description()
+            )));
+
+            let definition = &definitions[generator.name()];
+            assert_eq!(definition["type"], "object");
+            let properties = definition["properties"].as_object().unwrap();
+            assert_eq!(properties.len(), generator.params().len());
+            for spec in generator.params() {
+                let property = &properties[&spec.name];
+                assert_eq!(property["description"], spec.description.as_str());
+                let required = definition["required"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&spec.name.as_str().into());
+                assert_eq!(
+                    required,
+                    spec.required,
+                    "{}.{}",
+                    generator.name(),
+                    spec.name
+                );
+            }
+        }
+
+        let sbm = &definitions["stochastic_block_model"]["properties"];
+        assert_eq!(sbm["sizes"]["items"]["type"], "integer");
+        assert_eq!(sbm["probabilities"]["items"]["items"]["maximum"], 1.0);
+        assert_eq!(sbm["directed"]["default"], false);
+        assert_eq!(definitions["wheel"]["properties"]["nodes"]["minimum"], 4);
+    }
+
+    #[test]
+    fn test_schema_defaults_match_generators() {
+        let registry = GeneratorRegistry::default();
+        let cases: Vec<(&str, Vec<(&str, MetadataValue)>)> = vec![
+            ("cycle", vec![("nodes", MetadataValue::Integer(5))]),
+            (
+                "grid",
+                vec![
+                    ("rows", MetadataValue::Integer(3)),
+                    ("cols", MetadataValue::Integer(4)),
+                ],
+            ),
+            ("fat_tree", vec![("k", MetadataValue::Integer(4))]),
+            (
+                "torus",
+                vec![(
+                    "dims",
+                    MetadataValue::List(vec![MetadataValue::Integer(3), MetadataValue::Integer(3)]),
+                )],
+            ),
+            (
+                "watts_strogatz",
+                vec![
+                    ("nodes", MetadataValue::Integer(20)),
+                    ("k", MetadataValue::Integer(4)),
+                    ("seed", MetadataValue::Integer(1)),
+                ],
+            ),
+            (
+                "rmat",
+                vec![
+                    ("scale", MetadataValue::Integer(5)),
+                    ("seed", MetadataValue::Integer(1)),
+                ],
+            ),
+        ];
+
+        for (name, entries) in cases {
+            let generator = registry.get(name).unwrap();
+            let params: HashMap<String, MetadataValue> = entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect();
+            let mut filled = params.clone();
+            for param in generator.params() {
+                if let Some(default) = param.default {
+                    filled.entry(param.name).or_insert(default);
+                }
+            }
+
+            let plain = generator.generate(&params).unwrap();
+            let defaulted = generator.generate(&filled).unwrap();
+            let mut plain_edges: Vec<_> = plain.edges.keys().collect();
+            let mut defaulted_edges: Vec<_> = defaulted.edges.keys().collect();
+            plain_edges.sort();
+            defaulted_edges.sort();
+            assert_eq!(plain_edges, defaulted_edges, "{}", name);
+            assert_eq!(plain.node_count(), defaulted.node_count(), "{}", name);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -305,6 +305,272 @@ mod generator_integration_tests {
         assert!(edge_count <= 45); // Maximum for 10 nodes
     }
 
+    #[test]
+    fn test_watts_strogatz_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph test {
+                generate watts_strogatz {
+                    nodes: 20;
+                    k: 4;
+                    p: 0.2;
+                    seed: 42;
+                    prefix: "ws";
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 20);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 40);
+        assert!(graph["nodes"]["ws19"].is_object());
+    }
+
+    #[test]
+    fn test_stochastic_block_model_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph test {
+                generate stochastic_block_model {
+                    sizes: [3, 2];
+                    probabilities: [[1.0, 0.0], [0.0, 1.0]];
+                    block_types: ["left", "right"];
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 5);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 4);
+        assert_eq!(graph["nodes"]["n4"]["type"], "right");
+        assert_eq!(graph["nodes"]["n4"]["metadata"]["block"], 1);
+    }
+
+    #[test]
+    fn test_random_geometric_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph sensors {
+                generate random_geometric {
+                    nodes: 30;
+                    radius: 0.4;
+                    prefix: "s";
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 30);
+        for edge in graph["edges"].as_object().unwrap().values() {
+            assert!(edge["metadata"]["weight"].as_f64().unwrap() <= 0.4);
+        }
+    }
+
+    #[test]
+    fn test_navigable_small_world_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph routing {
+                generate kleinberg {
+                    rows: 10;
+                    cols: 10;
+                    q: 2;
+                    prefix: "k";
+                }
+                generate hyperbolic_random_graph {
+                    nodes: 200;
+                    average_degree: 6;
+                    exponent: 2.5;
+                    prefix: "h";
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let nodes = graph["nodes"].as_object().unwrap();
+        assert_eq!(nodes.len(), 300);
+        assert!(nodes["h0"]["metadata"]["radius"].is_f64());
+        assert_eq!(nodes["k3_4"]["metadata"]["col"], 4);
+
+        let edges = graph["edges"].as_object().unwrap();
+        let long_range = edges
+            .values()
+            .filter(|edge| edge["type"] == "long_range")
+            .count();
+        assert_eq!(long_range, 200);
+    }
+
+    #[test]
+    fn test_torus_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph cluster {
+                generate torus {
+                    dims: [4, 4, 4];
+                    prefix: "cpu";
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 64);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 192);
+        assert_eq!(
+            graph["nodes"]["cpu1_2_3"]["metadata"]["coords"],
+            serde_json::json!([1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_lattice_generation_sets_positions() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph mesh {
+                generate hexagonal_lattice {
+                    rows: 3;
+                    cols: 3;
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 18);
+        assert_eq!(graph["nodes"]["n0_0_1"]["y"].as_f64(), Some(1.0));
+    }
+
+    #[test]
+    fn test_named_graph_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph fixture {
+                generate named {
+                    name: "petersen";
+                    prefix: "p";
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 10);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 15);
+        assert!(graph["nodes"]["p9"].is_object());
+    }
+
+    #[test]
+    fn test_random_forest_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph org {
+                generate random_forest {
+                    nodes: 40;
+                    trees: 3;
+                    prefix: "emp";
+                    seed: 9;
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let graph: Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 40);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 37);
+        for node in graph["nodes"].as_object().unwrap().values() {
+            assert!(node["metadata"]["depth"].is_i64());
+        }
+    }
+
+    #[test]
+    fn test_havel_hakimi_error_is_reported() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph impossible {
+                generate havel_hakimi {
+                    degrees: [3, 3, 3, 1];
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code);
+        assert!(result.unwrap_err().contains("not graphical"));
+    }
+
+    #[test]
+    fn test_rmat_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph benchmark {
+                generate rmat {
+                    scale: 8;
+                    edge_factor: 4;
+                    seed: 42;
+                }
+            }
+        "#;
+
+        let first = engine.generate_from_ggl(ggl_code).unwrap();
+        let second = engine.generate_from_ggl(ggl_code).unwrap();
+        assert_eq!(first, second);
+
+        let graph: Value = serde_json::from_str(&first).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 256);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 1024);
+    }
+
+    #[test]
+    fn test_delaunay_generation() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph roads {
+                generate delaunay {
+                    nodes: 30;
+                    prune: "gabriel";
+                    seed: 1;
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 30);
+        let edges = graph["edges"].as_object().unwrap();
+        assert!(edges.len() >= 29 && edges.len() <= 3 * 30 - 6);
+        assert!(edges
+            .values()
+            .all(|edge| edge["metadata"]["length"].is_f64()));
+    }
+
     #[test]
     fn test_invalid_generator() {
         let mut engine = GGLEngine::new();
@@ -339,6 +605,113 @@ mod generator_integration_tests {
         let result = engine.generate_from_ggl(ggl_code);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_generator_attribute_templates() {
+        let ggl_code = r#"
+            graph test {
+                generate barabasi_albert {
+                    nodes: 50;
+                    edges_per_node: 2;
+                    node_type: "server";
+                    node_attrs: [cpu = uniform_int(2, 64), zone = choice("a", "b", "c"), rack = 1];
+                    edge_type: "link";
+                    edge_attrs: [latency = normal(5.0, 1.0)];
+                }
+                generate star { nodes: 3; prefix: "hub"; }
+            }
+        "#;
+        let run = |seed: u64| {
+            let mut engine = GGLEngine::new();
+            engine.set_seed(seed);
+            engine.generate_from_ggl(ggl_code).unwrap()
+        };
+        let json = run(7);
+        assert_eq!(json, run(7));
+        assert_ne!(json, run(8));
+
+        let graph: Value = serde_json::from_str(&json).unwrap();
+        let mut zones = std::collections::HashSet::new();
+        for (id, node) in graph["nodes"].as_object().unwrap() {
+            if id.starts_with("hub") {
+                // Templates only label the output of their own statement
+                assert_eq!(node["type"], "");
+                assert!(node["metadata"].as_object().unwrap().is_empty());
+                continue;
+            }
+            assert_eq!(node["type"], "server");
+            let cpu = node["metadata"]["cpu"].as_i64().unwrap();
+            assert!((2..=64).contains(&cpu));
+            assert_eq!(node["metadata"]["rack"], 1);
+            zones.insert(node["metadata"]["zone"].as_str().unwrap().to_string());
+        }
+        assert_eq!(zones.len(), 3);
+
+        for edge in graph["edges"].as_object().unwrap().values() {
+            if edge["source"].as_str().unwrap().starts_with("hub") {
+                continue;
+            }
+            assert_eq!(edge["type"], "link");
+            assert!(edge["metadata"]["latency"].is_f64());
+        }
+    }
+
+    #[test]
+    fn test_generator_param_validation() {
+        let mut engine = GGLEngine::new();
+        let run = |engine: &mut GGLEngine, params: &str| {
+            let ggl_code = format!("graph test {{\n    generate complete {{ {} }}\n}}", params);
+            engine.generate_from_ggl(&ggl_code)
+        };
+
+        // A misspelt parameter is rejected instead of silently ignored
+        let error = run(&mut engine, "node: 5;").unwrap_err();
+        assert_eq!(
+            error,
+            "Generator error at line 2, column 5: unknown parameter node for complete, did you mean nodes?"
+        );
+
+        let error = run(&mut engine, "nodes: 5; prefix: 5;").unwrap_err();
+        assert!(error.contains("parameter prefix must be a string, got integer 5"));
+
+        let error = run(&mut engine, "nodes: 2.5;").unwrap_err();
+        assert!(error.contains("parameter nodes must be an integer, got float 2.5"));
+
+        let error = run(&mut engine, "nodes: -3;").unwrap_err();
+        assert!(error.contains("parameter nodes must be at least 0, got -3"));
+
+        let error = run(&mut engine, "directed: true;").unwrap_err();
+        assert!(error.contains("missing required parameter nodes (integer)"));
+
+        let error = engine
+            .generate_from_ggl("graph { generate watts_strogatz { nodes: 10; k: 2; p: 1.5; } }")
+            .unwrap_err();
+        assert!(error.contains("parameter p must be at most 1, got 1.5"));
+
+        let error = engine
+            .generate_from_ggl("graph { generate compelte { nodes: 5; } }")
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Unknown generator: compelte at line 1, column 9, did you mean complete?"
+        );
+
+        // Deterministic generators do not accept a seed
+        assert!(run(&mut engine, "nodes: 3; seed: 1;")
+            .unwrap_err()
+            .contains("unknown parameter seed"));
+        assert!(run(&mut engine, "nodes: 3; prefix: \"v\"; directed: true;").is_ok());
+
+        // Generators that need at least one node reject empty sizes instead of panicking
+        let error = engine
+            .generate_from_ggl("graph { generate path { nodes: 0; } }")
+            .unwrap_err();
+        assert!(error.contains("parameter nodes must be at least 1, got 0"));
+        let error = engine
+            .generate_from_ggl("graph { generate grid { rows: 0; cols: 3; } }")
+            .unwrap_err();
+        assert!(error.contains("parameter rows must be at least 1, got 0"));
+    }
 }
 
 #[cfg(test)]
@@ -678,6 +1051,651 @@ mod rule_integration_tests {
     }
 }
 
+#[cfg(test)]
+mod generator_registry_integration_tests {
+    use super::*;
+    use graph_generation_language::generators::{
+        generate_path, FnGenerator, Generator, ParamSpec, ParamType,
+    };
+    use graph_generation_language::types::{Graph, MetadataValue, Node};
+    use std::collections::HashMap;
+
+    /// Places a hub node with a configurable name and type.
+    struct Hub;
+
+    impl Generator for Hub {
+        fn name(&self) -> &str {
+            "hub"
+        }
+
+        fn description(&self) -> &str {
+            "A single hub node"
+        }
+
+        fn params(&self) -> Vec<ParamSpec> {
+            vec![ParamSpec::new("role", ParamType::String, "Node type")
+                .with_default(MetadataValue::String("core".to_string()))]
+        }
+
+        fn generate(&self, params: &HashMap<String, MetadataValue>) -> Result<Graph, String> {
+            let Some(MetadataValue::String(role)) = params.get("role") else {
+                return Err("role must be a string".to_string());
+            };
+            let mut graph = Graph::new();
+            graph.add_node(Node::new("hub".to_string()).with_type(role.clone()));
+            Ok(graph)
+        }
+    }
+
+    #[test]
+    fn test_custom_generator_with_defaults() {
+        let mut engine = GGLEngine::new();
+        engine.register_generator(Hub);
+
+        let ggl_code = r#"
+            graph test {
+                generate hub {}
+                generate path { nodes: 2; }
+                edge: hub -- n0;
+            }
+        "#;
+        let graph: Value =
+            serde_json::from_str(&engine.generate_from_ggl(ggl_code).unwrap()).unwrap();
+        assert_eq!(graph["nodes"]["hub"]["type"], "core");
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 3);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 2);
+
+        // Other engines are unaffected
+        let result = GGLEngine::new().generate_from_ggl(ggl_code);
+        assert!(result.unwrap_err().contains("Unknown generator: hub"));
+    }
+
+    #[test]
+    fn test_registered_generator_overrides_builtin() {
+        let mut engine = GGLEngine::new();
+        engine.register_generator(
+            FnGenerator::new("cycle", "Paths in disguise", generate_path).with_param(
+                ParamSpec::new("nodes", ParamType::Integer, "Number of nodes").required(),
+            ),
+        );
+        assert_eq!(
+            engine.generators().get("cycle").unwrap().description(),
+            "Paths in disguise"
+        );
+
+        let json = engine
+            .generate_from_ggl("graph { generate cycle { nodes: 4; } }")
+            .unwrap();
+        let graph: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod graph_operation_integration_tests {
+    use super::*;
+
+    #[test]
+    fn test_let_and_operation_statements() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph fabric {
+                let bit = generate path { nodes: 2; prefix: "b"; }
+                let cube = cartesian(bit, bit, bit);
+                let ring = generate cycle { nodes: 4; prefix: "r"; }
+
+                join(cube, generate star { nodes: 1; prefix: "hub"; });
+                union(ring, generate path { nodes: 3; prefix: "p"; });
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let nodes = graph["nodes"].as_object().unwrap();
+
+        // 8 cube nodes and the hub, then the ring and the path
+        assert_eq!(nodes.len(), 9 + 4 + 3);
+        assert!(nodes.contains_key("b0_b1_b0"));
+        assert!(nodes.contains_key("hub0"));
+        assert!(!nodes.contains_key("b0"), "let must not add its graph");
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 12 + 8 + 4 + 2);
+    }
+
+    #[test]
+    fn test_operation_errors() {
+        let mut engine = GGLEngine::new();
+
+        let error = engine
+            .generate_from_ggl("graph {\n  catresian(a, b);\n}")
+            .unwrap_err();
+        assert!(
+            error.contains(
+                "Unknown operation: catresian at line 2, column 3, did you mean cartesian?"
+            ),
+            "{}",
+            error
+        );
+
+        let error = engine
+            .generate_from_ggl("graph { tensor(a, b); }")
+            .unwrap_err();
+        assert!(error.contains("Unknown graph: a"), "{}", error);
+
+        let error = engine
+            .generate_from_ggl("graph { let a = generate path { nodes: 2; } union(a, a); }")
+            .unwrap_err();
+        assert!(error.contains("Operation error at line 1"), "{}", error);
+        assert!(error.contains("different prefixes"), "{}", error);
+    }
+}
+
+#[cfg(test)]
+mod subgraph_integration_tests {
+    use super::*;
+    use graph_generation_language::export::{to_dot, to_graphml};
+
+    const DATACENTER: &str = r#"
+        graph dc {
+            node core :switch;
+            subgraph cluster_a {
+                node gw :router;
+                generate star { nodes: 3; prefix: "h"; }
+                edge: gw -> h0;
+                subgraph rack1 {
+                    node tor;
+                    edge up: tor -> gw;
+                }
+            }
+            subgraph cluster_b {
+                node gw :router;
+                edge: gw -> core;
+            }
+            edge wan: cluster_a.gw -> cluster_b.gw;
+        }
+    "#;
+
+    #[test]
+    fn test_subgraph_membership_and_addressing() {
+        let mut engine = GGLEngine::new();
+        let result = engine.generate_from_ggl(DATACENTER).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let nodes = graph["nodes"].as_object().unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        assert_eq!(nodes["cluster_a.gw"]["subgraph"], "cluster_a");
+        assert_eq!(nodes["cluster_a.h2"]["subgraph"], "cluster_a");
+        assert_eq!(nodes["cluster_a.rack1.tor"]["subgraph"], "cluster_a.rack1");
+        assert!(nodes["core"].get("subgraph").is_none());
+
+        // Names resolve to the innermost subgraph declaring them, then outwards
+        assert_eq!(edges["cluster_a.rack1.up"]["target"], "cluster_a.gw");
+        assert_eq!(edges["cluster_b.egw_core"]["source"], "cluster_b.gw");
+        assert_eq!(edges["cluster_b.egw_core"]["target"], "core");
+        assert_eq!(edges["cluster_a.e0_1"]["source"], "cluster_a.h1");
+        assert_eq!(edges["wan"]["source"], "cluster_a.gw");
+        assert_eq!(edges["wan"]["target"], "cluster_b.gw");
+    }
+
+    #[test]
+    fn test_subgraphs_in_dot_and_graphml() {
+        let mut engine = GGLEngine::new();
+        engine.generate_from_ggl(DATACENTER).unwrap();
+
+        let dot = to_dot(engine.graph(), true);
+        assert!(dot.contains("    subgraph \"cluster_a\" {\n        label=\"cluster_a\";\n"));
+        assert!(dot.contains("        subgraph \"cluster_a.rack1\" {\n            label=\"rack1\";\n            \"cluster_a.rack1.tor\";\n"));
+        assert!(dot.contains("\"cluster_a.gw\" -> \"cluster_b.gw\" [\"id\"=\"wan\"];"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+
+        let graphml = to_graphml(engine.graph(), false).unwrap();
+        assert!(graphml.contains("<node id=\"cluster_b\">\n      <graph id=\"cluster_b:\" edgedefault=\"undirected\">\n        <node id=\"cluster_b.gw\">"));
+        assert!(
+            graphml.contains("<edge id=\"wan\" source=\"cluster_a.gw\" target=\"cluster_b.gw\">")
+        );
+        assert_eq!(
+            graphml.matches("<graph ").count(),
+            graphml.matches("</graph>").count()
+        );
+    }
+
+    #[test]
+    fn test_rules_keep_subgraph_membership() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph g {
+                subgraph dc { node gw :leaf; }
+                rule promote {
+                    lhs { node N :leaf; }
+                    rhs { node N :hub; }
+                }
+                apply promote 1 times;
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(graph["nodes"]["dc.gw"]["type"], "hub");
+        assert_eq!(graph["nodes"]["dc.gw"]["subgraph"], "dc");
+
+        let dot = to_dot(engine.graph(), false);
+        assert!(
+            dot.contains("    subgraph \"cluster_dc\" {\n        label=\"dc\";\n        \"dc.gw\"")
+        );
+    }
+}
+
+#[cfg(test)]
+mod selector_edge_integration_tests {
+    use super::*;
+
+    #[test]
+    fn test_connect_generated_cluster_to_hub() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph network {
+                node core :switch;
+                subgraph hosts {
+                    generate path { nodes: 4; prefix: "h"; }
+                }
+                edge uplink: hosts.* -> core [speed=10];
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        assert_eq!(edges.len(), 3 + 4);
+        for i in 0..4 {
+            let edge = &edges[&format!("uplink_hosts.h{}_core", i)];
+            assert_eq!(edge["source"], format!("hosts.h{}", i));
+            assert_eq!(edge["target"], "core");
+            assert_eq!(edge["metadata"]["speed"], 10);
+        }
+    }
+
+    #[test]
+    fn test_attribute_selectors_give_cartesian_edges() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph racks {
+                node s1 :server;
+                node s2 :server;
+                node a :switch [zone="a"];
+                node b :switch [zone="b"];
+                node c :switch [zone="a"];
+                edge: [type=server] -- [type=switch, zone="a"];
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        let mut ids: Vec<&String> = edges.keys().collect();
+        ids.sort();
+        assert_eq!(ids, vec!["es1_a", "es1_c", "es2_a", "es2_c"]);
+    }
+
+    #[test]
+    fn test_selectors_inside_subgraph_and_self_loops() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph mesh {
+                node h9;
+                subgraph pod {
+                    node h0;
+                    node h1;
+                    node h2;
+                    edge: h* -- h*;
+                }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        // One edge per pair in the pod, but no loops and nothing to h9
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges["pod.epod.h0_pod.h1"]["target"], "pod.h1");
+        assert!(!edges.contains_key("pod.epod.h1_pod.h0"));
+        assert!(edges.values().all(|edge| edge["source"] != edge["target"]));
+        assert!(edges.values().all(|edge| edge["target"] != "h9"));
+    }
+
+    #[test]
+    fn test_directed_selectors_connect_both_ways() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph mesh {
+                node h0;
+                node h1;
+                node h2;
+                edge: h* -> h*;
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        assert_eq!(edges.len(), 6);
+        assert_eq!(edges["eh1_h0"]["source"], "h1");
+        assert_eq!(edges["eh0_h1"]["source"], "h0");
+    }
+
+    #[test]
+    fn test_selector_without_matches() {
+        let mut engine = GGLEngine::new();
+
+        let err = engine
+            .generate_from_ggl("graph { node hub; edge: hosts.* -> hub; }")
+            .unwrap_err();
+        assert_eq!(err, "Selector hosts.* matches no nodes");
+    }
+
+    #[test]
+    fn test_selector_edge_ids_do_not_overwrite_edges() {
+        let mut engine = GGLEngine::new();
+
+        let err = engine
+            .generate_from_ggl("graph { node a; node a_b; node b_c; node c; edge: a* -> *c; }")
+            .unwrap_err();
+        assert!(
+            err.contains("Edge ea_b_c from a_b to c already exists"),
+            "{}",
+            err
+        );
+
+        let err = engine
+            .generate_from_ggl("graph { node h0; node h1; edge eh0_h1: h1 -> h0; edge: h0 -> h*; }")
+            .unwrap_err();
+        assert!(err.contains("Edge eh0_h1"), "{}", err);
+
+        let result = engine
+            .generate_from_ggl(
+                "graph { node h0; node h1; edge eh0_h1: h1 -> h0; edge up: h0 -> h*; }",
+            )
+            .unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(graph["edges"]["eh0_h1"]["source"], "h1");
+        assert_eq!(graph["edges"]["up_h0_h1"]["source"], "h0");
+    }
+}
+
+#[cfg(test)]
+mod bulk_edit_integration_tests {
+    use super::*;
+
+    #[test]
+    fn test_set_delete_and_retype() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph cluster {
+                node s1 :server [cpu=16];
+                node s2 :server [cpu=4];
+                node s3 :server [cpu=8.5];
+                node sw :switch [cpu=32];
+                edge: s1 -- sw [weight=0.05];
+                edge: s2 -- sw [weight=0.5];
+                edge: s3 -- sw;
+
+                set nodes where type == "server" and cpu > 8 [tier="big"];
+                delete edges where weight < 0.1;
+                retype nodes where degree() == 1 :leaf;
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let nodes = graph["nodes"].as_object().unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        assert_eq!(nodes["s1"]["metadata"]["tier"], "big");
+        assert_eq!(nodes["s3"]["metadata"]["tier"], "big");
+        assert!(nodes["s2"]["metadata"].get("tier").is_none());
+        assert!(nodes["sw"]["metadata"].get("tier").is_none());
+
+        // Edges without a weight are kept
+        assert_eq!(edges.len(), 2);
+        assert!(edges.get("es1_sw").is_none());
+
+        assert_eq!(nodes["s1"]["type"], "server");
+        assert_eq!(nodes["s2"]["type"], "leaf");
+        assert_eq!(nodes["s3"]["type"], "leaf");
+        assert_eq!(nodes["sw"]["type"], "switch");
+    }
+
+    #[test]
+    fn test_delete_nodes_removes_their_edges() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph hub {
+                generate star { nodes: 5; }
+                delete nodes where degree() > 1;
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 4);
+        assert!(graph["edges"].as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_edit_errors() {
+        let mut engine = GGLEngine::new();
+
+        let err = engine
+            .generate_from_ggl("graph { node a; retype edges where degree() == 0 :x; }")
+            .unwrap_err();
+        assert_eq!(err, "Edit error: degree() only applies to nodes");
+
+        let err = engine
+            .generate_from_ggl("graph { delete nodes where size() == 0; }")
+            .unwrap_err();
+        assert_eq!(err, "Edit error: Unknown function: size()");
+    }
+}
+
+#[cfg(test)]
+mod relabel_integration_tests {
+    use super::*;
+
+    #[test]
+    fn test_relabel_to_dense_integers() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph dense {
+                generate star { nodes: 11; prefix: "host"; }
+                relabel "v{index}" by bfs;
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let nodes = graph["nodes"].as_object().unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+
+        for i in 0..11 {
+            assert_eq!(nodes[&format!("v{}", i)]["id"], format!("v{}", i));
+        }
+        // host10 sorts after host9, so BFS from the hub numbers it last
+        assert_eq!(edges["e0_10"]["source"], "v10");
+        assert_eq!(edges["e0_10"]["target"], "v0");
+        assert!(edges.values().all(|edge| edge["target"] == "v0"));
+    }
+
+    #[test]
+    fn test_relabel_by_attribute_and_mapping() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph named {
+                node a [name="alice"];
+                node b [name="bob"];
+                edge knows: a -> b;
+                relabel "{name}";
+                relabel { bob: robert; }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(graph["edges"]["knows"]["source"], "alice");
+        assert_eq!(graph["edges"]["knows"]["target"], "robert");
+        assert!(graph["nodes"].get("bob").is_none());
+    }
+
+    #[test]
+    fn test_relabel_errors() {
+        let mut engine = GGLEngine::new();
+
+        let err = engine
+            .generate_from_ggl(r#"graph { node a [k=1]; node b [k=1]; relabel "{k}"; }"#)
+            .unwrap_err();
+        assert_eq!(err, "Relabel error: Cannot relabel both a and b to 1");
+
+        let err = engine
+            .generate_from_ggl(r#"graph { node a; relabel "{name}"; }"#)
+            .unwrap_err();
+        assert_eq!(err, "Relabel error: Node a has no attribute name");
+
+        let err = engine
+            .generate_from_ggl("graph { node a; relabel { b: c; } }")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "Relabel error: Cannot relabel b, there is no such node"
+        );
+    }
+}
+
+#[cfg(test)]
+mod transform_integration_tests {
+    use super::*;
+
+    #[test]
+    fn test_spanning_tree_maze_from_grid() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph maze {
+                generate grid {
+                    rows: 8;
+                    cols: 8;
+                }
+
+                transform spanning_tree {
+                    algorithm: "dfs";
+                    seed: 12;
+                }
+            }
+        "#;
+
+        let first = engine.generate_from_ggl(ggl_code).unwrap();
+        let second = engine.generate_from_ggl(ggl_code).unwrap();
+        assert_eq!(first, second);
+
+        let graph: Value = serde_json::from_str(&first).unwrap();
+        assert_eq!(graph["nodes"].as_object().unwrap().len(), 64);
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 63);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_of_declared_edges() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph backbone {
+                node a;
+                node b;
+                node c;
+                edge ab: a -- b [weight=1];
+                edge bc: b -- c [weight=2.5];
+                edge ca: c -- a [weight=4, label="slow"];
+
+                transform spanning_tree { algorithm: "minimum"; }
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        let edges = graph["edges"].as_object().unwrap();
+        assert_eq!(edges.len(), 2);
+        assert!(edges.contains_key("ab") && edges.contains_key("bc"));
+    }
+
+    #[test]
+    fn test_structural_transforms_in_sequence() {
+        let mut engine = GGLEngine::new();
+
+        let ggl_code = r#"
+            graph analysis {
+                generate path { nodes: 6; }
+                transform graph_power { k: 2; }
+                transform to_directed;
+                transform reverse;
+                transform to_undirected;
+                transform complement;
+            }
+        "#;
+
+        let result = engine.generate_from_ggl(ggl_code).unwrap();
+        let graph: Value = serde_json::from_str(&result).unwrap();
+        // The square of a 6-path has 9 edges, leaving 15 - 9 for the complement
+        assert_eq!(graph["edges"].as_object().unwrap().len(), 6);
+        assert!(graph["edges"].as_object().unwrap().contains_key("en0_n3"));
+    }
+
+    #[test]
+    fn test_transform_errors() {
+        let mut engine = GGLEngine::new();
+
+        let result = engine.generate_from_ggl("graph { node a; transform shuffle; }");
+        assert!(result.unwrap_err().contains("Unknown transform: shuffle"));
+
+        let result = engine.generate_from_ggl(
+            r#"graph { node a; transform spanning_tree { algorithm: "prim"; } }"#,
+        );
+        assert!(result.unwrap_err().contains("Transform error"));
+    }
+
+    #[test]
+    fn test_transform_params_are_validated() {
+        let mut engine = GGLEngine::new();
+
+        let result = engine
+            .generate_from_ggl(r#"graph { node a; transform complement { node_typ: "x"; } }"#);
+        assert!(result
+            .unwrap_err()
+            .contains("unknown parameter node_typ for complement, did you mean node_type?"));
+
+        let result = engine.generate_from_ggl("graph { node a; transform reverse { k: 2; } }");
+        assert!(result.unwrap_err().contains("expected one of"));
+
+        let result = engine.generate_from_ggl("graph { node a; transform graph_power { k: 0; } }");
+        assert!(result
+            .unwrap_err()
+            .contains("parameter k must be at least 1"));
+
+        let result = engine.generate_from_ggl(
+            r#"graph { node a [w=1]; transform complement { attribute: "w"; value: 1; } }"#,
+        );
+        assert!(result.is_ok());
+    }
+}
+
 #[cfg(test)]
 mod error_handling_tests {
     use super::*;
@@ -929,6 +1947,119 @@ mod real_world_scenarios {
     }
 }
 
+#[cfg(test)]
+mod seeding_tests {
+    use super::*;
+
+    const RANDOM_PROGRAM: &str = r#"
+        graph random {
+            generate barabasi_albert {
+                nodes: 40;
+                edges_per_node: 2;
+                prefix: "ba";
+            }
+            generate watts_strogatz {
+                nodes: 30;
+                k: 4;
+                p: 0.5;
+                prefix: "ws";
+            }
+        }
+    "#;
+
+    fn run_with_seed(seed: u64, code: &str) -> String {
+        let mut engine = GGLEngine::new();
+        engine.set_seed(seed);
+        engine.generate_from_ggl(code).unwrap()
+    }
+
+    #[test]
+    fn test_engine_seed_is_byte_identical() {
+        let first = run_with_seed(7, RANDOM_PROGRAM);
+        let second = run_with_seed(7, RANDOM_PROGRAM);
+        assert_eq!(first, second);
+
+        let other = run_with_seed(8, RANDOM_PROGRAM);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_engine_seed_persists_across_runs() {
+        let mut engine = GGLEngine::new();
+        engine.set_seed(3);
+        let first = engine.generate_from_ggl(RANDOM_PROGRAM).unwrap();
+        let second = engine.generate_from_ggl(RANDOM_PROGRAM).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_seed_statement_is_byte_identical() {
+        let code = RANDOM_PROGRAM.replacen("graph random {", "graph random {\n seed 42;", 1);
+
+        let first = GGLEngine::new().generate_from_ggl(&code).unwrap();
+        let second = GGLEngine::new().generate_from_ggl(&code).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_generator_seed_overrides_engine_seed() {
+        let code = r#"
+            graph random {
+                generate watts_strogatz { nodes: 30; k: 4; p: 0.5; seed: 11; }
+            }
+        "#;
+
+        assert_eq!(run_with_seed(1, code), run_with_seed(2, code));
+    }
+
+    #[test]
+    fn test_transform_seed_does_not_shift_later_statements() {
+        let program = |transform: &str| {
+            format!(
+                r#"
+                graph random {{
+                    generate grid {{ rows: 4; cols: 4; prefix: "g"; }}
+                    transform spanning_tree {{ {} }}
+                    generate watts_strogatz {{ nodes: 30; k: 4; p: 0.5; prefix: "ws"; }}
+                }}
+            "#,
+                transform
+            )
+        };
+        let ws_edges = |json: String| {
+            let graph: Value = serde_json::from_str(&json).unwrap();
+            graph["edges"]
+                .as_object()
+                .unwrap()
+                .values()
+                .filter(|edge| edge["source"].as_str().unwrap().starts_with("ws"))
+                .map(|edge| (edge["source"].to_string(), edge["target"].to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        let drawn = ws_edges(run_with_seed(5, &program("")));
+        let given = ws_edges(run_with_seed(5, &program("seed: 1;")));
+        assert_eq!(drawn, given);
+    }
+
+    #[test]
+    fn test_output_keys_are_sorted() {
+        let code = r#"
+            graph sorted {
+                node zeta [z=1, a=2];
+                node alpha;
+                edge e2: zeta -- alpha;
+                edge e1: alpha -- zeta;
+            }
+        "#;
+
+        let json = GGLEngine::new().generate_from_ggl(code).unwrap();
+        assert!(json.find("\"alpha\"").unwrap() < json.find("\"zeta\"").unwrap());
+        assert!(json.find("\"e1\"").unwrap() < json.find("\"e2\"").unwrap());
+        assert!(json.find("\"a\":2").unwrap() < json.find("\"z\":1").unwrap());
+    }
+}
+
 #[cfg(test)]
 mod performance_tests {
     use super::*;
@@ -0,0 +1,232 @@
+use graph_generation_language::generators::{
+    generate_complete, generate_cycle, generate_grid, generate_path,
+};
+use graph_generation_language::operations::*;
+use graph_generation_language::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::HashMap;
+
+fn params(entries: &[(&str, MetadataValue)]) -> HashMap<String, MetadataValue> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+fn with_prefix(
+    generator: fn(&HashMap<String, MetadataValue>) -> Result<Graph, String>,
+    nodes: i64,
+    prefix: &str,
+) -> Graph {
+    generator(&params(&[
+        ("nodes", MetadataValue::Integer(nodes)),
+        ("prefix", MetadataValue::String(prefix.to_string())),
+    ]))
+    .unwrap()
+}
+
+/// Node degrees, counting each edge at both endpoints.
+fn degrees(graph: &Graph) -> HashMap<String, usize> {
+    let mut degrees: HashMap<String, usize> =
+        graph.nodes.keys().map(|id| (id.clone(), 0)).collect();
+    for edge in graph.edges.values() {
+        *degrees.get_mut(&edge.source).unwrap() += 1;
+        *degrees.get_mut(&edge.target).unwrap() += 1;
+    }
+    degrees
+}
+
+#[cfg(test)]
+mod product_tests {
+    use super::*;
+
+    #[test]
+    fn test_cartesian_product_of_paths_is_grid() {
+        let rows = with_prefix(generate_path, 4, "r");
+        let cols = with_prefix(generate_path, 3, "c");
+        let product = cartesian_product(&rows, &cols).unwrap();
+
+        let grid = generate_grid(&params(&[
+            ("rows", MetadataValue::Integer(4)),
+            ("cols", MetadataValue::Integer(3)),
+        ]))
+        .unwrap();
+        assert_eq!(product.node_count(), grid.node_count());
+        assert_eq!(product.edge_count(), grid.edge_count());
+        assert!(product.get_node("r0_c2").is_some());
+    }
+
+    #[test]
+    fn test_cartesian_product_of_cycles_is_torus() {
+        let product = cartesian_product(
+            &with_prefix(generate_cycle, 5, "a"),
+            &with_prefix(generate_cycle, 4, "b"),
+        )
+        .unwrap();
+        assert_eq!(product.node_count(), 20);
+        assert_eq!(product.edge_count(), 40);
+        assert!(degrees(&product).values().all(|&degree| degree == 4));
+    }
+
+    #[test]
+    fn test_repeated_cartesian_product_is_hypercube() {
+        let bit = with_prefix(generate_path, 2, "b");
+        let square = cartesian_product(&bit, &bit).unwrap();
+        let cube = cartesian_product(&square, &bit).unwrap();
+        assert_eq!(cube.node_count(), 8);
+        assert_eq!(cube.edge_count(), 12);
+        assert!(degrees(&cube).values().all(|&degree| degree == 3));
+    }
+
+    #[test]
+    fn test_product_edge_counts() {
+        // |E| = 3 for the triangle and 2 for the path on 3 nodes
+        let triangle = with_prefix(generate_complete, 3, "t");
+        let path = with_prefix(generate_path, 3, "p");
+
+        assert_eq!(tensor_product(&triangle, &path).unwrap().edge_count(), 12);
+        assert_eq!(
+            strong_product(&triangle, &path).unwrap().edge_count(),
+            3 * 3 + 3 * 2 + 12
+        );
+        assert_eq!(
+            lexicographic_product(&triangle, &path)
+                .unwrap()
+                .edge_count(),
+            3 * 9 + 3 * 2
+        );
+        assert_eq!(
+            lexicographic_product(&path, &triangle)
+                .unwrap()
+                .edge_count(),
+            2 * 9 + 3 * 3
+        );
+    }
+
+    #[test]
+    fn test_products_ignore_direction_and_repeats() {
+        let directed = generate_complete(&params(&[
+            ("nodes", MetadataValue::Integer(3)),
+            ("directed", MetadataValue::Boolean(true)),
+        ]))
+        .unwrap();
+        let undirected = with_prefix(generate_complete, 3, "n");
+        let path = with_prefix(generate_path, 2, "p");
+
+        assert_eq!(
+            cartesian_product(&directed, &path).unwrap().edge_count(),
+            cartesian_product(&undirected, &path).unwrap().edge_count()
+        );
+    }
+
+    #[test]
+    fn test_product_labels() {
+        let mut g = Graph::new();
+        g.add_node(Node::new("a".to_string()).with_type("rack".to_string()));
+        g.add_node(Node::new("b".to_string()).with_type("rack".to_string()));
+        g.add_edge(
+            Edge::new("ab".to_string(), "a".to_string(), "b".to_string())
+                .with_type("uplink".to_string())
+                .with_metadata("speed".to_string(), MetadataValue::Integer(100)),
+        );
+        let h = with_prefix(generate_path, 2, "s");
+
+        let product = cartesian_product(&g, &h).unwrap();
+        assert_eq!(product.get_node("a_s1").unwrap().r#type, "rack");
+
+        let edge = product.edges.get("ea_s0_b_s0").unwrap();
+        assert_eq!(edge.r#type, "uplink");
+        assert_eq!(
+            edge.metadata.get("speed"),
+            Some(&MetadataValue::Integer(100))
+        );
+    }
+
+    #[test]
+    fn test_product_rejects_ambiguous_node_ids() {
+        let mut g = Graph::new();
+        g.add_node(Node::new("a".to_string()));
+        g.add_node(Node::new("a_b".to_string()));
+        let mut h = Graph::new();
+        h.add_node(Node::new("b_c".to_string()));
+        h.add_node(Node::new("c".to_string()));
+
+        let error = cartesian_product(&g, &h).unwrap_err();
+        assert!(error.contains("a_b_c"), "{}", error);
+        assert!(tensor_product(&g, &h).is_err());
+    }
+
+    #[test]
+    fn test_product_rejects_ambiguous_edge_ids() {
+        let mut g = Graph::new();
+        g.add_node(Node::new("a_b".to_string()));
+        g.add_node(Node::new("b".to_string()));
+        g.add_edge(Edge::new(
+            "g".to_string(),
+            "a_b".to_string(),
+            "b".to_string(),
+        ));
+        let mut h = Graph::new();
+        h.add_node(Node::new("a".to_string()));
+        h.add_node(Node::new("a_a".to_string()));
+        h.add_edge(Edge::new(
+            "h".to_string(),
+            "a".to_string(),
+            "a_a".to_string(),
+        ));
+
+        // The nodes are distinct, but a_b_a_a -- b_a_a and a_b_a -- a_b_a_a are both ea_b_a_a_b_a_a
+        let error = cartesian_product(&g, &h).unwrap_err();
+        assert!(error.contains("ea_b_a_a_b_a_a"), "{}", error);
+    }
+}
+
+#[cfg(test)]
+mod union_and_join_tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_union() {
+        let a = with_prefix(generate_cycle, 4, "a");
+        let b = with_prefix(generate_path, 3, "b");
+
+        let union = disjoint_union(&a, &b).unwrap();
+        assert_eq!(union.node_count(), 7);
+        assert_eq!(union.edge_count(), 6);
+        // Both generators name their first edge e0_1
+        assert!(union.edges.contains_key("e0_1"));
+        let renamed = union.edges.get("eb0_b1").unwrap();
+        assert_eq!(
+            (renamed.source.as_str(), renamed.target.as_str()),
+            ("b0", "b1")
+        );
+
+        let error = disjoint_union(&a, &a).unwrap_err();
+        assert!(error.contains("both operands have a node a0"), "{}", error);
+    }
+
+    #[test]
+    fn test_join_of_edgeless_graphs_is_complete_bipartite() {
+        let mut left = Graph::new();
+        let mut right = Graph::new();
+        for i in 0..3 {
+            left.add_node(Node::new(format!("l{}", i)));
+        }
+        for i in 0..4 {
+            right.add_node(Node::new(format!("r{}", i)));
+        }
+
+        let joined = join(&left, &right).unwrap();
+        assert_eq!(joined.node_count(), 7);
+        assert_eq!(joined.edge_count(), 12);
+        let edge = joined.edges.get("el0_r3").unwrap();
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("l0", "r3"));
+    }
+
+    #[test]
+    fn test_get_operation() {
+        for name in OPERATIONS {
+            assert!(get_operation(name).is_some(), "{} is not registered", name);
+        }
+        assert!(get_operation("product").is_none());
+    }
+}
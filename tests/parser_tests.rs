@@ -1,4 +1,8 @@
-use graph_generation_language::parser::{parse_ggl, GGLStatement};
+use graph_generation_language::attributes::{AttributeValue, Distribution};
+use graph_generation_language::parser::{parse_ggl, EditAction, GGLStatement, GraphOperand};
+use graph_generation_language::predicates::{CompareOp, ElementKind, Operand};
+use graph_generation_language::relabel::{NodeOrder, Relabeling};
+use graph_generation_language::selectors::NodeSelector;
 use graph_generation_language::types::MetadataValue;
 
 #[cfg(test)]
@@ -127,7 +131,12 @@ mod lexical_tests {
                             assert_eq!(*n, *expected);
                         }
                         (Some(MetadataValue::Float(n)), ExpectedWeight::Float(expected)) => {
-                            assert!((n - expected).abs() < f64::EPSILON, "Expected {}, got {}", expected, n);
+                            assert!(
+                                (n - expected).abs() < f64::EPSILON,
+                                "Expected {}, got {}",
+                                expected,
+                                n
+                            );
                         }
                         _ => panic!("Expected correct number type at position {}", i),
                     }
@@ -480,11 +489,166 @@ mod generator_statement_tests {
                 assert_eq!(gen.name, "complete");
                 assert_eq!(gen.params.len(), 1);
                 assert_eq!(gen.params.get("nodes"), Some(&MetadataValue::Integer(5)));
+                assert_eq!((gen.line, gen.column), (3, 17));
             }
             _ => panic!("Expected GenerateStmt"),
         }
     }
 
+    #[test]
+    fn test_generator_with_list_params() {
+        let input = r#"
+            graph test {
+                generate stochastic_block_model {
+                    sizes: [10, 20];
+                    probabilities: [[0.5, 0.1], [0.1, 0.4]];
+                    block_types: ["a", "b"];
+                    empty: [];
+                }
+            }
+        "#;
+
+        let result = parse_ggl(input);
+        assert!(result.is_ok(), "Failed to parse lists: {:?}", result.err());
+
+        match &result.unwrap()[0] {
+            GGLStatement::GenerateStmt(gen) => {
+                assert_eq!(
+                    gen.params.get("sizes"),
+                    Some(&MetadataValue::List(vec![
+                        MetadataValue::Integer(10),
+                        MetadataValue::Integer(20)
+                    ]))
+                );
+                assert_eq!(
+                    gen.params.get("probabilities"),
+                    Some(&MetadataValue::List(vec![
+                        MetadataValue::List(vec![
+                            MetadataValue::Float(0.5),
+                            MetadataValue::Float(0.1)
+                        ]),
+                        MetadataValue::List(vec![
+                            MetadataValue::Float(0.1),
+                            MetadataValue::Float(0.4)
+                        ]),
+                    ]))
+                );
+                assert_eq!(
+                    gen.params.get("block_types"),
+                    Some(&MetadataValue::List(vec![
+                        MetadataValue::String("a".to_string()),
+                        MetadataValue::String("b".to_string())
+                    ]))
+                );
+                assert_eq!(gen.params.get("empty"), Some(&MetadataValue::List(vec![])));
+            }
+            _ => panic!("Expected GenerateStmt"),
+        }
+    }
+
+    #[test]
+    fn test_generator_attribute_templates() {
+        let input = r#"
+            graph test {
+                generate complete {
+                    nodes: 3;
+                    node_type: "server";
+                    node_attrs: [cpu = uniform_int(2, 64), zone = choice("a", "b"), rack = 1];
+                    edge_type: link;
+                    edge_attrs: [latency = normal(5.0, 1.0)];
+                }
+            }
+        "#;
+
+        match &parse_ggl(input).unwrap()[0] {
+            GGLStatement::GenerateStmt(gen) => {
+                // Templates are kept apart from the generator's own parameters
+                assert_eq!(gen.params.len(), 1);
+                assert_eq!(gen.templates.node_type.as_deref(), Some("server"));
+                assert_eq!(gen.templates.edge_type.as_deref(), Some("link"));
+
+                let node_attrs = &gen.templates.node_attrs;
+                assert_eq!(node_attrs.len(), 3);
+                assert_eq!(node_attrs[0].name, "cpu");
+                assert_eq!(
+                    node_attrs[0].value,
+                    AttributeValue::Sampled(Distribution::UniformInt { min: 2, max: 64 })
+                );
+                assert_eq!(
+                    node_attrs[1].value,
+                    AttributeValue::Sampled(Distribution::Choice(vec![
+                        MetadataValue::String("a".to_string()),
+                        MetadataValue::String("b".to_string())
+                    ]))
+                );
+                assert_eq!(
+                    node_attrs[2].value,
+                    AttributeValue::Fixed(MetadataValue::Integer(1))
+                );
+                assert_eq!(
+                    gen.templates.edge_attrs[0].value,
+                    AttributeValue::Sampled(Distribution::Normal {
+                        mean: 5.0,
+                        std_dev: 1.0
+                    })
+                );
+            }
+            _ => panic!("Expected GenerateStmt"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_attribute_templates() {
+        let invalid = [
+            "graph { generate path { nodes: 3; node_attrs: [cpu = gaussian(1, 2)]; } }",
+            "graph { generate path { nodes: 3; node_attrs: [cpu = uniform_int(9, 2)]; } }",
+            "graph { generate path { nodes: 3; prefix: [a = 1]; } }",
+            "graph { generate path { nodes: 3; node_type: 4; } }",
+            "graph { generate path { nodes: 3; edge_attrs: [1, 2]; } }",
+        ];
+        for input in invalid {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+
+        let error = parse_ggl("graph {\n generate path { node_attrs: [cpu = normall(0, 1)]; }\n}")
+            .unwrap_err();
+        assert!(error.contains("line 2, column 31"), "{}", error);
+        assert!(error.contains("did you mean normal?"), "{}", error);
+    }
+
+    #[test]
+    fn test_list_attribute_values() {
+        let input = r#"
+            graph test {
+                node a [tags=["x", "y"], coords=[1, 2.5]];
+            }
+        "#;
+
+        match &parse_ggl(input).unwrap()[0] {
+            GGLStatement::NodeDecl(node) => {
+                assert_eq!(
+                    node.attributes.get("coords"),
+                    Some(&MetadataValue::List(vec![
+                        MetadataValue::Integer(1),
+                        MetadataValue::Float(2.5)
+                    ]))
+                );
+            }
+            _ => panic!("Expected NodeDecl"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_lists() {
+        for input in [
+            "graph { generate g { sizes: [1, 2; } }",
+            "graph { generate g { sizes: [1,, 2]; } }",
+            "graph { generate g { sizes: [1, 2,]; } }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+
     #[test]
     fn test_generator_with_multiple_params() {
         let input = r#"
@@ -755,6 +919,461 @@ mod rule_application_tests {
     }
 }
 
+#[cfg(test)]
+mod seed_statement_tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_statement() {
+        let input = r#"
+            graph test {
+                seed 42;
+                node a;
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        assert_eq!(statements.len(), 2);
+
+        match &statements[0] {
+            GGLStatement::SeedStmt(seed) => assert_eq!(seed.seed, 42),
+            _ => panic!("Expected SeedStmt"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_seed_statement() {
+        for input in [
+            "graph { seed -1; }",
+            "graph { seed 1.5; }",
+            "graph { seed; }",
+            "graph { seed 3 }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod transform_statement_tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_statement() {
+        let input = r#"
+            graph test {
+                generate grid { rows: 3; cols: 3; }
+                transform spanning_tree {
+                    algorithm: "dfs";
+                    seed: 4;
+                }
+                transform spanning_tree;
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        assert_eq!(statements.len(), 3);
+
+        match &statements[1] {
+            GGLStatement::TransformStmt(transform) => {
+                assert_eq!(transform.name, "spanning_tree");
+                assert_eq!(
+                    transform.params.get("algorithm"),
+                    Some(&MetadataValue::String("dfs".to_string()))
+                );
+                assert_eq!(
+                    transform.params.get("seed"),
+                    Some(&MetadataValue::Integer(4))
+                );
+            }
+            _ => panic!("Expected TransformStmt"),
+        }
+        match &statements[2] {
+            GGLStatement::TransformStmt(transform) => assert!(transform.params.is_empty()),
+            _ => panic!("Expected TransformStmt"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_transform_statement() {
+        for input in [
+            "graph { transform; }",
+            "graph { transform spanning_tree }",
+            "graph { transform spanning_tree { algorithm: \"dfs\" } }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod operation_statement_tests {
+    use super::*;
+
+    #[test]
+    fn test_let_and_operation_statements() {
+        let input = r#"
+            graph test {
+                let ring = generate cycle { nodes: 4; }
+                let line = path;
+                cartesian(ring, generate path { nodes: 3; prefix: "p"; }, join(a, b));
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        assert_eq!(statements.len(), 3);
+
+        match &statements[0] {
+            GGLStatement::LetStmt(binding) => {
+                assert_eq!(binding.name, "ring");
+                match &binding.value {
+                    GraphOperand::Generate(gen) => assert_eq!(gen.name, "cycle"),
+                    other => panic!("Expected a generate operand, got {:?}", other),
+                }
+            }
+            _ => panic!("Expected LetStmt"),
+        }
+        match &statements[1] {
+            GGLStatement::LetStmt(binding) => {
+                assert!(matches!(&binding.value, GraphOperand::Named(name) if name == "path"))
+            }
+            _ => panic!("Expected LetStmt"),
+        }
+        match &statements[2] {
+            GGLStatement::OperationStmt(call) => {
+                assert_eq!(call.operation, "cartesian");
+                assert_eq!((call.line, call.column), (5, 17));
+                assert_eq!(call.operands.len(), 3);
+                assert!(matches!(&call.operands[0], GraphOperand::Named(name) if name == "ring"));
+                assert!(matches!(&call.operands[1], GraphOperand::Generate(_)));
+                match &call.operands[2] {
+                    GraphOperand::Call(inner) => {
+                        assert_eq!(inner.operation, "join");
+                        assert_eq!(inner.operands.len(), 2);
+                    }
+                    other => panic!("Expected a call operand, got {:?}", other),
+                }
+            }
+            _ => panic!("Expected OperationStmt"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_operation_statements() {
+        for input in [
+            "graph { cartesian(a); }",
+            "graph { cartesian(a, b) }",
+            "graph { cartesian(); }",
+            "graph { let = a; }",
+            "graph { let x = a }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod subgraph_tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_subgraph_blocks() {
+        let input = r#"
+            graph test {
+                subgraph cluster_a {
+                    node gw :router;
+                    generate path { nodes: 2; }
+                    subgraph rack { node tor; edge up: tor -> gw; }
+                }
+                edge: cluster_a.gw -- cluster_b.gw;
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        assert_eq!(statements.len(), 2);
+
+        match &statements[0] {
+            GGLStatement::SubgraphStmt(block) => {
+                assert_eq!(block.name, "cluster_a");
+                assert_eq!(block.statements.len(), 3);
+                assert!(matches!(block.statements[0], GGLStatement::NodeDecl(_)));
+                assert!(matches!(block.statements[1], GGLStatement::GenerateStmt(_)));
+                match &block.statements[2] {
+                    GGLStatement::SubgraphStmt(inner) => {
+                        assert_eq!(inner.name, "rack");
+                        assert_eq!(inner.statements.len(), 2);
+                    }
+                    _ => panic!("Expected a nested SubgraphStmt"),
+                }
+            }
+            _ => panic!("Expected SubgraphStmt"),
+        }
+        match &statements[1] {
+            GGLStatement::EdgeDecl(edge) => {
+                assert_eq!(edge.source, "cluster_a.gw");
+                assert_eq!(edge.target, "cluster_b.gw");
+                assert_eq!(edge.id, "ecluster_a.gw_cluster_b.gw");
+            }
+            _ => panic!("Expected EdgeDecl"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_subgraph_blocks() {
+        for input in [
+            "graph { subgraph { node a; } }",
+            "graph { subgraph a { transform complement; } }",
+            "graph { subgraph a { seed 1; } }",
+            "graph { subgraph a { node b; }",
+            "graph { edge: a. -> b; }",
+            "graph { node a.b; }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod selector_edge_tests {
+    use super::*;
+
+    #[test]
+    fn test_selector_endpoints() {
+        let input = r#"
+            graph test {
+                edge: hosts.* -> core;
+                edge link: [type=server] -- [type=switch, zone="a"] [speed=10];
+                edge: n* -> hub;
+                edge: a.b -> c;
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        assert_eq!(statements.len(), 4);
+
+        match &statements[0] {
+            GGLStatement::SelectorEdgeDecl(edge) => {
+                assert_eq!(edge.id, None);
+                assert_eq!(edge.source, NodeSelector::Glob("hosts.*".to_string()));
+                assert_eq!(edge.target, NodeSelector::Id("core".to_string()));
+                assert!(edge.directed);
+            }
+            _ => panic!("Expected SelectorEdgeDecl"),
+        }
+        match &statements[1] {
+            GGLStatement::SelectorEdgeDecl(edge) => {
+                assert_eq!(edge.id.as_deref(), Some("link"));
+                assert!(!edge.directed);
+                match (&edge.source, &edge.target) {
+                    (NodeSelector::Attributes(source), NodeSelector::Attributes(target)) => {
+                        assert_eq!(
+                            source.get("type"),
+                            Some(&MetadataValue::String("server".to_string()))
+                        );
+                        assert_eq!(target.len(), 2);
+                        assert_eq!(
+                            target.get("zone"),
+                            Some(&MetadataValue::String("a".to_string()))
+                        );
+                    }
+                    _ => panic!("Expected attribute selectors"),
+                }
+                assert_eq!(
+                    edge.attributes.get("speed"),
+                    Some(&MetadataValue::Integer(10))
+                );
+            }
+            _ => panic!("Expected SelectorEdgeDecl"),
+        }
+        match &statements[2] {
+            GGLStatement::SelectorEdgeDecl(edge) => {
+                assert_eq!(edge.source, NodeSelector::Glob("n*".to_string()));
+            }
+            _ => panic!("Expected SelectorEdgeDecl"),
+        }
+        // Plain names stay ordinary edge declarations
+        assert!(matches!(statements[3], GGLStatement::EdgeDecl(_)));
+    }
+
+    #[test]
+    fn test_selectors_only_in_edge_declarations() {
+        for input in [
+            "graph { node n*; }",
+            "graph { edge n*: a -> b; }",
+            "graph { rule r { lhs { n* -> b; } rhs { } } }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod edit_statement_tests {
+    use super::*;
+
+    #[test]
+    fn test_set_delete_and_retype() {
+        let input = r#"
+            graph test {
+                set nodes where type == "server" and cpu > 8 [tier="big"];
+                delete edges where weight < 0.1;
+                retype nodes where degree() == 1 :leaf;
+                delete nodes;
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        assert_eq!(statements.len(), 4);
+
+        match &statements[0] {
+            GGLStatement::EditStmt(edit) => {
+                assert_eq!(edit.kind, ElementKind::Nodes);
+                let comparisons = &edit.predicate.comparisons;
+                assert_eq!(comparisons.len(), 2);
+                assert_eq!(
+                    comparisons[0].operand,
+                    Operand::Attribute("type".to_string())
+                );
+                assert_eq!(comparisons[0].op, CompareOp::Eq);
+                assert_eq!(
+                    comparisons[0].value,
+                    MetadataValue::String("server".to_string())
+                );
+                assert_eq!(comparisons[1].op, CompareOp::Gt);
+                assert_eq!(comparisons[1].value, MetadataValue::Integer(8));
+                match &edit.action {
+                    EditAction::Set(attributes) => assert_eq!(
+                        attributes.get("tier"),
+                        Some(&MetadataValue::String("big".to_string()))
+                    ),
+                    _ => panic!("Expected EditAction::Set"),
+                }
+            }
+            _ => panic!("Expected EditStmt"),
+        }
+        match &statements[1] {
+            GGLStatement::EditStmt(edit) => {
+                assert_eq!(edit.kind, ElementKind::Edges);
+                assert_eq!(edit.predicate.comparisons[0].op, CompareOp::Lt);
+                assert_eq!(
+                    edit.predicate.comparisons[0].value,
+                    MetadataValue::Float(0.1)
+                );
+                assert!(matches!(edit.action, EditAction::Delete));
+            }
+            _ => panic!("Expected EditStmt"),
+        }
+        match &statements[2] {
+            GGLStatement::EditStmt(edit) => {
+                assert_eq!(
+                    edit.predicate.comparisons[0].operand,
+                    Operand::Function("degree".to_string())
+                );
+                assert!(matches!(&edit.action, EditAction::Retype(t) if t == "leaf"));
+            }
+            _ => panic!("Expected EditStmt"),
+        }
+        match &statements[3] {
+            GGLStatement::EditStmt(edit) => assert!(edit.predicate.comparisons.is_empty()),
+            _ => panic!("Expected EditStmt"),
+        }
+    }
+
+    #[test]
+    fn test_and_is_a_keyword_only_on_its_own() {
+        let statements =
+            parse_ggl("graph { delete nodes where x == a and android != 1; }").unwrap();
+        match &statements[0] {
+            GGLStatement::EditStmt(edit) => {
+                assert_eq!(edit.predicate.comparisons.len(), 2);
+                assert_eq!(
+                    edit.predicate.comparisons[1].operand,
+                    Operand::Attribute("android".to_string())
+                );
+            }
+            _ => panic!("Expected EditStmt"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_edit_statements() {
+        for input in [
+            "graph { set nodes; }",
+            "graph { delete nodes where; }",
+            "graph { delete vertices; }",
+            "graph { retype nodes where x = 1 :leaf; }",
+            "graph { delete edges where weight < 1 [a=1]; }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod relabel_statement_tests {
+    use super::*;
+
+    #[test]
+    fn test_relabel_forms() {
+        let input = r#"
+            graph test {
+                relabel;
+                relabel by bfs;
+                relabel "v{index}" by degree;
+                relabel "{name}";
+                relabel { n0: hub; rack.n1: "spare"; n2: 7; }
+            }
+        "#;
+
+        let statements = parse_ggl(input).unwrap();
+        let relabelings: Vec<&Relabeling> = statements
+            .iter()
+            .map(|statement| match statement {
+                GGLStatement::RelabelStmt(relabeling) => relabeling,
+                _ => panic!("Expected RelabelStmt"),
+            })
+            .collect();
+
+        assert_eq!(relabelings[0], &Relabeling::integers(NodeOrder::Id));
+        assert_eq!(relabelings[1], &Relabeling::integers(NodeOrder::Bfs));
+        assert_eq!(
+            relabelings[2],
+            &Relabeling::Format {
+                format: "v{index}".to_string(),
+                order: NodeOrder::Degree,
+            }
+        );
+        assert_eq!(
+            relabelings[3],
+            &Relabeling::Format {
+                format: "{name}".to_string(),
+                order: NodeOrder::Id,
+            }
+        );
+        match relabelings[4] {
+            Relabeling::Mapping(mapping) => {
+                assert_eq!(mapping.len(), 3);
+                assert_eq!(mapping["n0"], "hub");
+                assert_eq!(mapping["rack.n1"], "spare");
+                assert_eq!(mapping["n2"], "7");
+            }
+            _ => panic!("Expected Relabeling::Mapping"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_relabel_statements() {
+        for input in [
+            "graph { relabel by name; }",
+            "graph { relabel { n0: hub } }",
+            "graph { relabel \"v{index}\" }",
+            "graph { relabel \"v{index}\" bfs; }",
+        ] {
+            assert!(parse_ggl(input).is_err(), "Expected error for {}", input);
+        }
+    }
+}
+
 #[cfg(test)]
 mod complex_program_tests {
     use super::*;
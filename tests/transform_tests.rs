@@ -0,0 +1,420 @@
+use graph_generation_language::generators::{generate_delaunay, generate_grid};
+use graph_generation_language::transforms::*;
+use graph_generation_language::types::{Edge, Graph, MetadataValue, Node};
+use std::collections::HashMap;
+
+fn params(entries: &[(&str, MetadataValue)]) -> HashMap<String, MetadataValue> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+fn grid(rows: i64, cols: i64) -> Graph {
+    generate_grid(&params(&[
+        ("rows", MetadataValue::Integer(rows)),
+        ("cols", MetadataValue::Integer(cols)),
+    ]))
+    .unwrap()
+}
+
+/// Counts connected components, treating edges as undirected.
+fn component_count(graph: &Graph) -> usize {
+    let mut parent: HashMap<&str, &str> = graph
+        .nodes
+        .keys()
+        .map(|id| (id.as_str(), id.as_str()))
+        .collect();
+    fn find<'a>(parent: &mut HashMap<&'a str, &'a str>, mut u: &'a str) -> &'a str {
+        while parent[u] != u {
+            u = parent[u];
+        }
+        u
+    }
+    for edge in graph.edges.values() {
+        let (ru, rv) = (
+            find(&mut parent, &edge.source),
+            find(&mut parent, &edge.target),
+        );
+        parent.insert(ru, rv);
+    }
+    let ids: Vec<&str> = parent.keys().copied().collect();
+    ids.into_iter()
+        .filter(|&id| find(&mut parent, id) == id)
+        .count()
+}
+
+#[cfg(test)]
+mod spanning_tree_tests {
+    use super::*;
+
+    #[test]
+    fn test_random_trees_span_grid() {
+        for algorithm in ["wilson", "dfs"] {
+            let mut graph = grid(12, 9);
+            spanning_tree(
+                &mut graph,
+                &params(&[
+                    ("algorithm", MetadataValue::String(algorithm.to_string())),
+                    ("seed", MetadataValue::Integer(3)),
+                ]),
+            )
+            .unwrap();
+
+            assert_eq!(graph.node_count(), 108);
+            assert_eq!(graph.edge_count(), 107, "{} tree", algorithm);
+            assert_eq!(component_count(&graph), 1, "{} tree", algorithm);
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree_is_reproducible() {
+        let run = |seed: i64| {
+            let mut graph = grid(10, 10);
+            spanning_tree(
+                &mut graph,
+                &params(&[("seed", MetadataValue::Integer(seed))]),
+            )
+            .unwrap();
+            let mut ids: Vec<String> = graph.edges.into_keys().collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(run(5), run(5));
+        assert_ne!(run(5), run(6));
+    }
+
+    #[test]
+    fn test_wilson_is_uniform() {
+        // A 4-cycle has exactly 4 spanning trees, one per omitted edge
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for seed in 0..4000 {
+            let mut graph = Graph::new();
+            for id in ["a", "b", "c", "d"] {
+                graph.add_node(Node::new(id.to_string()));
+            }
+            for (id, u, v) in [
+                ("ab", "a", "b"),
+                ("bc", "b", "c"),
+                ("cd", "c", "d"),
+                ("da", "d", "a"),
+            ] {
+                graph.add_edge(Edge::new(id.to_string(), u.to_string(), v.to_string()));
+            }
+            spanning_tree(
+                &mut graph,
+                &params(&[("seed", MetadataValue::Integer(seed))]),
+            )
+            .unwrap();
+            let omitted = ["ab", "bc", "cd", "da"]
+                .into_iter()
+                .find(|id| !graph.edges.contains_key(*id))
+                .unwrap();
+            *counts.entry(omitted.to_string()).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 4);
+        for (edge, count) in counts {
+            assert!(
+                (850..=1150).contains(&count),
+                "{} omitted {} times",
+                edge,
+                count
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_by_length() {
+        let mut graph = generate_delaunay(&params(&[
+            ("nodes", MetadataValue::Integer(150)),
+            ("seed", MetadataValue::Integer(2)),
+        ]))
+        .unwrap();
+        let mut relative = generate_delaunay(&params(&[
+            ("nodes", MetadataValue::Integer(150)),
+            (
+                "prune",
+                MetadataValue::String("relative_neighborhood".to_string()),
+            ),
+            ("seed", MetadataValue::Integer(2)),
+        ]))
+        .unwrap();
+
+        spanning_tree(
+            &mut graph,
+            &params(&[
+                ("algorithm", MetadataValue::String("minimum".to_string())),
+                ("weight", MetadataValue::String("length".to_string())),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(graph.edge_count(), 149);
+        // The Euclidean minimum spanning tree lies within the relative-neighbourhood graph
+        assert!(graph.edges.keys().all(|id| relative.edges.contains_key(id)));
+
+        // Kept edges retain their attributes
+        let edge = graph.edges.values().next().unwrap();
+        assert!(matches!(
+            edge.metadata.get("length"),
+            Some(MetadataValue::Float(_))
+        ));
+
+        relative.edges.values_mut().next().unwrap().metadata.clear();
+        let result = spanning_tree(
+            &mut relative,
+            &params(&[
+                ("algorithm", MetadataValue::String("minimum".to_string())),
+                ("weight", MetadataValue::String("length".to_string())),
+            ]),
+        );
+        assert!(result.unwrap_err().contains("no numeric length"));
+    }
+
+    #[test]
+    fn test_disconnected_graph_becomes_forest() {
+        let mut graph = grid(3, 3);
+        let other = generate_grid(&params(&[
+            ("rows", MetadataValue::Integer(2)),
+            ("cols", MetadataValue::Integer(4)),
+            ("prefix", MetadataValue::String("m".to_string())),
+        ]))
+        .unwrap();
+        graph.nodes.extend(other.nodes);
+        graph
+            .edges
+            .extend(other.edges.into_iter().map(|(id, mut edge)| {
+                edge.id = format!("m{}", id);
+                (edge.id.clone(), edge)
+            }));
+        graph.add_node(Node::new("isolated".to_string()));
+
+        for algorithm in ["wilson", "dfs"] {
+            let mut forest = graph.clone();
+            spanning_tree(
+                &mut forest,
+                &params(&[
+                    ("algorithm", MetadataValue::String(algorithm.to_string())),
+                    ("root", MetadataValue::String("n1_1".to_string())),
+                    ("seed", MetadataValue::Integer(9)),
+                ]),
+            )
+            .unwrap();
+            assert_eq!(forest.edge_count(), 8 + 7);
+            assert_eq!(component_count(&forest), 3);
+        }
+    }
+
+    #[test]
+    fn test_spanning_tree_invalid_params() {
+        let mut graph = grid(3, 3);
+        let result = spanning_tree(
+            &mut graph,
+            &params(&[("algorithm", MetadataValue::String("prim".to_string()))]),
+        );
+        assert!(result.unwrap_err().contains("Unknown algorithm prim"));
+
+        let result = spanning_tree(
+            &mut graph,
+            &params(&[("root", MetadataValue::String("missing".to_string()))]),
+        );
+        assert!(result.unwrap_err().contains("missing"));
+
+        let result = spanning_tree(
+            &mut graph,
+            &params(&[("algorithm", MetadataValue::String("minimum".to_string()))]),
+        );
+        assert!(result.is_err());
+        assert_eq!(graph.edge_count(), 12);
+    }
+
+    #[test]
+    fn test_transform_registry() {
+        assert!(get_transform("spanning_tree").is_some());
+        assert!(get_transform("maze").is_none());
+    }
+}
+
+fn typed_path(types: &[&str]) -> Graph {
+    let mut graph = Graph::new();
+    for i in 0..=types.len() {
+        graph.add_node(Node::new(format!("v{}", i)).with_position(i as f64, 0.0));
+    }
+    for (i, edge_type) in types.iter().enumerate() {
+        graph.add_edge(
+            Edge::new(format!("e{}", i), format!("v{}", i), format!("v{}", i + 1))
+                .with_type(edge_type.to_string()),
+        );
+    }
+    graph
+}
+
+#[cfg(test)]
+mod structural_transform_tests {
+    use super::*;
+
+    #[test]
+    fn test_complement_twice_restores_simple_graph() {
+        let original = grid(3, 4);
+        let mut graph = original.clone();
+        complement(&mut graph, &HashMap::new()).unwrap();
+        assert_eq!(graph.edge_count(), 12 * 11 / 2 - original.edge_count());
+
+        complement(&mut graph, &HashMap::new()).unwrap();
+        let pairs = |graph: &Graph| {
+            let mut pairs: Vec<(String, String)> = graph
+                .edges
+                .values()
+                .map(|e| {
+                    let (u, v) = (e.source.clone(), e.target.clone());
+                    if u < v {
+                        (u, v)
+                    } else {
+                        (v, u)
+                    }
+                })
+                .collect();
+            pairs.sort();
+            pairs
+        };
+        assert_eq!(pairs(&graph), pairs(&original));
+    }
+
+    #[test]
+    fn test_complement_of_selected_nodes() {
+        let mut graph = typed_path(&["", "", ""]);
+        for id in ["v0", "v1", "v2"] {
+            graph.nodes.get_mut(id).unwrap().r#type = "server".to_string();
+        }
+        complement(
+            &mut graph,
+            &params(&[("node_type", MetadataValue::String("server".to_string()))]),
+        )
+        .unwrap();
+
+        // Only v0 -- v2 among the servers, and the edge to v3 is kept
+        let mut ids: Vec<&String> = graph.edges.keys().collect();
+        ids.sort();
+        assert_eq!(ids, vec!["e2", "ev0_v2"]);
+    }
+
+    #[test]
+    fn test_directed_complement() {
+        let mut graph = typed_path(&[""]);
+        complement(
+            &mut graph,
+            &params(&[("directed", MetadataValue::Boolean(true))]),
+        )
+        .unwrap();
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.edges["ev1_v0"].target, "v0");
+    }
+
+    #[test]
+    fn test_line_graph() {
+        let mut mesh = generate_delaunay(&params(&[
+            ("nodes", MetadataValue::Integer(20)),
+            ("seed", MetadataValue::Integer(1)),
+        ]))
+        .unwrap();
+        // Σ deg(v)(deg(v) - 1) / 2 edges in the line graph
+        let mut degrees: HashMap<String, usize> = HashMap::new();
+        for edge in mesh.edges.values() {
+            *degrees.entry(edge.source.clone()).or_default() += 1;
+            *degrees.entry(edge.target.clone()).or_default() += 1;
+        }
+        let expected: usize = degrees.values().map(|d| d * (d - 1) / 2).sum();
+        let edges = mesh.edge_count();
+        line_graph(&mut mesh, &HashMap::new()).unwrap();
+        assert_eq!(mesh.node_count(), edges);
+        assert_eq!(mesh.edge_count(), expected);
+
+        let mut path = typed_path(&["road", "rail", "road"]);
+        line_graph(&mut path, &HashMap::new()).unwrap();
+        let node = path.get_node("e1").unwrap();
+        assert_eq!(node.r#type, "rail");
+        assert_eq!(node.x, 1.5);
+        assert_eq!(
+            node.metadata.get("source"),
+            Some(&MetadataValue::String("v1".to_string()))
+        );
+        assert_eq!(path.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_graph_power_of_grid() {
+        let mut graph = grid(4, 4);
+        graph_power(&mut graph, &params(&[("k", MetadataValue::Integer(6))])).unwrap();
+        // The diameter of a 4x4 grid is 6, so its 6th power is complete
+        assert_eq!(graph.edge_count(), 16 * 15 / 2);
+
+        let mut graph = grid(4, 4);
+        let result = graph_power(&mut graph, &params(&[("k", MetadataValue::Integer(0))]));
+        assert!(result.unwrap_err().contains("at least 1"));
+    }
+}
+
+#[cfg(test)]
+mod edge_transform_tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_selected_edges() {
+        let mut graph = typed_path(&["up", "down", "up"]);
+        reverse(
+            &mut graph,
+            &params(&[("edge_type", MetadataValue::String("down".to_string()))]),
+        )
+        .unwrap();
+        assert_eq!(graph.edges["e0"].source, "v0");
+        assert_eq!(graph.edges["e1"].source, "v2");
+        assert_eq!(graph.edges["e1"].target, "v1");
+    }
+
+    #[test]
+    fn test_selection_by_attribute() {
+        let mut graph = typed_path(&["", "", ""]);
+        graph
+            .edges
+            .get_mut("e2")
+            .unwrap()
+            .metadata
+            .insert("weight".to_string(), MetadataValue::Integer(0));
+        contract(
+            &mut graph,
+            &params(&[
+                ("attribute", MetadataValue::String("weight".to_string())),
+                ("value", MetadataValue::Integer(0)),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 3);
+        assert!(graph.get_node("v3").is_none());
+
+        let result = reverse(&mut graph, &params(&[("value", MetadataValue::Integer(0))]));
+        assert!(result.unwrap_err().contains("needs an attribute"));
+    }
+
+    #[test]
+    fn test_subdivide_and_contract() {
+        let mut graph = typed_path(&["link", "link"]);
+        subdivide(
+            &mut graph,
+            &params(&[
+                ("nodes", MetadataValue::Integer(3)),
+                ("node_type", MetadataValue::String("repeater".to_string())),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(graph.node_count(), 3 + 6);
+        assert_eq!(graph.edge_count(), 8);
+        let repeater = graph.get_node("e0_2").unwrap();
+        assert_eq!(repeater.r#type, "repeater");
+        assert_eq!(repeater.x, 0.5);
+        assert!(graph.edges.values().all(|edge| edge.r#type == "link"));
+        assert_eq!(component_count(&graph), 1);
+
+        contract(&mut graph, &HashMap::new()).unwrap();
+        assert_eq!(graph.node_count(), 1);
+        assert_eq!(graph.edge_count(), 0);
+    }
+}